@@ -0,0 +1,46 @@
+//! ## Collecting maps and sets, with duplicate detection
+//!
+//! Grammars for records or union types often match a repeated `entry` rule and fold the results
+//! into a map, rejecting a repeated key with a span-carrying error rather than silently letting
+//! the last one win. Hand-rolling that fold and the duplicate check in every such consuming
+//! method gets old fast.
+//!
+//! [`match_nodes!`] has collecting arms for this. `[entry(e)..] => collect_map` gathers the
+//! matched children into a `Vec`, then collects that into whatever container the surrounding
+//! code expects via `FromIterator`; `collect_set` does the same for bare values. Both come in a
+//! duplicate-checking flavor - `collect_map_no_dup` and `collect_set_no_dup` - that stop at the
+//! second occurrence of a key and build an [`Error`] from the [`Node`] that produced it, via
+//! [`Node::error`], instead of continuing with a silently-clobbered entry. The same four methods
+//! are also available directly on [`Nodes`] (`collect_map`, `collect_map_no_dup`, `collect_set`,
+//! `collect_set_no_dup`), for when the sequence isn't otherwise going through `match_nodes!`.
+//!
+//! ```ignore
+//! use std::collections::BTreeMap;
+//! use pest_consume::match_nodes;
+//!
+//! impl RecordParser {
+//!     fn record(input: Node) -> Result<BTreeMap<String, Expr>> {
+//!         match_nodes!(input.into_children();
+//!             [entry(e)..] => collect_map_no_dup,
+//!         )
+//!     }
+//!     fn entry(input: Node) -> Result<(String, Expr)> {
+//!         match_nodes!(input.into_children();
+//!             [label(l), expr(e)] => Ok((l, e)),
+//!         )
+//!     }
+//!     ...
+//! }
+//! ```
+//!
+//! Because the duplicate-checking variants return `Result<Map, Error>`, a repeated key fails the
+//! parse the same way any other consuming error does, and propagates with `?` like the rest of
+//! the pass.
+//!
+//! [`match_nodes!`]: macro.match_nodes.html
+//! [`Nodes`]: struct.Nodes.html
+//! [`Node`]: struct.Node.html
+//! [`Node::error`]: struct.Node.html#method.error
+//! [`Error`]: struct.Error.html
+//! [examples]: https://github.com/Nadrieril/pest_consume/tree/master/pest_consume/examples
+//! [dhall-rust-parser]: https://github.com/Nadrieril/dhall-rust/blob/master/dhall_syntax/src/parser.rs
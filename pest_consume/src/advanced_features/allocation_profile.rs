@@ -0,0 +1,45 @@
+//! ## What actually allocates per node, and what doesn't
+//!
+//! Profiling a parser over large input and seeing time dominated by `Node`/`Nodes` construction
+//! usually points at one of two places - this crate's own [`Node`] wrapping, or the user data
+//! cloned into it - and they call for different fixes.
+//!
+//! **Wrapping a [`Pair`](pest::iterators::Pair) into a [`Node`] is not itself an allocation.**
+//! [`Node::into_children`]/[`Node::children_ref`] build the child [`Nodes`] straight from pest's
+//! own zero-copy [`Pairs`](pest::iterators::Pairs) iterator - no intermediate `Vec` - *unless*
+//! [`Parser::parse_with_options`] is in use, in which case filtering out the skipped rules does
+//! collect into one `Vec` per child sequence built, since pest's iterator has no way to skip
+//! items without being consumed. If a grammar that needs [`node_filtering`](super::node_filtering)
+//! is on a hot path, consider whether the same rules can be made silent (a leading `_` in the
+//! grammar) instead, which costs pest nothing at parse time rather than costing this crate a
+//! `Vec` at consume time.
+//!
+//! **[User data](super::user_data) is genuinely cloned once per `Node`/`Nodes` produced** - every
+//! [`Node::into_children`], [`Node::children_ref`], [`Nodes::fork`], and
+//! [`Nodes::peek`]/[`Nodes::nth`]/[`Nodes::peek_last`] clones `D`. For a `D` that's cheap - a
+//! reference, a small `Copy` config struct, an `Rc`-cloned handle - this is the point: it lets
+//! every node carry its own independent copy with no lifetime entanglement between siblings. For
+//! a `D` that isn't cheap, the fix isn't to make cloning lazy (a `Node` with no live consuming
+//! method above it has nothing to delay the clone until - by the time [`Node::user_data`] could
+//! be called, the clone this crate would otherwise have deferred has already had to happen to
+//! build the `Node` in the first place) but to stop using user data for it: see
+//! [`context`](super::context), which threads data through by mutable reference and is never
+//! cloned at all, for exactly this case.
+//!
+//! This crate has no `criterion` benchmark suite - adding one that's actually exercised in CI
+//! would need a dev-dependency this workspace doesn't currently pull in, so a specific
+//! before/after throughput number isn't something this doc can responsibly claim without one.
+//! What's above is a precise account of where each allocation in a consume pass comes from, so a
+//! caller profiling their own grammar can tell which of the two categories their bottleneck falls
+//! into before reaching for a fix.
+//!
+//! [`Node`]: crate::Node
+//! [`Node::into_children`]: crate::Node::into_children
+//! [`Node::children_ref`]: crate::Node::children_ref
+//! [`Node::user_data`]: crate::Node::user_data
+//! [`Nodes`]: crate::Nodes
+//! [`Nodes::fork`]: crate::Nodes::fork
+//! [`Nodes::peek`]: crate::Nodes::peek
+//! [`Nodes::nth`]: crate::Nodes::nth
+//! [`Nodes::peek_last`]: crate::Nodes::peek_last
+//! [`Parser::parse_with_options`]: crate::Parser::parse_with_options
@@ -0,0 +1,29 @@
+//! ## `ariadne`-backed multi-label reports
+//!
+//! Enabled by the `ariadne` feature. [`Error`](crate::Error) - a plain re-export of
+//! [`pest::error::Error`] - only ever carries a single span, which isn't enough for a diagnostic
+//! that wants to point at more than one place at once - e.g. "this identifier" at the offending
+//! use, and "first defined here" at its original declaration.
+//!
+//! [`IntoAriadneReport::report_builder`] starts an [`AriadneReportBuilder`] from an [`Error`],
+//! seeded with a primary label built from the error's own span and message.
+//! [`AriadneReportBuilder::with_label`] attaches as many secondary labels as needed, and
+//! [`AriadneReportBuilder::build`] produces the finished [`ariadne::Report`], ready to print
+//! against an [`ariadne::Source`] built from the same text that was parsed.
+//!
+//! ```ignore
+//! use pest_consume::IntoAriadneReport;
+//!
+//! let report = input
+//!     .error("undefined variable")
+//!     .report_builder()
+//!     .with_label(definition_site.as_span(), "shadows this earlier definition")
+//!     .build();
+//! report.eprint(ariadne::Source::from(source_text)).unwrap();
+//! ```
+//!
+//! [`Error`]: crate::Error
+//! [`IntoAriadneReport::report_builder`]: crate::IntoAriadneReport::report_builder
+//! [`AriadneReportBuilder`]: crate::AriadneReportBuilder
+//! [`AriadneReportBuilder::with_label`]: crate::AriadneReportBuilder::with_label
+//! [`AriadneReportBuilder::build`]: crate::AriadneReportBuilder::build
@@ -0,0 +1,62 @@
+//! ## Collecting every error instead of stopping at the first
+//!
+//! A consuming method that returns `Err` aborts the whole parse right there: fine for a
+//! compiler that only needs to report one problem at a time, painful for tooling - a linter, an
+//! IDE, a batch validator - that wants to surface every issue it can find in a single pass. Today
+//! that means calling a consuming method once per error, which defeats the point of writing one
+//! pass over the tree.
+//!
+//! [`Parser::parse_collecting_errors`] is a diagnostics-oriented alternative to [`Parser::parse`]
+//! for this. It still runs the ordinary consume pass, but a consuming method that hits a
+//! non-fatal problem can call [`Node::emit_error`] to record an [`Error`] into a shared buffer and
+//! then keep going, returning whatever placeholder or default value lets its caller carry on.
+//! The pass runs to completion and `parse_collecting_errors` returns `(Option<T>, Vec<Error>)`:
+//! `Some(T)` alongside every emitted error if the top-level method still produced a value, `None`
+//! alongside them if a fatal error (an ordinary `Err` that was allowed to propagate) cut the pass
+//! short instead.
+//!
+//! Unlike [`Parser::parse`] and [`Parser::parse_with_userdata`], which hand back the parsed
+//! [`Nodes`] and leave calling a top-level consuming method to you, `parse_collecting_errors`
+//! takes that final step as a closure. The error buffer has to exist before anything - including
+//! picking the root [`Node`] out of the parsed [`Nodes`] with `Nodes::single` - runs, since any
+//! of it might call [`Node::emit_error`].
+//!
+//! ```ignore
+//! impl CSVParser {
+//!     fn record(input: Node) -> Result<Vec<f64>> {
+//!         match_nodes!(input.into_children();
+//!             [field(fields)..] => Ok(fields),
+//!         )
+//!     }
+//!     fn field(input: Node) -> Result<f64> {
+//!         match input.as_str().parse() {
+//!             Ok(n) => Ok(n),
+//!             Err(_) => {
+//!                 // Record the problem and let the record carry on with a placeholder.
+//!                 input.emit_error(input.error("not a number"));
+//!                 Ok(0.0)
+//!             }
+//!         }
+//!     }
+//! }
+//!
+//! fn parse_csv(input_str: &str) -> (Option<Vec<f64>>, Vec<pest_consume::Error<Rule>>) {
+//!     CSVParser::parse_collecting_errors(Rule::record, input_str, |inputs| {
+//!         let input = inputs.single()?;
+//!         CSVParser::record(input)
+//!     })
+//! }
+//! ```
+//!
+//! Every malformed field is reported this way rather than only the first one encountered.
+//!
+//! [`match_nodes!`]: macro.match_nodes.html
+//! [`Nodes`]: struct.Nodes.html
+//! [`Node`]: struct.Node.html
+//! [`Node::emit_error`]: struct.Node.html#method.emit_error
+//! [`Error`]: struct.Error.html
+//! [`Parser`]: trait.Parser.html
+//! [`Parser::parse`]: trait.Parser.html#method.parse
+//! [`Parser::parse_collecting_errors`]: trait.Parser.html#method.parse_collecting_errors
+//! [examples]: https://github.com/Nadrieril/pest_consume/tree/master/pest_consume/examples
+//! [dhall-rust-parser]: https://github.com/Nadrieril/dhall-rust/blob/master/dhall_syntax/src/parser.rs
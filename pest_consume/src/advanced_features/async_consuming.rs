@@ -0,0 +1,84 @@
+//! ## Why `match_nodes!` can't dispatch into an `async fn`
+//!
+//! Every arm [`match_nodes!`] generates boils down to the same shape, however the pattern is
+//! written - a plain, synchronous call, `?`-propagated:
+//!
+//! ```ignore
+//! let name = Self::name(__matched.next().unwrap())?;
+//! ```
+//!
+//! There's no variant of that codegen that awaits instead - `match_nodes!` is an ordinary
+//! `macro_rules!` macro, expanded once, with no way to tell at expansion time whether the consuming
+//! method it's calling into is `async fn` or not, and so no way to decide whether to splice in
+//! `.await` for this grammar's methods without doing it for every grammar's. Doing that anyway -
+//! maintaining two full copies of every arm shape the macro already supports, one synchronous and
+//! one awaiting - would double an already large macro for a feature this crate's own dependencies
+//! don't support today besides: there's no async runtime anywhere in this crate to hand an awaited
+//! future's executor to, and picking one (`tokio`, `async-std`, executor-agnostic `Future`s with the
+//! caller driving them) is exactly the kind of decision a parsing helper library shouldn't make on
+//! an application's behalf.
+//!
+//! ## What works today, with no crate changes at all
+//!
+//! An `async fn` consuming method is already just `async fn` - ordinary Rust, same as every
+//! consuming method in these docs - as long as it's dispatched by hand instead of through
+//! `match_nodes!`, using [`Nodes::peek_rule`] and [`Nodes::next_node`], the same escape valve
+//! [`extensible_dispatch`] uses for a different reason. Recursive async dispatch - a node's
+//! `async fn` awaiting each child's own `async fn` in turn - is just recursive async/await, with no
+//! extra mechanism needed for either the ordering (each `.await` already runs to completion before
+//! the next) or error propagation (`?` inside an `async fn` returning `Result` works exactly the
+//! same as outside one):
+//!
+//! ```ignore
+//! impl ModuleParser {
+//!     async fn block(input: Node<'_>) -> Result<Vec<Stmt>> {
+//!         let mut stmts = input.into_children();
+//!         let mut out = Vec::new();
+//!         while stmts.peek_rule().is_some() {
+//!             out.push(Self::stmt(stmts.next_node().unwrap()).await?);
+//!         }
+//!         Ok(out)
+//!     }
+//!
+//!     async fn stmt(input: Node<'_>) -> Result<Stmt> {
+//!         match input.as_rule() {
+//!             Rule::import_stmt => Self::import_stmt(input).await,
+//!             Rule::expr_stmt => Self::expr_stmt(input),
+//!             _ => Err(input.error_no_consuming_method()),
+//!         }
+//!     }
+//!
+//!     async fn import_stmt(input: Node<'_>) -> Result<Stmt> {
+//!         let path = input.into_children().single()?.as_str();
+//!         let source = read_module_source(path).await?; // the actual `async` work
+//!         Ok(Stmt::Import(source))
+//!     }
+//! }
+//! ```
+//!
+//! This gives up `match_nodes!`'s concise patterns for whichever rules need to await something,
+//! the same concession [`extensible_dispatch`] already asks for to split handlers across crates -
+//! but every rule whose handler has no async work of its own can still use `match_nodes!`
+//! internally, called from inside an outer `async fn` that only awaits around it, not through it.
+//!
+//! ## Top-level-only async needs no rewriting at all
+//!
+//! The request this module answers also asked about the narrower case of awaiting only once, at
+//! the very top, rather than recursively. That's not even a workaround - it's already exactly how
+//! [`Parser::parse`] and an application's own entry point compose: `parse` itself is a plain
+//! synchronous function (there's no I/O in it to await, only pest's in-memory grammar match), and
+//! nothing stops the function that calls it from being `async fn`:
+//!
+//! ```ignore
+//! async fn parse_module(input_str: &str) -> Result<Module> {
+//!     let inputs = ModuleParser::parse(Rule::module, input_str)?;
+//!     let node = inputs.single()?;
+//!     ModuleParser::module(node).await // `module`, like `block` above, is a hand-dispatched `async fn`
+//! }
+//! ```
+//!
+//! [`match_nodes!`]: crate::match_nodes
+//! [`Nodes::peek_rule`]: crate::Nodes::peek_rule
+//! [`Nodes::next_node`]: crate::Nodes::next_node
+//! [`Parser::parse`]: crate::Parser::parse
+//! [`extensible_dispatch`]: super::extensible_dispatch
@@ -0,0 +1,29 @@
+//! ## Parsing many files that share one `Data`
+//!
+//! A tool that parses many small files against the same interner, config, or other shared
+//! [user data](super::user_data) - a project-wide linter, say - otherwise has to write its own
+//! per-file loop around [`Parser::parse_with_userdata`], cloning `data` and attaching each file's
+//! name to its errors by hand. [`Parser::parse_batch`] is that loop, already written:
+//!
+//! ```ignore
+//! let files: Vec<(&str, &str)> = read_project_files()?;
+//! let results = CSVParser::parse_batch(Rule::file, &files, Rc::clone(&interner));
+//! for (name, result) in results {
+//!     match result {
+//!         Ok(inputs) => report_ok(&name, inputs),
+//!         Err(e) => eprintln!("{e}"), // already names `name` via its `Display`
+//!     }
+//! }
+//! ```
+//!
+//! Every input gets its own entry in the returned `Vec` regardless of whether it parsed - one
+//! malformed file doesn't stop the rest of the batch from being reported, the same way
+//! [`Nodes::consume_all`](super::error_recovery) keeps going past one bad element instead of
+//! aborting the whole sequence.
+//!
+//! This doesn't run the batch in parallel; it's the same sequential loop a hand-written one would
+//! be, just without the boilerplate. See [`parallel_consuming`](super::parallel_consuming) for
+//! running the *consuming* pass (as opposed to the parse itself) across threads.
+//!
+//! [`Parser::parse_with_userdata`]: crate::Parser::parse_with_userdata
+//! [`Parser::parse_batch`]: crate::Parser::parse_batch
@@ -12,7 +12,10 @@
 //! The data needs to be `Clone`, and will be cloned often so it should be cheap to clone.
 //! A common usage is to have this data be a reference, which are free to clone.
 //!
-//! If you need mutable access to some data, use [`Cell`] or [`RefCell`].
+//! If you need mutable access to some data, use [`Cell`] or [`RefCell`]. For something heavier,
+//! like an interner or an arena that should be threaded through the whole consume pass by
+//! mutable reference instead of being cloned at every node, see [`context`](super::context)
+//! instead.
 //!
 //! ```ignore
 //! struct AppSettings { ... }
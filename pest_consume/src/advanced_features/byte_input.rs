@@ -0,0 +1,31 @@
+//! ## Why there's no generic `Input`/`ByteNode` for parsing `&[u8]`
+//!
+//! The trait method every [`Parser`] impl in this crate ultimately calls, `pest::Parser::parse`,
+//! is declared `fn parse(rule: R, input: &str) -> Result<Pairs<'_, R>, Error<R>>`: its input is
+//! a `&str`, not a generic `Input` type or a `&[u8]`, and the `Pairs`/`Pair`/`Span` it returns are
+//! all built on that same `&str`. Pest has no byte-input parsing mode, experimental or otherwise,
+//! to generalize over, since `Span::start_pos`/`end_pos` and every other position pest tracks
+//! assume the underlying buffer is valid UTF-8: a `Span` can be turned back into `&str` (via
+//! [`pest::Span::as_str`]) without a fallibility check anywhere in pest's own API. A generic
+//! `Input` associated type or a parallel `ByteNode` on this crate's side would have nothing
+//! underneath it to generalize: every `Pair` it would wrap still comes from the same
+//! `&str`-producing `pest::Parser::parse`, so the genericity would be cosmetic rather than real.
+//!
+//! What pest does support is matching a grammar against arbitrary bytes embedded in otherwise
+//! ordinary text - a length-prefixed binary blob hex- or base64-encoded inline, say - and that
+//! already works today without any change here: [`Node::as_bytes`] returns the matched span as
+//! `&[u8]`, for exactly that case. It's infallible rather than gated on UTF-8 validity, since the
+//! bytes underneath are always a substring of the already-UTF8-validated source `&str` - there's
+//! no byte sequence [`Node::as_bytes`] can return that wasn't already guaranteed valid UTF-8 by
+//! virtue of coming from [`Node::as_str`].
+//!
+//! For a genuinely binary format with no practical text encoding - framed network protocols,
+//! file formats with magic-number headers - a hand-written byte-level parser (`nom`, or plain
+//! slice indexing) remains the better tool than pest/pest_consume, the same way
+//! [`advanced_features::streaming_reads`] points elsewhere for bounded-memory streaming: both are
+//! capabilities outside what pest's `&str`-based foundation can soundly offer.
+//!
+//! [`Parser`]: trait.Parser.html
+//! [`Node::as_bytes`]: struct.Node.html#method.as_bytes
+//! [`Node::as_str`]: struct.Node.html#method.as_str
+//! [`advanced_features::streaming_reads`]: super::streaming_reads
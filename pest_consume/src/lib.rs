@@ -0,0 +1,527 @@
+//! A few helpers on top of [pest] to write a full parser.
+//!
+//! See the [`advanced_features`] module for documentation on the advanced features of this
+//! crate.
+//!
+//! [pest]: https://pest.rs
+
+pub mod advanced_features {
+    //! Advanced features of this crate, documented on their own so the main docs stay focused on
+    //! the common path.
+    pub mod collections;
+    pub mod context;
+    pub mod error_recovery;
+    pub mod precedence_climbing;
+    pub mod user_data;
+}
+
+mod precedence;
+
+pub use pest;
+pub use precedence::{Assoc, PrecClimber};
+
+use std::cell::{Ref, RefCell, RefMut};
+use std::rc::Rc;
+
+use pest::iterators::{Pair, Pairs};
+use pest::error::ErrorVariant;
+use pest::RuleType;
+
+/// The error type used throughout this crate; a plain re-export of [`pest::error::Error`].
+pub type Error<R> = pest::error::Error<R>;
+
+/// A single node of the parse tree, together with whatever [user data](advanced_features::user_data)
+/// was threaded through the parse, and whatever [context](advanced_features::context) it was
+/// threaded through by reference.
+///
+/// `D` is the type of the user data, and defaults to `()` for parses that don't need any. `Ctx` is
+/// the type of the context, and likewise defaults to `()`.
+pub struct Node<'i, R, D = (), Ctx = ()> {
+    pair: Pair<'i, R>,
+    user_data: D,
+    context: *mut Ctx,
+    context_lock: Rc<RefCell<()>>,
+    errors: Option<*mut Vec<Error<R>>>,
+}
+
+impl<'i, R: RuleType, D: Clone, Ctx> Node<'i, R, D, Ctx> {
+    /// The rule this node was parsed as.
+    pub fn as_rule(&self) -> R {
+        self.pair.as_rule()
+    }
+
+    /// The text this node spans.
+    pub fn as_str(&self) -> &'i str {
+        self.pair.as_str()
+    }
+
+    /// The underlying [`pest::Span`] this node spans.
+    pub fn as_span(&self) -> pest::Span<'i> {
+        self.pair.as_span()
+    }
+
+    /// Build an [`Error`] pointing at this node, with the given message.
+    pub fn error(&self, message: impl ToString) -> Error<R> {
+        Error::new_from_span(
+            ErrorVariant::CustomError {
+                message: message.to_string(),
+            },
+            self.pair.as_span(),
+        )
+    }
+
+    /// The user data threaded through the parse.
+    pub fn user_data(&self) -> &D {
+        &self.user_data
+    }
+
+    /// A shared borrow of the context threaded through the parse, as described in
+    /// [`advanced_features::context`]. For parses that don't use [`Parser::parse_with_context`],
+    /// this is just `&()`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if another `Node`/`Nodes` sharing this context currently holds a
+    /// [`context_mut`](Self::context_mut) borrow - e.g. a sibling `Node` produced by the same
+    /// `Nodes` whose `context_mut` guard is still alive.
+    pub fn context(&self) -> Ref<'_, Ctx> {
+        let lock = self.context_lock.borrow();
+        Ref::map(lock, |()| {
+            // Safety: `context` either points at a live `Ctx` that `Parser::parse_with_context`
+            // borrowed mutably for at least as long as this whole consume pass (see that method),
+            // or, when no context was threaded through, is a dangling-but-aligned pointer to the
+            // zero-sized `()` - sound to dereference, since reading a `()` never actually touches
+            // memory. `self.context_lock`, just borrowed above and shared with every sibling
+            // `Node`/`Nodes` derived from the same context, is what actually rules out a
+            // concurrent `context_mut` borrow aliasing this one.
+            unsafe { &*self.context }
+        })
+    }
+
+    /// A mutable borrow of the context threaded through the parse. See [`Node::context`] for a
+    /// shared borrow, and [`advanced_features::context`] for the full picture.
+    ///
+    /// # Panics
+    ///
+    /// Panics if another `Node`/`Nodes` sharing this context currently holds any
+    /// [`context`](Self::context) or `context_mut` borrow.
+    pub fn context_mut(&self) -> RefMut<'_, Ctx> {
+        let lock = self.context_lock.borrow_mut();
+        RefMut::map(lock, |()| {
+            // Safety: see `Node::context`.
+            unsafe { &mut *self.context }
+        })
+    }
+
+    /// Record a non-fatal `error` into the shared buffer from
+    /// [`Parser::parse_collecting_errors`], instead of aborting the whole parse, as described in
+    /// [`advanced_features::error_recovery`]. Outside of `parse_collecting_errors`, there is no
+    /// buffer to record into, so this is a no-op.
+    pub fn emit_error(&self, error: Error<R>) {
+        if let Some(errors) = self.errors {
+            // Safety: `errors` was derived from a `&mut Vec<Error<R>>` that
+            // `Parser::parse_collecting_errors` keeps borrowed for the whole consume pass, and is
+            // never touched anywhere else during that pass.
+            unsafe { (*errors).push(error) };
+        }
+    }
+
+    /// This node's children, as a fresh [`Nodes`] value.
+    pub fn into_children(self) -> Nodes<'i, R, D, Ctx> {
+        let span = self.pair.as_span();
+        Nodes {
+            pairs: self.pair.into_inner(),
+            user_data: self.user_data,
+            context: self.context,
+            context_lock: self.context_lock,
+            errors: self.errors,
+            parent_span: span,
+        }
+    }
+
+    /// The underlying [`pest::iterators::Pair`].
+    pub fn as_pair(&self) -> &Pair<'i, R> {
+        &self.pair
+    }
+}
+
+/// A sequence of sibling nodes - typically the children of some [`Node`], or the top-level pairs
+/// produced by a parse.
+pub struct Nodes<'i, R, D = (), Ctx = ()> {
+    pairs: Pairs<'i, R>,
+    user_data: D,
+    context: *mut Ctx,
+    /// Shared by every `Node`/`Nodes` derived from the same context, so that two of them can
+    /// never produce aliasing `context`/`context_mut` borrows even though `context` itself is
+    /// freely copied between them. See [`Node::context_mut`].
+    context_lock: Rc<RefCell<()>>,
+    errors: Option<*mut Vec<Error<R>>>,
+    /// Span to point errors at when there is no specific offending node to blame (e.g. the
+    /// sequence is empty, or shorter than expected).
+    parent_span: pest::Span<'i>,
+}
+
+impl<'i, R: RuleType, D: Clone> Nodes<'i, R, D> {
+    /// Build a fresh [`Nodes`] directly from pest's own [`Pairs`], carrying the given user data.
+    /// `input_str` is used to build errors that don't point at any specific node.
+    pub fn new(pairs: Pairs<'i, R>, user_data: D, input_str: &'i str) -> Self {
+        Nodes {
+            pairs,
+            user_data,
+            context: std::ptr::NonNull::dangling().as_ptr(),
+            context_lock: Rc::new(RefCell::new(())),
+            errors: None,
+            parent_span: pest::Span::new(input_str, 0, input_str.len())
+                .unwrap_or_else(|| pest::Span::new(input_str, 0, 0).unwrap()),
+        }
+    }
+}
+
+impl<'i, R: RuleType> Nodes<'i, R> {
+    /// Build a fresh [`Nodes`] for [`Parser::parse_collecting_errors`], with no user data, that
+    /// records into `errors` whenever a consuming method calls [`Node::emit_error`]. `input_str`
+    /// is used to build errors that don't point at any specific node.
+    pub fn new_collecting_errors(
+        pairs: Pairs<'i, R>,
+        input_str: &'i str,
+        errors: &'i mut Vec<Error<R>>,
+    ) -> Self {
+        Nodes {
+            pairs,
+            user_data: (),
+            context: std::ptr::NonNull::dangling().as_ptr(),
+            context_lock: Rc::new(RefCell::new(())),
+            errors: Some(errors as *mut Vec<Error<R>>),
+            parent_span: pest::Span::new(input_str, 0, input_str.len())
+                .unwrap_or_else(|| pest::Span::new(input_str, 0, 0).unwrap()),
+        }
+    }
+}
+
+impl<'i, R: RuleType, D: Clone, Ctx> Nodes<'i, R, D, Ctx> {
+    /// Build a fresh [`Nodes`] directly from pest's own [`Pairs`], carrying the given user data
+    /// and a mutable borrow of `context` threaded through the whole pass, as described in
+    /// [`advanced_features::context`]. `input_str` is used to build errors that don't point at any
+    /// specific node.
+    pub fn new_with_context(
+        pairs: Pairs<'i, R>,
+        user_data: D,
+        input_str: &'i str,
+        context: &'i mut Ctx,
+    ) -> Self {
+        Nodes {
+            pairs,
+            user_data,
+            context: context as *mut Ctx,
+            context_lock: Rc::new(RefCell::new(())),
+            errors: None,
+            parent_span: pest::Span::new(input_str, 0, input_str.len())
+                .unwrap_or_else(|| pest::Span::new(input_str, 0, 0).unwrap()),
+        }
+    }
+
+    /// The rule of the next node, without consuming it.
+    pub fn peek_rule(&self) -> Option<R> {
+        self.pairs.peek().map(|p| p.as_rule())
+    }
+
+    /// The rules of the next (up to) `n` nodes, without consuming anything. Used by
+    /// [`match_nodes!`] to check a candidate arm's shape before committing to it.
+    pub fn peek_rules(&self, n: usize) -> Vec<R> {
+        self.pairs.clone().take(n).map(|p| p.as_rule()).collect()
+    }
+
+    /// Whether there are no nodes left.
+    pub fn is_empty(&self) -> bool {
+        self.pairs.peek().is_none()
+    }
+
+    /// Consume and return the next node, if any.
+    pub fn next_node(&mut self) -> Option<Node<'i, R, D, Ctx>> {
+        let pair = self.pairs.next()?;
+        Some(Node {
+            pair,
+            user_data: self.user_data.clone(),
+            context: self.context,
+            context_lock: Rc::clone(&self.context_lock),
+            errors: self.errors,
+        })
+    }
+
+    /// Build an [`Error`] pointing at the next node, or at the parent node's span if this
+    /// sequence is empty.
+    pub fn error(&self, message: impl ToString) -> Error<R> {
+        let variant = ErrorVariant::CustomError {
+            message: message.to_string(),
+        };
+        match self.pairs.clone().peek() {
+            Some(pair) => Error::new_from_span(variant, pair.as_span()),
+            None => Error::new_from_span(variant, self.parent_span),
+        }
+    }
+
+    /// Consume this sequence, requiring that it contains exactly one node, and return it.
+    pub fn single(mut self) -> Result<Node<'i, R, D, Ctx>, Error<R>> {
+        let node = self
+            .next_node()
+            .ok_or_else(|| self.error("expected exactly one node, found none"))?;
+        if !self.is_empty() {
+            return Err(node.error("expected exactly one node, found more than one"));
+        }
+        Ok(node)
+    }
+
+    /// Consume every remaining node, mapping each one with `f`, and collect the results into a
+    /// `Vec`. Used to implement the trailing-repetition (`..`) capture in [`match_nodes!`].
+    pub fn map_to_vec<T>(
+        mut self,
+        mut f: impl FnMut(Node<'i, R, D, Ctx>) -> Result<T, Error<R>>,
+    ) -> Result<Vec<T>, Error<R>> {
+        let mut out = Vec::new();
+        while let Some(node) = self.next_node() {
+            out.push(f(node)?);
+        }
+        Ok(out)
+    }
+
+    /// Gather every remaining node into a `Vec`, mapping each one with `f`. Meant to be collected
+    /// further into a map type of the caller's choice, e.g. with `.into_iter().collect()`. See
+    /// [`collect_map_no_dup`](Self::collect_map_no_dup) for the duplicate-rejecting variant.
+    pub fn collect_map<K, V>(
+        self,
+        f: impl FnMut(Node<'i, R, D, Ctx>) -> Result<(K, V), Error<R>>,
+    ) -> Result<Vec<(K, V)>, Error<R>> {
+        self.map_to_vec(f)
+    }
+
+    /// Like [`collect_map`](Self::collect_map), but rejects a repeated key: the error is built
+    /// from the [`Node`] of the second occurrence, via [`Node::error`].
+    pub fn collect_map_no_dup<K, V>(
+        mut self,
+        mut f: impl FnMut(Node<'i, R, D, Ctx>) -> Result<(K, V), Error<R>>,
+    ) -> Result<Vec<(K, V)>, Error<R>>
+    where
+        K: Eq + std::hash::Hash + Clone,
+    {
+        let mut seen = std::collections::HashSet::new();
+        let mut out = Vec::new();
+        while let Some(node) = self.next_node() {
+            let dup_error = node.error("duplicate key");
+            let (k, v) = f(node)?;
+            if !seen.insert(k.clone()) {
+                return Err(dup_error);
+            }
+            out.push((k, v));
+        }
+        Ok(out)
+    }
+
+    /// Gather every remaining node into a `Vec`, mapping each one with `f`. Meant to be collected
+    /// further into a set type of the caller's choice. See
+    /// [`collect_set_no_dup`](Self::collect_set_no_dup) for the duplicate-rejecting variant.
+    pub fn collect_set<V>(
+        self,
+        f: impl FnMut(Node<'i, R, D, Ctx>) -> Result<V, Error<R>>,
+    ) -> Result<Vec<V>, Error<R>> {
+        self.map_to_vec(f)
+    }
+
+    /// Like [`collect_set`](Self::collect_set), but rejects a repeated value: the error is built
+    /// from the [`Node`] of the second occurrence, via [`Node::error`].
+    pub fn collect_set_no_dup<V>(
+        mut self,
+        mut f: impl FnMut(Node<'i, R, D, Ctx>) -> Result<V, Error<R>>,
+    ) -> Result<Vec<V>, Error<R>>
+    where
+        V: Eq + std::hash::Hash + Clone,
+    {
+        let mut seen = std::collections::HashSet::new();
+        let mut out = Vec::new();
+        while let Some(node) = self.next_node() {
+            let dup_error = node.error("duplicate value");
+            let v = f(node)?;
+            if !seen.insert(v.clone()) {
+                return Err(dup_error);
+            }
+            out.push(v);
+        }
+        Ok(out)
+    }
+}
+
+/// Implement this trait for your grammar's `pest_derive`-generated parser to get access to
+/// [`Node`]-based parsing.
+///
+/// Your parser must also implement `pest::Parser<Self::Rule>` - this is what `pest_derive`
+/// generates for you.
+pub trait Parser {
+    /// The rule type generated by `pest_derive` for this grammar.
+    type Rule: RuleType;
+
+    /// Parse `input_str` starting from `rule`, with no user data.
+    fn parse(rule: Self::Rule, input_str: &str) -> Result<Nodes<'_, Self::Rule>, Error<Self::Rule>>
+    where
+        Self: pest::Parser<Self::Rule>,
+    {
+        Self::parse_with_userdata(rule, input_str, ())
+    }
+
+    /// Parse `input_str` starting from `rule`, threading `data` through every [`Node`] as
+    /// described in [`advanced_features::user_data`].
+    fn parse_with_userdata<D: Clone>(
+        rule: Self::Rule,
+        input_str: &str,
+        data: D,
+    ) -> Result<Nodes<'_, Self::Rule, D>, Error<Self::Rule>>
+    where
+        Self: pest::Parser<Self::Rule>,
+    {
+        let pairs = <Self as pest::Parser<Self::Rule>>::parse(rule, input_str)?;
+        Ok(Nodes::new(pairs, data, input_str))
+    }
+
+    /// Parse `input_str` starting from `rule`, threading a mutable `context` through every
+    /// [`Node`] by reference rather than cloning it, as described in
+    /// [`advanced_features::context`]. Use this instead of
+    /// [`parse_with_userdata`](Self::parse_with_userdata) when the data a consuming method needs
+    /// (an interner, an arena, a symbol table) should be mutated in place as the pass descends the
+    /// tree, rather than cloned at every node.
+    fn parse_with_context<'i, Ctx>(
+        rule: Self::Rule,
+        input_str: &'i str,
+        context: &'i mut Ctx,
+    ) -> Result<Nodes<'i, Self::Rule, (), Ctx>, Error<Self::Rule>>
+    where
+        Self: pest::Parser<Self::Rule>,
+    {
+        let pairs = <Self as pest::Parser<Self::Rule>>::parse(rule, input_str)?;
+        Ok(Nodes::new_with_context(pairs, (), input_str, context))
+    }
+
+    /// Parse `input_str` starting from `rule`, then run `consume` to produce a `T`, collecting
+    /// every error [`Node::emit_error`] records along the way instead of stopping at the first,
+    /// as described in [`advanced_features::error_recovery`]. Unlike [`parse`](Self::parse), this
+    /// takes the final step of calling a top-level consuming method itself, since the error buffer
+    /// has to exist before anything - including picking the root node out of `Nodes` - runs.
+    ///
+    /// Returns `Some(T)` alongside every collected error if `consume` (and the initial parse)
+    /// succeeded, or `None` alongside them if a fatal error - an ordinary `Err` that was allowed to
+    /// propagate, or a failure from pest itself - cut the pass short instead.
+    fn parse_collecting_errors<T>(
+        rule: Self::Rule,
+        input_str: &str,
+        consume: impl FnOnce(Nodes<'_, Self::Rule>) -> Result<T, Error<Self::Rule>>,
+    ) -> (Option<T>, Vec<Error<Self::Rule>>)
+    where
+        Self: pest::Parser<Self::Rule>,
+    {
+        let mut errors = Vec::new();
+        let pairs = match <Self as pest::Parser<Self::Rule>>::parse(rule, input_str) {
+            Ok(pairs) => pairs,
+            Err(err) => {
+                errors.push(err);
+                return (None, errors);
+            }
+        };
+        let nodes = Nodes::new_collecting_errors(pairs, input_str, &mut errors);
+        match consume(nodes) {
+            Ok(value) => (Some(value), errors),
+            Err(err) => {
+                errors.push(err);
+                (None, errors)
+            }
+        }
+    }
+}
+
+/// Expects a [`Nodes`] value and one or more `[pattern] => expr` arms, tried in order against the
+/// actual rules of the sequence. Each pattern is a comma-separated list of `rule_name(binding)`,
+/// optionally ending in `rule_name(binding)..` to capture every remaining node. `rule_name` must
+/// name both a variant of the grammar's `Rule` enum and an associated function (typically
+/// `Self::rule_name`) to call on the matching node.
+///
+/// An arm whose pattern is a single trailing capture, with no fixed nodes before it, may also
+/// write one of `collect_map`, `collect_map_no_dup`, `collect_set`, `collect_set_no_dup` in place
+/// of the expression, e.g. `[entry(e)..] => collect_map_no_dup`; an arm with fixed nodes before
+/// the capture does not accept these forms. This gathers every remaining node with the rule's
+/// associated function and collects the result into whatever container the surrounding code
+/// expects, rejecting duplicate keys/values for the `_no_dup` variants. See
+/// [`advanced_features::collections`].
+#[macro_export]
+macro_rules! match_nodes {
+    ($nodes:expr; $($arms:tt)*) => {
+        'match_nodes: {
+            #[allow(unused_mut)]
+            let mut __nodes = $nodes;
+            $crate::match_nodes!(@arm 'match_nodes, __nodes; $($arms)*);
+            break 'match_nodes ::std::result::Result::Err(
+                __nodes.error("no arm of match_nodes! matched these nodes"),
+            );
+        }
+    };
+    (@arm $label:lifetime, $nodes:ident; [$($fixed:ident ( $fixed_bind:pat )),* $(,)?] => $body:expr $(, $($rest:tt)*)?) => {
+        if $crate::match_nodes!(@check $nodes; [$($fixed),*]) {
+            $(let $fixed_bind = Self::$fixed($nodes.next_node().unwrap())?;)*
+            break $label $body;
+        }
+        $($crate::match_nodes!(@arm $label, $nodes; $($rest)*);)?
+    };
+    (@arm $label:lifetime, $nodes:ident; [$($fixed:ident ( $fixed_bind:pat )),* , $last:ident ( $last_bind:pat ) ..] => $body:expr $(, $($rest:tt)*)?) => {
+        if $crate::match_nodes!(@check_prefix $nodes; [$($fixed),*]) {
+            $(let $fixed_bind = Self::$fixed($nodes.next_node().unwrap())?;)*
+            let $last_bind = $nodes.map_to_vec(Self::$last)?;
+            break $label $body;
+        }
+        $($crate::match_nodes!(@arm $label, $nodes; $($rest)*);)?
+    };
+    // Collecting arms: gather every remaining node with `Self::$last`, then collect the result
+    // into whatever container the surrounding code expects, rejecting duplicates for the
+    // `_no_dup` variants. Tried before the generic trailing-capture arm below, since `collect_map`
+    // etc. would otherwise also parse as (and shadow) an ordinary `$body:expr`. See
+    // `advanced_features::collections`.
+    (@arm $label:lifetime, $nodes:ident; [$last:ident ( $last_bind:pat ) ..] => collect_map $(, $($rest:tt)*)?) => {
+        if $crate::match_nodes!(@check_prefix $nodes; []) {
+            break $label $nodes.collect_map(Self::$last).map(|__v| __v.into_iter().collect());
+        }
+        $($crate::match_nodes!(@arm $label, $nodes; $($rest)*);)?
+    };
+    (@arm $label:lifetime, $nodes:ident; [$last:ident ( $last_bind:pat ) ..] => collect_map_no_dup $(, $($rest:tt)*)?) => {
+        if $crate::match_nodes!(@check_prefix $nodes; []) {
+            break $label $nodes.collect_map_no_dup(Self::$last).map(|__v| __v.into_iter().collect());
+        }
+        $($crate::match_nodes!(@arm $label, $nodes; $($rest)*);)?
+    };
+    (@arm $label:lifetime, $nodes:ident; [$last:ident ( $last_bind:pat ) ..] => collect_set $(, $($rest:tt)*)?) => {
+        if $crate::match_nodes!(@check_prefix $nodes; []) {
+            break $label $nodes.collect_set(Self::$last).map(|__v| __v.into_iter().collect());
+        }
+        $($crate::match_nodes!(@arm $label, $nodes; $($rest)*);)?
+    };
+    (@arm $label:lifetime, $nodes:ident; [$last:ident ( $last_bind:pat ) ..] => collect_set_no_dup $(, $($rest:tt)*)?) => {
+        if $crate::match_nodes!(@check_prefix $nodes; []) {
+            break $label $nodes.collect_set_no_dup(Self::$last).map(|__v| __v.into_iter().collect());
+        }
+        $($crate::match_nodes!(@arm $label, $nodes; $($rest)*);)?
+    };
+    (@arm $label:lifetime, $nodes:ident; [$last:ident ( $last_bind:pat ) ..] => $body:expr $(, $($rest:tt)*)?) => {
+        if $crate::match_nodes!(@check_prefix $nodes; []) {
+            let $last_bind = $nodes.map_to_vec(Self::$last)?;
+            break $label $body;
+        }
+        $($crate::match_nodes!(@arm $label, $nodes; $($rest)*);)?
+    };
+    (@arm $label:lifetime, $nodes:ident;) => {};
+
+    // Peek-only check that the nodes are exactly the given rules, in order.
+    (@check $nodes:ident; [$($name:ident),*]) => {{
+        let __expected: &[Rule] = &[$(Rule::$name),*];
+        let __actual = $nodes.peek_rules(__expected.len() + 1);
+        __actual.len() == __expected.len() && __actual.iter().eq(__expected.iter())
+    }};
+    // Peek-only check that the nodes *start with* the given rules, in order.
+    (@check_prefix $nodes:ident; [$($name:ident),*]) => {{
+        let __expected: &[Rule] = &[$(Rule::$name),*];
+        let __actual = $nodes.peek_rules(__expected.len());
+        __actual.len() == __expected.len() && __actual.iter().eq(__expected.iter())
+    }};
+}
@@ -0,0 +1,33 @@
+//! ## Cancelling a long-running parse
+//!
+//! A consuming pass over a large or adversarial input can take a while, and there's no way to
+//! interrupt ordinary Rust function calls short of killing the thread they're running on.
+//! [`Parser::parse_with_cancel`] offers a cooperative alternative: every [`Node`] produced while
+//! descending from it carries the same `cancel_token` its roots were given, and [`match_nodes!`]
+//! checks that token before trying any arm, returning a clean [`Error`] instead of dispatching
+//! into another node - the same mechanism [`advanced_features::recursion_limit`] uses for depth,
+//! checked at the same granularity: at least once per node visited.
+//!
+//! ```ignore
+//! use std::sync::atomic::{AtomicBool, Ordering};
+//!
+//! let cancelled = AtomicBool::new(false);
+//! spawn_deadline_timer(Duration::from_secs(1), || cancelled.store(true, Ordering::Relaxed));
+//!
+//! let inputs = CalcParser::parse_with_cancel(Rule::calculation, input_str, &cancelled)?;
+//! let input = inputs.single()?;
+//! CalcParser::calculation(input)
+//! ```
+//!
+//! Dispatching by hand instead of through `match_nodes!` (e.g. via [`next_node`]) bypasses that
+//! automatic check, so call [`check_cancelled`] directly in that case. Outside of
+//! `parse_with_cancel`, [`check_cancelled`] always passes - the same unbounded behavior as every
+//! other entry point.
+//!
+//! [`Node`]: struct.Node.html
+//! [`Error`]: ../struct.Error.html
+//! [`match_nodes!`]: ../macro.match_nodes.html
+//! [`Parser::parse_with_cancel`]: trait.Parser.html#method.parse_with_cancel
+//! [`next_node`]: struct.Nodes.html#method.next_node
+//! [`check_cancelled`]: struct.Nodes.html#method.check_cancelled
+//! [`advanced_features::recursion_limit`]: super::recursion_limit
@@ -0,0 +1,107 @@
+//! The `Nodes::prec_climb` method described in [`crate::advanced_features::precedence_climbing`].
+
+use std::collections::HashMap;
+
+use pest::RuleType;
+
+use crate::{Error, Node, Nodes};
+
+/// Associativity of an operator in a [`PrecClimber`] table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Assoc {
+    /// `a op b op c` parses as `(a op b) op c`.
+    Left,
+    /// `a op b op c` parses as `a op (b op c)`.
+    Right,
+}
+
+/// A table of infix operators, mapping each operator rule to a precedence and associativity, used
+/// to drive [`Nodes::prec_climb`].
+pub struct PrecClimber<R> {
+    ops: HashMap<R, (u32, Assoc)>,
+}
+
+impl<R: RuleType> PrecClimber<R> {
+    /// Builds a climbing table from `(operator rule, precedence, associativity)` triples. Higher
+    /// precedence binds tighter.
+    ///
+    /// # Panics
+    ///
+    /// Panics if two operators share a precedence but disagree on associativity - that
+    /// combination has no sound reading, so it is rejected eagerly here rather than deep into a
+    /// parse.
+    pub fn new(ops: impl IntoIterator<Item = (R, u32, Assoc)>) -> Self {
+        let mut table = HashMap::new();
+        let mut assoc_by_prec: HashMap<u32, Assoc> = HashMap::new();
+        for (rule, prec, assoc) in ops {
+            match assoc_by_prec.get(&prec) {
+                Some(existing) if *existing != assoc => panic!(
+                    "PrecClimber: precedence {} is used with both Assoc::Left and Assoc::Right",
+                    prec
+                ),
+                _ => {
+                    assoc_by_prec.insert(prec, assoc);
+                }
+            }
+            table.insert(rule, (prec, assoc));
+        }
+        PrecClimber { ops: table }
+    }
+
+    fn get(&self, rule: R) -> Option<(u32, Assoc)> {
+        self.ops.get(&rule).copied()
+    }
+}
+
+impl<'i, R: RuleType, D: Clone, Ctx> Nodes<'i, R, D, Ctx> {
+    /// Runs the precedence-climbing recurrence over this child sequence, which must be shaped as
+    /// `primary (op primary)*`. `primary` converts a primary child into `T`; `fold` combines an
+    /// already-converted left-hand side, the operator node, and an already-converted right-hand
+    /// side.
+    ///
+    /// Fails if the sequence is empty, if it ends on an operator with no following primary, or if
+    /// `primary`/`fold` fail.
+    pub fn prec_climb<T>(
+        mut self,
+        climber: &PrecClimber<R>,
+        mut primary: impl FnMut(Node<'i, R, D, Ctx>) -> Result<T, Error<R>>,
+        mut fold: impl FnMut(T, Node<'i, R, D, Ctx>, T) -> Result<T, Error<R>>,
+    ) -> Result<T, Error<R>> {
+        let first = self
+            .next_node()
+            .ok_or_else(|| self.error("prec_climb: expected a primary expression, found nothing"))?;
+        let lhs = primary(first)?;
+        climb_rec(&mut self, lhs, 0, climber, &mut primary, &mut fold)
+    }
+}
+
+fn climb_rec<'i, R: RuleType, D: Clone, Ctx, T>(
+    nodes: &mut Nodes<'i, R, D, Ctx>,
+    mut lhs: T,
+    min_prec: u32,
+    climber: &PrecClimber<R>,
+    primary: &mut impl FnMut(Node<'i, R, D, Ctx>) -> Result<T, Error<R>>,
+    fold: &mut impl FnMut(T, Node<'i, R, D, Ctx>, T) -> Result<T, Error<R>>,
+) -> Result<T, Error<R>> {
+    while let Some((prec, _)) = nodes.peek_rule().and_then(|rule| climber.get(rule)) {
+        if prec < min_prec {
+            break;
+        }
+        let op = nodes.next_node().expect("peek_rule just confirmed a node");
+        let rhs_node = nodes
+            .next_node()
+            .ok_or_else(|| op.error("prec_climb: operator is not followed by a primary expression"))?;
+        let mut rhs = primary(rhs_node)?;
+
+        while let Some((next_prec, next_assoc)) = nodes.peek_rule().and_then(|rule| climber.get(rule)) {
+            if next_prec > prec || (next_assoc == Assoc::Right && next_prec == prec) {
+                rhs = climb_rec(nodes, rhs, next_prec, climber, primary, fold)?;
+            } else {
+                break;
+            }
+        }
+
+        lhs = fold(lhs, op, rhs)?;
+    }
+    Ok(lhs)
+}
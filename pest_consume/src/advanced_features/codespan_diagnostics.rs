@@ -0,0 +1,31 @@
+//! ## `codespan-reporting`-backed diagnostics
+//!
+//! Enabled by the `codespan` feature. [`Error`] only ever carries a single span, and renders it
+//! with its own one-line-at-a-time `Display` - fine for a standalone tool, but many compiler
+//! projects instead standardize on [`codespan_reporting`] so every diagnostic in the project,
+//! pest-derived or not, renders through the same `Files` database and the same styling.
+//!
+//! [`IntoCodespanDiagnostic::into_diagnostic`] converts an [`Error`] into a
+//! [`codespan_reporting::diagnostic::Diagnostic`] with a primary label at the error's span,
+//! carrying the same message [`Error`]'s own `Display` impl would print (the expected/unexpected
+//! rules, or a custom message). It takes a `file_id` rather than assuming one, so it slots into
+//! whatever [`Files`](codespan_reporting::files::Files) database the rest of the project already
+//! uses:
+//!
+//! ```ignore
+//! use codespan_reporting::files::SimpleFiles;
+//! use codespan_reporting::term::{self, termcolor::{ColorChoice, StandardStream}};
+//! use pest_consume::IntoCodespanDiagnostic;
+//!
+//! let mut files = SimpleFiles::new();
+//! let file_id = files.add("input.csv", input.clone());
+//!
+//! let records = CSVParser::parse(Rule::file, &input).map_err(|e| e.into_diagnostic(file_id))?;
+//!
+//! // On error elsewhere:
+//! let writer = StandardStream::stderr(ColorChoice::Auto);
+//! term::emit(&mut writer.lock(), &term::Config::default(), &files, &diagnostic).unwrap();
+//! ```
+//!
+//! [`Error`]: crate::Error
+//! [`IntoCodespanDiagnostic::into_diagnostic`]: crate::IntoCodespanDiagnostic::into_diagnostic
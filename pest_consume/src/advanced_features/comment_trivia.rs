@@ -0,0 +1,42 @@
+//! ## Recovering comments for a comment-preserving pretty-printer
+//!
+//! A pest grammar's `COMMENT`/`WHITESPACE` rules are silently spliced between every sequenced item
+//! in a non-atomic rule, but they never get a pair of their own - by the time a [`Node`] exists,
+//! its comments are already gone. A formatter that needs to keep them can't get them back from the
+//! tree alone.
+//!
+//! [`Parser::parse_with_trivia`] takes the names of those two rules and reconstructs the comments
+//! immediately surrounding each node from the gap of source text between sibling spans:
+//!
+//! ```ignore
+//! let inputs = RecordParser::parse_with_trivia(Rule::record, input_str, Rule::COMMENT, Rule::WHITESPACE)?;
+//! let record = inputs.single()?;
+//! for field in record.children_ref() {
+//!     for comment in field.leading_trivia() {
+//!         println!("{comment}");
+//!     }
+//! }
+//! ```
+//!
+//! [`Node::leading_trivia`] reconstructs the gap between the end of the previous sibling (or the
+//! start of the parent, for the first child) and the start of this node;
+//! [`Node::trailing_trivia`] does the mirror image on the other side. Each gap is tokenized as
+//! alternating `whitespace_rule`/`comment_rule` pieces that together account for every byte of the
+//! gap, so a `comment_rule` that greedily runs to the end of the line is recovered in full rather
+//! than just its shortest possible match. That only breaks down the moment the gap contains a
+//! literal the grammar matched without giving it a pair of its own, e.g. the `","` between two
+//! fields - trivia reconstruction stops there instead. A grammar whose separators are themselves
+//! matched as named rules rather than bare literals sees trivia on both sides of that separator's
+//! own node instead of past it.
+//!
+//! Requires a parent chain the same way [`Parser::parse_parented`] does - [`Node::leading_trivia`]/
+//! [`Node::trailing_trivia`] look up this node's siblings via [`Node::parent`], so they're always
+//! empty without [`Parser::parse_with_trivia`], and also empty for the root node itself, which has
+//! no siblings to look between.
+//!
+//! [`Parser::parse_with_trivia`]: crate::Parser::parse_with_trivia
+//! [`Parser::parse_parented`]: crate::Parser::parse_parented
+//! [`Node`]: crate::Node
+//! [`Node::parent`]: crate::Node::parent
+//! [`Node::leading_trivia`]: crate::Node::leading_trivia
+//! [`Node::trailing_trivia`]: crate::Node::trailing_trivia
@@ -0,0 +1,82 @@
+use pest::error::LineColLocation;
+use pest_consume::{match_nodes, Error, Parser as _};
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+field = @{ (!("," | WHITESPACE) ~ ANY)+ }
+fields = { field ~ ("," ~ field)* }
+record = { SOI ~ fields ~ EOI }
+WHITESPACE = _{ " " }
+"#]
+struct CSVParser;
+
+type Node<'i> = pest_consume::Node<'i, Rule>;
+type PestResult<T> = Result<T, Error<Rule>>;
+
+impl pest_consume::Parser for CSVParser {
+    type Rule = Rule;
+}
+
+impl CSVParser {
+    #[allow(non_snake_case)]
+    fn EOI(_input: Node) -> PestResult<()> {
+        Ok(())
+    }
+
+    fn field(input: Node) -> PestResult<f64> {
+        match input.as_str().parse() {
+            Ok(n) => Ok(n),
+            Err(_) => {
+                // Record the problem and let the record carry on with a placeholder.
+                input.emit_error(input.error("not a number"));
+                Ok(0.0)
+            }
+        }
+    }
+
+    fn fields(input: Node) -> PestResult<Vec<f64>> {
+        match_nodes!(input.into_children();
+            [field(fields)..] => Ok(fields),
+        )
+    }
+
+    fn record(input: Node) -> PestResult<Vec<f64>> {
+        match_nodes!(input.into_children();
+            [fields(f), EOI(_)] => Ok(f),
+        )
+    }
+}
+
+fn parse_csv(input_str: &str) -> (Option<Vec<f64>>, Vec<Error<Rule>>) {
+    CSVParser::parse_collecting_errors(Rule::record, input_str, |inputs| {
+        let input = inputs.single()?;
+        CSVParser::record(input)
+    })
+}
+
+#[test]
+fn succeeds_with_no_errors_when_every_field_is_valid() {
+    let (result, errors) = parse_csv("1, 2.5, 3");
+    assert_eq!(result, Some(vec![1.0, 2.5, 3.0]));
+    assert!(errors.is_empty());
+}
+
+#[test]
+fn collects_every_malformed_field_instead_of_stopping_at_the_first() {
+    let (result, errors) = parse_csv("1, nope, 3, also_nope");
+    assert_eq!(result, Some(vec![1.0, 0.0, 3.0, 0.0]));
+    assert_eq!(errors.len(), 2);
+}
+
+#[test]
+fn a_fatal_pest_error_leaves_no_result() {
+    let (result, errors) = parse_csv("");
+    assert_eq!(result, None);
+    assert_eq!(errors.len(), 1);
+}
+
+#[test]
+fn emitted_errors_point_at_the_offending_field() {
+    let (_, errors) = parse_csv("1, nope, 3");
+    assert_eq!(errors[0].line_col, LineColLocation::Span((1, 4), (1, 8)));
+}
@@ -0,0 +1,81 @@
+//! ## Threading a mutable context through the parser
+//!
+//! [User data](super::user_data) is cloned at every [`Node`], which is the right tool when the
+//! data is cheap to clone - a reference, a small config struct. But some use cases, like
+//! [string interning](https://en.wikipedia.org/wiki/String_interning) into a shared table or
+//! pushing nodes into an arena as you descend the tree, want a single value mutated in place, not
+//! cloned and reconciled. Doing that with user data forces an `Rc<RefCell<_>>` (or similar)
+//! around data that has no other reason to need interior mutability.
+//!
+//! [`Parser::parse_with_context`] is the dedicated tool for this instead: it takes a mutable
+//! borrow of a context value, not owned data, and that borrow is threaded through the whole
+//! consume pass rather than cloned. Unlike user data, the context does not need to implement
+//! `Clone` at all. Access it from a consuming method with [`Node::context`] or
+//! [`Node::context_mut`] - both can be called on any `Node` derived from the same context, even
+//! sibling `Node`s obtained from the same `Nodes`, since exclusivity between them is checked at
+//! runtime (like a [`RefCell`](std::cell::RefCell)) rather than by the borrow checker: a
+//! `context_mut` borrow that's still alive when another `Node` tries to access the context panics
+//! instead of quietly aliasing it.
+//!
+//! This is why the context is its own mechanism rather than just letting user data be `&mut T`:
+//! user data is cloned into every sibling `Node`/`Nodes` produced from the same parent, on the
+//! assumption that cloning it is cheap and sound to do freely - which is exactly what a bare
+//! `&mut T` cloned onto two siblings would *not* be, since both could then write through what
+//! Rust's aliasing rules require to be an exclusive borrow. A parse that only ever touches one
+//! live node's data at a time - depth-first, nothing held across a sibling - would make a single
+//! re-borrowed `&mut T` sound in principle, but `Node`/`Nodes` don't enforce that access pattern
+//! (nothing stops a consuming method from holding one sibling `Node` while inspecting another).
+//! The context's pointer-plus-runtime-lock design is what makes a real mutable borrow available
+//! without having to assume that pattern away: it allows the common case to just work, and panics
+//! instead of corrupting memory on the rare case that would have actually aliased.
+//!
+//! The context type is a third type parameter: `Node<'i, Rule, Data, Ctx>` and
+//! `Nodes<'i, Rule, Data, Ctx>` carry both the cloned `Data` and the borrowed `Ctx` side by side,
+//! so the two mechanisms can be combined if you need both cheap cloned settings and a mutable
+//! shared interner.
+//!
+//! ```ignore
+//! struct Interner {
+//!     strings: Vec<String>,
+//! }
+//!
+//! impl Interner {
+//!     fn intern(&mut self, s: &str) -> usize {
+//!         match self.strings.iter().position(|existing| existing == s) {
+//!             Some(id) => id,
+//!             None => {
+//!                 self.strings.push(s.to_owned());
+//!                 self.strings.len() - 1
+//!             }
+//!         }
+//!     }
+//! }
+//!
+//! // We changed the type alias to include the type of the context.
+//! type Node<'i> = pest_consume::Node<'i, Rule, (), Interner>;
+//!
+//! fn parse_with_interner(input_str: &str, interner: &mut Interner) -> Result<Vec<usize>> {
+//!     let inputs = CSVParser::parse_with_context(Rule::file, input_str, interner)?;
+//!     let input = inputs.single()?;
+//!     CSVParser::file(input)
+//! }
+//!
+//! impl CSVParser {
+//!     fn field(input: Node) -> Result<usize> {
+//!         // The context can be borrowed mutably from any Node, without needing `mut input` -
+//!         // exclusivity is checked at runtime, not by the borrow checker.
+//!         Ok(input.context_mut().intern(input.as_str()))
+//!     }
+//!     ...
+//! }
+//! ```
+//!
+//! [`parser`]: https://docs.rs/pest_consume_macros/1.0.1/pest_consume_macros/attr.parser.html
+//! [`Nodes`]: struct.Nodes.html
+//! [`Node`]: struct.Node.html
+//! [`Node::context`]: struct.Node.html#method.context
+//! [`Node::context_mut`]: struct.Node.html#method.context_mut
+//! [`Parser`]: trait.Parser.html
+//! [`Parser::parse_with_context`]: trait.Parser.html#method.parse_with_context
+//! [examples]: https://github.com/Nadrieril/pest_consume/tree/master/pest_consume/examples
+//! [dhall-rust-parser]: https://github.com/Nadrieril/dhall-rust/blob/master/dhall_syntax/src/parser.rs
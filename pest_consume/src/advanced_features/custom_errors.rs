@@ -0,0 +1,86 @@
+//! ## Reporting a custom error type instead of `Error<Rule>`
+//!
+//! This crate has no `#[pest_consume::parser]` macro to configure - the `impl` block of
+//! consuming methods shown elsewhere in these docs is ordinary hand-written Rust, not macro
+//! output, so there's no `#[parser(Error = MyError)]` attribute for it to accept. A consuming
+//! method is free to return `Result<T, MyError>` for any `MyError` today; nothing pins it to
+//! [`Error`](crate::Error).
+//!
+//! The one place `Error` shows up regardless is [`Parser::parse`] and its siblings, which only
+//! ever fail with `Error<Rule>` - that's pest's own grammar-level failure, before any consuming
+//! method runs. A top-level function that calls into both needs `MyError: From<Error<Rule>>` so
+//! `?` can convert that failure alongside its own:
+//!
+//! ```ignore
+//! #[derive(Debug)]
+//! enum MyError {
+//!     Grammar(pest_consume::Error<Rule>),
+//!     UndefinedVariable(String),
+//! }
+//!
+//! impl From<pest_consume::Error<Rule>> for MyError {
+//!     fn from(e: pest_consume::Error<Rule>) -> Self {
+//!         MyError::Grammar(e)
+//!     }
+//! }
+//!
+//! impl CSVParser {
+//!     fn variable(input: Node) -> Result<String, MyError> {
+//!         if is_defined(input.as_str()) {
+//!             Ok(input.as_str().to_owned())
+//!         } else {
+//!             // `Node::error_as` builds the same pointed-at-this-node `Error` that
+//!             // `Node::error` would, then converts it with `Into::into`.
+//!             Err(input.error_as("undefined variable"))
+//!         }
+//!     }
+//! }
+//!
+//! fn parse_csv(input_str: &str) -> Result<Vec<String>, MyError> {
+//!     let inputs = CSVParser::parse(Rule::file, input_str)?;
+//!     let input = inputs.single()?;
+//!     CSVParser::file(input)
+//! }
+//! ```
+//!
+//! [`Node::error_as`] exists purely for that last step: it's [`Node::error`] plus an `.into()`,
+//! so a method reporting `MyError` doesn't have to spell out the conversion at every call site.
+//!
+//! ## Matching on an error category instead of its message
+//!
+//! A caller further downstream that wants to branch on *why* a parse failed, rather than display
+//! the message, shouldn't have to pattern-match on substrings of it. [`Node::error_coded`] bundles
+//! a caller-defined code `C` alongside the built [`Error`] into a [`CodedError`]:
+//!
+//! ```ignore
+//! #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+//! enum ErrorCode {
+//!     UndefinedVariable,
+//!     TypeMismatch,
+//! }
+//!
+//! impl CSVParser {
+//!     fn variable(input: Node) -> Result<String, CodedError<Rule, ErrorCode>> {
+//!         if is_defined(input.as_str()) {
+//!             Ok(input.as_str().to_owned())
+//!         } else {
+//!             Err(input.error_coded(ErrorCode::UndefinedVariable, "undefined variable"))
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! `CodedError<Rule, ErrorCode>` can be returned directly, as above, or converted into a larger
+//! `MyError` the same way a plain [`Error`] would be - [`CodedError::code`] and
+//! [`CodedError::into_error`] give back the two halves for a `From` impl to redistribute into
+//! `MyError`'s own variants.
+//!
+//! [`Error`]: struct.Error.html
+//! [`CodedError`]: struct.CodedError.html
+//! [`CodedError::code`]: struct.CodedError.html#method.code
+//! [`CodedError::into_error`]: struct.CodedError.html#method.into_error
+//! [`Node`]: struct.Node.html
+//! [`Node::error`]: struct.Node.html#method.error
+//! [`Node::error_as`]: struct.Node.html#method.error_as
+//! [`Node::error_coded`]: struct.Node.html#method.error_coded
+//! [`Parser::parse`]: trait.Parser.html#method.parse
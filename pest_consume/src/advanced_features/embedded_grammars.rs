@@ -0,0 +1,74 @@
+//! ## Two grammars, two `Rule` types, one logical module
+//!
+//! This crate has no `#[pest_consume::parser]` macro - as [`custom_errors`](super::custom_errors)
+//! already notes, a consuming method is an ordinary associated function, and [`match_nodes!`]
+//! resolves `Self::rule_name` the way any other Rust call expression does, inferring which
+//! grammar's `Node<'i, Rule>` it's matching against from whatever sequence it was handed at the
+//! call site - not from anything declared once on an `impl` block or a macro attribute. There's
+//! consequently nothing to "tell" about which `Rule` a method belongs to, and nothing stopping two
+//! unrelated grammars' consuming methods from living in the same file, or even the same `impl`
+//! block's surrounding module, today:
+//!
+//! ```ignore
+//! #[derive(pest_derive::Parser)]
+//! #[grammar = "config.pest"]
+//! struct ConfigParser;
+//!
+//! #[derive(pest_derive::Parser)]
+//! #[grammar = "expr.pest"]
+//! struct ExprParser;
+//!
+//! impl pest_consume::Parser for ConfigParser {
+//!     type Rule = Rule; // config.pest's `Rule`
+//! }
+//! impl pest_consume::Parser for ExprParser {
+//!     type Rule = Rule; // expr.pest's `Rule` - same name, different type, different module
+//! }
+//!
+//! impl ConfigParser {
+//!     fn value(input: Node) -> Result<Value> {
+//!         match_nodes!(input.into_children();
+//!             [string(s)] => Ok(Value::String(s)),
+//!             [expr_value(e)] => Ok(Value::Expr(e)), // hands off to the other grammar below
+//!         )
+//!     }
+//! }
+//!
+//! impl ExprParser {
+//!     fn expr(input: Node) -> Result<Expr> { /* ... */ }
+//! }
+//! ```
+//!
+//! Each `impl` block's methods only ever see that grammar's own `Node<'i, Rule>`, so the two never
+//! get confused about which `Rule` enum a call resolves against - that's ordinary type-checking,
+//! not something this crate enforces specially. The only remaining question is how a config node
+//! hands its matched text over to the expression grammar in the first place.
+//!
+//! ## Crossing the boundary with [`Node::parse_embedded`]
+//!
+//! [`Node::parse_embedded`] re-parses a node's own [`as_str`](crate::Node::as_str) with a second
+//! [`Parser`], starting from whichever rule is the second grammar's entry point - a thin wrapper
+//! around `P::parse(rule, self.as_str())`, so the embedding itself is one method call rather than
+//! code repeated at every site that needs it:
+//!
+//! ```ignore
+//! impl ConfigParser {
+//!     fn expr_value(input: Node) -> Result<Expr> {
+//!         let exprs = input.parse_embedded::<ExprParser>(expr::Rule::expr)?;
+//!         ExprParser::expr(exprs.single()?)
+//!     }
+//! }
+//! ```
+//!
+//! The returned [`Nodes`] borrows the same underlying `&str` the config node matched - there's no
+//! copy, and no second source string to keep alive separately - so an error from the embedded
+//! parse still points at a real span into the original input. That span is relative to the
+//! re-parsed substring rather than the whole file, the same caveat any [`Parser::parse`] call on a
+//! substring already has; see [`source_edits`](super::source_edits) for recovering whole-file
+//! positions from a sub-slice when that matters.
+//!
+//! [`match_nodes!`]: crate::match_nodes
+//! [`Node::parse_embedded`]: crate::Node::parse_embedded
+//! [`Nodes`]: crate::Nodes
+//! [`Parser`]: crate::Parser
+//! [`Parser::parse`]: crate::Parser::parse
@@ -0,0 +1,33 @@
+//! ## One call instead of parse, then `single`, then dispatch
+//!
+//! This crate has no attribute macro of its own - `impl Parser for MyParser` is a plain trait
+//! impl, and the `#[derive(Parser)]` that generates `Rule` and the underlying
+//! `pest::Parser<Rule>` impl belongs to `pest_derive`, a separate crate this one doesn't control.
+//! So an `#[entry]` placed on a consuming method has nothing to hook into on this crate's side -
+//! there's no macro expansion step here that could see the attribute, read the annotated
+//! function's name, and emit a sibling free function named after it.
+//!
+//! What every entry point actually repeats isn't the naming, though - it's the three calls: parse,
+//! pull the single root [`Node`] out of the result, then hand it to the root consuming method.
+//! [`Parser::parse_entry`] (and [`Parser::parse_entry_with_userdata`] for the user-data case)
+//! collapses exactly that into one call, taking the dispatch step as a closure rather than
+//! generating a differently-named function per grammar:
+//!
+//! ```ignore
+//! impl CSVParser {
+//!     fn file(input: Node) -> Result<Vec<Vec<String>>> {
+//!         /* ... */
+//!     }
+//! }
+//!
+//! // Instead of:
+//! let ast = CSVParser::parse(Rule::file, input)?.single()?;
+//! let ast = CSVParser::file(ast)?;
+//!
+//! // One call:
+//! let ast = CSVParser::parse_entry(Rule::file, input, CSVParser::file)?;
+//! ```
+//!
+//! [`Node`]: crate::Node
+//! [`Parser::parse_entry`]: crate::Parser::parse_entry
+//! [`Parser::parse_entry_with_userdata`]: crate::Parser::parse_entry_with_userdata
@@ -0,0 +1,220 @@
+//! ## Collecting every error instead of stopping at the first
+//!
+//! A consuming method that returns `Err` aborts the whole parse right there: fine for a
+//! compiler that only needs to report one problem at a time, painful for tooling - a linter, an
+//! IDE, a batch validator - that wants to surface every issue it can find in a single pass. Today
+//! that means calling a consuming method once per error, which defeats the point of writing one
+//! pass over the tree.
+//!
+//! [`Parser::parse_collecting_errors`] is a diagnostics-oriented alternative to [`Parser::parse`]
+//! for this. It still runs the ordinary consume pass, but a consuming method that hits a
+//! non-fatal problem can call [`Node::emit_error`] to record an [`Error`] into a shared buffer and
+//! then keep going, returning whatever placeholder or default value lets its caller carry on.
+//! The pass runs to completion and `parse_collecting_errors` returns `(Option<T>, Vec<Error>)`:
+//! `Some(T)` alongside every emitted error if the top-level method still produced a value, `None`
+//! alongside them if a fatal error (an ordinary `Err` that was allowed to propagate) cut the pass
+//! short instead.
+//!
+//! Unlike [`Parser::parse`] and [`Parser::parse_with_userdata`], which hand back the parsed
+//! [`Nodes`] and leave calling a top-level consuming method to you, `parse_collecting_errors`
+//! takes that final step as a closure. The error buffer has to exist before anything - including
+//! picking the root [`Node`] out of the parsed [`Nodes`] with `Nodes::single` - runs, since any
+//! of it might call [`Node::emit_error`].
+//!
+//! ```ignore
+//! impl CSVParser {
+//!     fn record(input: Node) -> Result<Vec<f64>> {
+//!         match_nodes!(input.into_children();
+//!             [field(fields)..] => Ok(fields),
+//!         )
+//!     }
+//!     fn field(input: Node) -> Result<f64> {
+//!         match input.as_str().parse() {
+//!             Ok(n) => Ok(n),
+//!             Err(_) => {
+//!                 // Record the problem and let the record carry on with a placeholder.
+//!                 input.emit_error(input.error("not a number"));
+//!                 Ok(0.0)
+//!             }
+//!         }
+//!     }
+//! }
+//!
+//! fn parse_csv(input_str: &str) -> (Option<Vec<f64>>, Vec<pest_consume::Error<Rule>>) {
+//!     CSVParser::parse_collecting_errors(Rule::file, input_str, |inputs| {
+//!         let input = inputs.single()?;
+//!         CSVParser::record(input)
+//!     })
+//! }
+//! ```
+//!
+//! Every malformed field is reported this way rather than only the first one encountered.
+//!
+//! ## Collecting errors from a single child sequence
+//!
+//! [`Node::emit_error`] is the right tool when the problems can surface anywhere across a whole
+//! consume pass. For the narrower case of validating one sequence of same-shaped children - every
+//! `field` of a `record`, say - [`Nodes::consume_all`] is simpler: it runs `f` over every
+//! remaining node, but instead of stopping at the first `Err` the way [`Nodes::map_to_vec`] does,
+//! it keeps going and returns `(Vec<T>, Vec<Error>)` - every successfully-mapped value alongside
+//! every error.
+//!
+//! ```ignore
+//! fn record(input: Node) -> Result<Vec<f64>> {
+//!     let (fields, errors) = input.into_children().consume_all(Self::field);
+//!     if errors.is_empty() {
+//!         Ok(fields)
+//!     } else {
+//!         Err(errors.into_iter().next().unwrap())
+//!     }
+//! }
+//! fn field(input: Node) -> Result<f64> {
+//!     input.as_str().parse().map_err(|_| input.error("not a number"))
+//! }
+//! ```
+//!
+//! ## Recovering at synchronization points
+//!
+//! [`Nodes::consume_all`] treats every remaining node the same way. A block of statements is
+//! different: a malformed statement shouldn't take the rest of the block down with it, but the
+//! statements themselves don't share a uniform shape the way CSV fields do, so `f` needs to run
+//! over each statement's own children rather than one node at a time.
+//! [`Nodes::consume_with_recovery`] is for this: given a `sync` rule that separates statements -
+//! a `;`, say - it splits the sequence into groups at each `sync` node (discarding the `sync`
+//! nodes themselves), and runs `f` once per group. A group that errors doesn't affect any other
+//! group, since the boundaries are already fixed by where `sync` matched - there's no need to
+//! guess how far a failed `f` actually got before giving up on it.
+//!
+//! ```ignore
+//! impl BlockParser {
+//!     fn block(input: Node) -> (Vec<Stmt>, Vec<pest_consume::Error<Rule>>) {
+//!         input.into_children().consume_with_recovery(Rule::semi, |group| {
+//!             Self::statement(group.single()?)
+//!         })
+//!     }
+//!     fn statement(input: Node) -> Result<Stmt> {
+//!         ...
+//!     }
+//! }
+//! ```
+//!
+//! This is the shape an IDE or linter usually wants from a block: every statement that parsed
+//! fine, plus one diagnostic per statement that didn't, instead of the whole block disappearing
+//! behind the first mistake.
+//!
+//! ## Presenting accumulated errors in source order
+//!
+//! Every way of accumulating more than one [`Error`] - [`Node::emit_error`],
+//! [`Nodes::consume_all`], [`Nodes::consume_with_recovery`] - records them in traversal order,
+//! which rarely matches where they are in the source. [`sort_errors_by_position`] sorts a `Vec`
+//! of errors by their span's (or position's) start, and drops exact duplicates left adjacent by
+//! the sort - the same duplicate a retried sub-parse or an overlapping recovery group can leave
+//! behind.
+//!
+//! ```ignore
+//! let (result, mut errors) = CSVParser::parse_collecting_errors(Rule::file, input, |inputs| {
+//!     CSVParser::record(inputs.single()?)
+//! });
+//! pest_consume::sort_errors_by_position(&mut errors);
+//! ```
+//!
+//! ## Non-fatal warnings alongside a successful parse
+//!
+//! `Node::emit_error`/`parse_collecting_errors` are for problems severe enough that the pass
+//! might still fail overall (a `None` result alongside them). A linter that wants to report
+//! purely advisory issues - deprecated syntax, say - while *always* getting its AST back on a
+//! successful parse instead reaches for [`Node::warn`] and [`Parser::parse_collecting_warnings`]:
+//!
+//! ```ignore
+//! impl CSVParser {
+//!     fn field(input: Node) -> Result<f64> {
+//!         if input.as_str().starts_with('+') {
+//!             input.warn("a leading '+' on a number is deprecated");
+//!         }
+//!         input.as_str().trim_start_matches('+').parse().map_err(|_| input.error("not a number"))
+//!     }
+//!     ...
+//! }
+//!
+//! fn parse_csv(input_str: &str) -> Result<(Vec<f64>, Vec<pest_consume::Error<Rule>>)> {
+//!     CSVParser::parse_collecting_warnings(Rule::file, input_str, |inputs| {
+//!         let input = inputs.single()?;
+//!         CSVParser::record(input)
+//!     })
+//! }
+//! ```
+//!
+//! Unlike `parse_collecting_errors`, a warning never turns success into failure: the return type
+//! is `Result<(T, Vec<Error>), Error>`, where the outer `Err` is only reached the same way it
+//! would be from [`Parser::parse`] - an ordinary propagated `Err`, or a failure from pest itself.
+//!
+//! ## Recovering a partial tree when pest's own grammar match fails
+//!
+//! Every recovery tool above runs *after* pest has already matched `rule` against the whole
+//! input - they're for a consuming method that wants to keep going past its own mistake, not for
+//! a grammar match that never completed in the first place. When pest itself fails partway
+//! through - the input has a syntax error pest can't get past - [`Parser::parse`] gives back only
+//! an `Err`, with no tree at all, since pest never finishes building one to hand back.
+//!
+//! [`Parser::parse_partial`] is the entry point for that case: it still returns the same `Err` on
+//! failure, but alongside it, tries to recover a [`Nodes`] for whatever prefix of the input did
+//! parse cleanly - useful for a language server that wants to keep offering completions and
+//! diagnostics on the rest of a file despite one unfinished statement.
+//!
+//! ```ignore
+//! let (partial, error) = CSVParser::parse_partial(Rule::file, input_str);
+//! if let Some(error) = error {
+//!     report(error);
+//! }
+//! if let Some(inputs) = partial {
+//!     // Whatever full records parsed before the syntax error, still available for the rest of
+//!     // the IDE's features to work with.
+//!     render_records(CSVParser::record(inputs.single()?)?);
+//! }
+//! ```
+//!
+//! Recovering that prefix means finding the longest byte range, starting from the beginning of
+//! the input, that's *itself* a complete match for `rule` on its own - pest exposes no partial
+//! token queue to salvage from a failed match (see [`tree_transforms`] for why), so the only way
+//! to find one is to retry the parse against shorter and shorter candidates, the same technique
+//! [`Node::leading_trivia`]/[`Node::trailing_trivia`]'s reconstruction already leans on. Whether
+//! that search ever succeeds is entirely a function of how `rule` is written:
+//!
+//! - **A rule ending in `item* ~ EOI` recovers well.** A prefix ending right after the last
+//!   complete `item` and before whatever broke - the unparsed remainder simply isn't there yet -
+//!   is a complete match for `rule` on its own, so the search finds it.
+//! - **A rule ending in `item+ ~ EOI` recovers just as well once at least one `item` has
+//!   matched**, but gives up entirely (`None`) if the very first one is malformed, since no
+//!   non-empty prefix can satisfy the `+`.
+//! - **A rule with no repetition at the top - a single nested expression grammar, say - almost
+//!   never recovers**, since any prefix short enough to avoid the syntax error is usually also
+//!   too short to satisfy the rest of the rule's structure. Recovery here has to happen inside the
+//!   grammar (an explicit `recover` alternative, or structuring the top rule as a list of
+//!   sub-expressions) rather than from the outside.
+//!
+//! Retrying shorter and shorter candidates means the search is only ever as expensive as the
+//! number of candidates it tries, each a full reparse of that candidate - see
+//! [`Parser::parse_partial`]'s own doc comment for the bound this crate puts on that count, so a
+//! syntax error near the end of a large input can't turn recovery itself into the slow part of
+//! the parse.
+//!
+//! [`match_nodes!`]: macro.match_nodes.html
+//! [`Nodes`]: struct.Nodes.html
+//! [`Node`]: struct.Node.html
+//! [`Node::emit_error`]: struct.Node.html#method.emit_error
+//! [`Node::warn`]: struct.Node.html#method.warn
+//! [`Node::leading_trivia`]: struct.Node.html#method.leading_trivia
+//! [`Node::trailing_trivia`]: struct.Node.html#method.trailing_trivia
+//! [`Nodes::consume_all`]: struct.Nodes.html#method.consume_all
+//! [`Nodes::consume_with_recovery`]: struct.Nodes.html#method.consume_with_recovery
+//! [`sort_errors_by_position`]: ../fn.sort_errors_by_position.html
+//! [`Nodes::map_to_vec`]: struct.Nodes.html#method.map_to_vec
+//! [`Error`]: struct.Error.html
+//! [`Parser`]: trait.Parser.html
+//! [`Parser::parse`]: trait.Parser.html#method.parse
+//! [`Parser::parse_collecting_errors`]: trait.Parser.html#method.parse_collecting_errors
+//! [`Parser::parse_collecting_warnings`]: trait.Parser.html#method.parse_collecting_warnings
+//! [`Parser::parse_partial`]: trait.Parser.html#method.parse_partial
+//! [`tree_transforms`]: super::tree_transforms
+//! [examples]: https://github.com/Nadrieril/pest_consume/tree/master/pest_consume/examples
+//! [dhall-rust-parser]: https://github.com/Nadrieril/dhall-rust/blob/master/dhall_syntax/src/parser.rs
@@ -0,0 +1,71 @@
+//! ## Splitting consuming methods across dialect crates
+//!
+//! This crate has no `#[pest_consume::parser]` macro - as [`custom_errors`](super::custom_errors)
+//! already notes, the `impl` block of consuming methods shown throughout these docs is ordinary
+//! hand-written Rust, not macro output. That rules out a macro-level mechanism for spreading one
+//! grammar's rules across several `impl` blocks, or for a dialect crate to register new rule
+//! handlers into a base parser it doesn't own: there's no attribute to carry a registry, and
+//! [`match_nodes!`] resolves `rule_name` to an associated function by its literal identifier at
+//! compile time, not through anything a dialect crate could hook into at a distance.
+//!
+//! What *is* available today, with no new mechanism needed, is dispatching by hand instead of
+//! through `match_nodes!`, using [`Nodes::peek_rule`] and [`Nodes::next_node`] - the same escape
+//! valve [`match_nodes!`] itself points to for unwieldy rule names. A base parser can consult a
+//! `Rule`-keyed table of handlers built up from several sources, falling back to it only for the
+//! rules it doesn't recognize itself:
+//!
+//! ```ignore
+//! type DialectHandler = fn(Node) -> Result<Stmt, Error<Rule>>;
+//!
+//! impl CoreParser {
+//!     fn stmt(input: Node, dialect: &HashMap<Rule, DialectHandler>) -> Result<Stmt, Error<Rule>> {
+//!         match input.as_rule() {
+//!             Rule::if_stmt => Self::if_stmt(input),
+//!             Rule::while_stmt => Self::while_stmt(input),
+//!             rule => match dialect.get(&rule) {
+//!                 Some(handler) => handler(input),
+//!                 // `Node::error_no_consuming_method` reports this the same way `match_nodes!`
+//!                 // would have, had it been able to check for a missing handler here too.
+//!                 None => Err(input.error_no_consuming_method()),
+//!             },
+//!         }
+//!     }
+//!
+//!     fn block(input: Node, dialect: &HashMap<Rule, DialectHandler>) -> Result<Vec<Stmt>, Error<Rule>> {
+//!         let mut stmts = input.into_children();
+//!         let mut out = Vec::new();
+//!         while stmts.peek_rule().is_some() {
+//!             out.push(Self::stmt(stmts.next_node().unwrap(), dialect)?);
+//!         }
+//!         Ok(out)
+//!     }
+//! }
+//! ```
+//!
+//! A dialect crate builds its own `HashMap<Rule, DialectHandler>` - e.g. `{Rule::goto_stmt:
+//! goto_stmt as DialectHandler}` - and the application wires it in by passing that table down
+//! alongside [user data](super::user_data), which already threads arbitrary caller state through
+//! every consuming method the same way. This gives up `match_nodes!`'s concise patterns for the
+//! rules dispatched this way, but keeps the rest of a grammar's consuming methods exactly as
+//! written elsewhere in these docs - including [`Node::error_no_consuming_method`] for the
+//! fallback arm, so a rule nobody registered a handler for still reports the same message
+//! `match_nodes!`'s own compile-time check would have named it with, had one been possible here.
+//!
+//! ## A generated `dispatch` function for a data-driven tree walk
+//!
+//! For the same reason, there's no `#[pest_consume::parser]`-generated `dispatch(node: Node) ->
+//! Result<Value>` that matches every method in an `impl` against the node's rule automatically -
+//! there's no macro attribute on the `impl` block for such a thing to hang off of, and this crate
+//! has no visibility into a method's return type to check that they all unify into one `Value`
+//! besides. The `HashMap<Rule, DialectHandler>` above is the same mechanism that covers this case
+//! too, just built from every rule in the grammar instead of only the ones a dialect crate adds -
+//! `{Rule::if_stmt: CoreParser::if_stmt as DialectHandler, Rule::while_stmt:
+//! CoreParser::while_stmt as DialectHandler, ...}` - since a plain `fn` pointer already requires
+//! every entry to share one signature, which gives the "methods whose return types don't unify"
+//! constraint the request asked for, for free, as a compile error at the table's construction
+//! site rather than a constraint that would need documenting separately.
+//!
+//! [`match_nodes!`]: ../macro.match_nodes.html
+//! [`Nodes::peek_rule`]: ../struct.Nodes.html#method.peek_rule
+//! [`Nodes::next_node`]: ../struct.Nodes.html#method.next_node
+//! [`Node::error_no_consuming_method`]: ../struct.Node.html#method.error_no_consuming_method
@@ -0,0 +1,39 @@
+//! ## Reporting which grammar rules a parse actually exercised
+//!
+//! A big grammar often grows rules that a given test corpus never reaches - a rarely-used syntax
+//! form, an error-recovery branch, an alternative nobody wrote a test for. [`check_cancelled`]-style
+//! instrumentation (cancellation, the recursion limit) all answer "did the parse stay within some
+//! bound"; this answers a different question: "which rules did this parse actually visit".
+//!
+//! [`Parser::parse_with_coverage`] takes a `&mut HashSet<Rule>` alongside the usual `rule` and
+//! `input_str`, and every node consumed during the pass - via [`next_node`] directly, or through
+//! [`match_nodes!`], which is built on top of it - records its own rule into that set as it's
+//! visited. Once the pass finishes, the set holds exactly the rules the input exercised, so
+//! running it over a whole test corpus and diffing the result against every `Rule` variant (e.g.
+//! with a generated `Rule::COUNT` or a plain `match` that lists them all) surfaces the ones no
+//! test ever reached.
+//!
+//! ```ignore
+//! use std::collections::HashSet;
+//!
+//! let mut coverage = HashSet::new();
+//! for input_str in test_corpus() {
+//!     let inputs = CalcParser::parse_with_coverage(Rule::calculation, input_str, &mut coverage)?;
+//!     let input = inputs.single()?;
+//!     CalcParser::calculation(input)?;
+//! }
+//!
+//! let untested: Vec<Rule> = Rule::all_rules().into_iter().filter(|r| !coverage.contains(r)).collect();
+//! ```
+//!
+//! Like [`advanced_features::cancellation`]'s `cancel_token`, dispatching by hand instead of
+//! through `match_nodes!` still records coverage as long as it goes through [`next_node`] - only
+//! bypassing `Nodes` entirely (e.g. walking `Pair`s directly) would miss it. Outside of
+//! `parse_with_coverage`, there is no set to record into, so visiting a node is a no-op as far as
+//! coverage goes, the same as every other entry point.
+//!
+//! [`Parser::parse_with_coverage`]: trait.Parser.html#method.parse_with_coverage
+//! [`next_node`]: struct.Nodes.html#method.next_node
+//! [`match_nodes!`]: macro.match_nodes.html
+//! [`check_cancelled`]: struct.Nodes.html#method.check_cancelled
+//! [`advanced_features::cancellation`]: super::cancellation
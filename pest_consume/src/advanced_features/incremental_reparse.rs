@@ -0,0 +1,32 @@
+//! ## Re-parsing a single subtree instead of the whole document
+//!
+//! [`Parser::reparse`] parses just `substring` starting from `rule`, exactly like
+//! [`parse`](crate::Parser::parse) would against that slice alone:
+//!
+//! ```ignore
+//! let edited_item = &document[item_start..item_end];
+//! let inputs = ItemParser::reparse(Rule::item, edited_item)?;
+//! let node = inputs.single()?;
+//! ItemParser::item(node)
+//! ```
+//!
+//! This is as far as this crate can go on its own toward incremental re-parsing. What it can't do
+//! is splice that result back into a tree parsed from the original, larger document with its byte
+//! offsets rebased to match: every [`Node`] and [`Nodes`] borrows its [`Pair`]/[`pest::Span`]
+//! directly from the exact `&str` [`pest::Parser::parse`] was called against, and pest exposes no
+//! way to build a [`pest::Span`] whose offsets point into a *different* string than the one it was
+//! matched over. Rebasing would mean fabricating spans pest never actually matched - unsound, and
+//! not something pest's own API surface permits doing safely.
+//!
+//! A caching layer built on [`reparse`](crate::Parser::reparse) therefore has to track the substring's
+//! own offset within the document itself (ordinary integer arithmetic - `node.as_span().start() +
+//! item_start` - rather than anything this crate can attach to the `Node`), and replace the stale
+//! top-level item's cached result with the freshly re-parsed one rather than grafting pest trees
+//! together.
+//!
+//! [`Node`]: struct.Node.html
+//! [`Nodes`]: struct.Nodes.html
+//! [`Pair`]: https://docs.rs/pest/latest/pest/iterators/struct.Pair.html
+//! [`Parser::reparse`]: trait.Parser.html#method.reparse
+//! [`Parser::parse`]: trait.Parser.html#method.parse
+//! [`pest::Parser::parse`]: https://docs.rs/pest/latest/pest/trait.Parser.html#tymethod.parse
@@ -0,0 +1,42 @@
+//! ## Matching on a leaf's literal text, not just its rule
+//!
+//! A grammar that reuses one rule for a handful of fixed keywords, e.g. `keyword = @{ "if" | "while"
+//! | "for" }`, pushes the job of telling them apart onto the consuming method - usually a `match` on
+//! [`as_str`](crate::Node::as_str) right after [`match_nodes!`] has already bound the node:
+//!
+//! ```ignore
+//! fn stmt(input: Node) -> Result<Stmt> {
+//!     match_nodes!(input.into_children();
+//!         [keyword(kw), expr(c), block(b)] => match kw.as_str() {
+//!             "if" => Ok(Stmt::If(c, b)),
+//!             "while" => Ok(Stmt::While(c, b)),
+//!             _ => unreachable!(),
+//!         },
+//!     )
+//! }
+//! ```
+//!
+//! Writing a string literal in place of a binding, `keyword("if")`, moves that check into the
+//! pattern itself: the slot matches only when the node's rule is `keyword` *and* its `as_str()`
+//! equals `"if"` exactly, and it produces no binding at all, since there's nothing left to bind.
+//! One arm per keyword reads like a dispatch table, and a keyword nobody handles falls all the way
+//! through to [`match_nodes!`]'s own "no arm matched" error instead of an `unreachable!()` that
+//! quietly lies if the grammar ever grows a new keyword:
+//!
+//! ```ignore
+//! fn stmt(input: Node) -> Result<Stmt> {
+//!     match_nodes!(input.into_children();
+//!         [keyword("if"), expr(c), block(b)] => Ok(Stmt::If(c, b)),
+//!         [keyword("while"), expr(c), block(b)] => Ok(Stmt::While(c, b)),
+//!     )
+//! }
+//! ```
+//!
+//! A mismatched literal behaves exactly like a mismatched rule: the arm is skipped and the next one
+//! gets a fresh look at the same nodes, rather than the whole dispatch erroring out. This only
+//! applies to a required slot in an otherwise ordinary `[pattern] => expr` arm - it isn't supported
+//! together with `?`, a trailing `..` capture, a `collect_*` body, a guard, tags, or groups, all of
+//! which already describe their own restrictions in [`match_nodes!`]'s own docs.
+//!
+//! [`match_nodes!`]: crate::match_nodes
+//! [`Node`]: crate::Node
@@ -0,0 +1,39 @@
+//! ## Memoizing an expensive consuming method
+//!
+//! [As noted](super::custom_errors), this crate has no `#[pest_consume::parser]` macro, so there's
+//! no attribute to generate a memoized wrapper around a consuming method - but the same caching can
+//! be done by hand with [`Node::memoize`], for a grammar ambiguous enough (or a method expensive
+//! enough) that the same sub-span ends up consumed more than once, e.g. backtracking across
+//! alternatives that share a prefix.
+//!
+//! [`Memo`] is a cache keyed by `(rule, span)`, owned by the caller rather than threaded through
+//! the parse automatically - store it alongside the rest of a parse's state (a local variable, or
+//! a field on [user data](super::user_data)/[context](super::context) if every node needs to reach
+//! the same cache) and pass it to [`Node::memoize`]. The wrapped closure's return type must be
+//! `Clone`, since a cache hit hands out a clone of the previously computed value rather than a
+//! reference to it.
+//!
+//! ```ignore
+//! // User data is cheap to clone and shared by every sibling `Node`, so storing the cache there
+//! // (rather than a context, or a variable passed down by hand) is enough for every node to reach
+//! // the same one. See `advanced_features::user_data`.
+//! type Node<'i> = pest_consume::Node<'i, Rule, Rc<Memo<'i, Rule, Ast>>>;
+//!
+//! impl ExprParser {
+//!     fn expr(input: Node) -> Result<Ast> {
+//!         input.memoize(input.user_data(), |input| {
+//!             match_nodes!(input.into_children();
+//!                 [literal(l)] => Ok(Ast::Literal(l)),
+//!                 [expr(lhs), expr(rhs)] => Ok(Ast::Add(Box::new(lhs), Box::new(rhs))),
+//!             )
+//!         })
+//!     }
+//! }
+//! ```
+//!
+//! Since the cache lives outside the `Node`/`Nodes` themselves, nothing stops two independent
+//! parses from sharing one - or a single method from being memoized by one cache while another
+//! method on the same node uses a different one, keyed separately.
+//!
+//! [`Memo`]: ../struct.Memo.html
+//! [`Node::memoize`]: ../struct.Node.html#method.memoize
@@ -0,0 +1,28 @@
+//! ## `miette`-compatible error output
+//!
+//! Enabled by the `miette` feature. [`Error`](crate::Error) - a plain re-export of
+//! [`pest::error::Error`] - implements [`std::error::Error`] and [`Display`](std::fmt::Display)
+//! already, but it doesn't know the full source text it was parsed from (only a single line of
+//! context), so it can't render the nicely underlined, in-context diagnostics a
+//! [`miette::Diagnostic`] gets for free.
+//!
+//! [`IntoMietteError::with_source`] attaches that source text, producing a [`MietteError`] that
+//! does implement `miette::Diagnostic`: its span points at the exact offending byte range, and its
+//! label carries the same message [`Error`]'s own `Display` impl would print (the expected/
+//! unexpected rules, or a custom message).
+//!
+//! ```ignore
+//! use pest_consume::IntoMietteError;
+//!
+//! fn main() -> miette::Result<()> {
+//!     let input = std::fs::read_to_string("input.csv").unwrap();
+//!     let records = CSVParser::parse(Rule::file, &input)
+//!         .map_err(|e| e.with_source(input.clone()))?;
+//!     // ...
+//!     Ok(())
+//! }
+//! ```
+//!
+//! [`Error`]: crate::Error
+//! [`IntoMietteError::with_source`]: crate::IntoMietteError::with_source
+//! [`MietteError`]: crate::MietteError
@@ -0,0 +1,31 @@
+//! ## Naming the source a parse came from
+//!
+//! [pest]'s own [`Error`] can carry a path, shown alongside the line/column when the error is
+//! displayed - useful for a tool that parses more than one file and wants every error to say which
+//! one it came from. Ordinarily that means calling [`with_path`] on every [`Error`] by hand after
+//! the fact, which gets lost the moment an error is wrapped into a caller's own error type before
+//! it's displayed.
+//!
+//! [`Parser::parse_named`] takes the path once, at `parse` time, and threads it through - every
+//! [`Node`]/[`Nodes`] produced while descending from it carries the same path, so [`Node::error`]
+//! and [`Nodes::error`] attach it automatically, with no further bookkeeping at the call site.
+//!
+//! ```ignore
+//! let inputs = CalcParser::parse_named(Rule::calculation, input_str, "input.calc")?;
+//! let input = inputs.single()?;
+//! CalcParser::calculation(input)
+//! // Any error built from a node below `input` now displays "--> input.calc" alongside its
+//! // line/column, the same as if `.with_path("input.calc")` had been called on it directly.
+//! ```
+//!
+//! Outside of `parse_named`, there is no path to attach, so [`Node::error`]/[`Nodes::error`] behave
+//! exactly as they always have.
+//!
+//! [pest]: https://pest.rs
+//! [`Error`]: ../struct.Error.html
+//! [`with_path`]: https://docs.rs/pest/latest/pest/error/struct.Error.html#method.with_path
+//! [`Parser::parse_named`]: trait.Parser.html#method.parse_named
+//! [`Node`]: struct.Node.html
+//! [`Nodes`]: struct.Nodes.html
+//! [`Node::error`]: struct.Node.html#method.error
+//! [`Nodes::error`]: struct.Nodes.html#method.error
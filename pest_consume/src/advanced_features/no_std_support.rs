@@ -0,0 +1,41 @@
+//! ## Where this crate stands on `no_std` + `alloc`
+//!
+//! [pest] itself is already `#![no_std]` (it only needs `alloc`), so a `no_std` build of this
+//! crate's core tree-walking path - [`Node`], [`Nodes`], [`match_nodes!`] - isn't blocked by the
+//! parser underneath it. This crate isn't there yet, but the gap is narrow enough to say exactly
+//! what it is rather than leave it as an open question:
+//!
+//! - **[`Parser::parse_from_reader`]/[`ReadError`]** are the one piece that's inherently
+//!   `std`-only - [`std::io::Read`] has no `alloc`-based equivalent on stable Rust - so they're
+//!   now gated behind a `std` feature (on by default, to keep existing callers unaffected) rather
+//!   than compiled unconditionally. Building with `default-features = false` drops them.
+//! - **`Rc`/`RefCell`**, used throughout for the context/error/warning sharing described in
+//!   [`context`](super::context) and [`error_recovery`](super::error_recovery), already have
+//!   direct equivalents in `alloc`/`core` (`alloc::rc::Rc`, `core::cell::RefCell`) - swapping the
+//!   imports is mechanical.
+//! - **`AtomicBool`**, used by [`Parser::parse_with_cancel`] (see
+//!   [`cancellation`](super::cancellation)), has a `core::sync::atomic` equivalent on every
+//!   target that supports atomics at all.
+//! - **`HashMap`/`HashSet`**, used by [`memoization`](super::memoization) and
+//!   [`grammar_coverage`](super::grammar_coverage) among others, have no `core`/`alloc`
+//!   equivalent - `alloc` only has `BTreeMap`/`BTreeSet`, which need `R: Ord` rather than `Hash`
+//!   (already true of every [`RuleType`](pest::RuleType), so this swap is feasible, just not a
+//!   one-line import change like the two above) or a dependency on a hasher crate like
+//!   `hashbrown`.
+//! - **The optional `ariadne`/`miette`/`ouroboros` integrations** depend on crates that are
+//!   themselves `std`-oriented; supporting them under `no_std` isn't this crate's call to make
+//!   and isn't attempted here.
+//!
+//! So this change lands the one boundary that's unambiguous - `std::io` behind its own feature -
+//! and documents the rest rather than doing a speculative `core`/`alloc` rewrite of the whole
+//! crate with no `no_std` target available to actually build and test it against. Swapping
+//! `HashMap`/`HashSet` for `BTreeMap`/`BTreeSet` crate-wide would be the next real step toward
+//! full `no_std` support.
+//!
+//! [pest]: https://docs.rs/pest
+//! [`Node`]: crate::Node
+//! [`Nodes`]: crate::Nodes
+//! [`match_nodes!`]: crate::match_nodes
+//! [`Parser::parse_from_reader`]: crate::Parser::parse_from_reader
+//! [`Parser::parse_with_cancel`]: crate::Parser::parse_with_cancel
+//! [`ReadError`]: crate::ReadError
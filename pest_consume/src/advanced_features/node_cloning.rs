@@ -0,0 +1,25 @@
+//! ## `Node::clone` shares a handle, it doesn't re-walk the subtree
+//!
+//! A [`Node`] is a thin handle - a [`pest::iterators::Pair`] (itself an `Rc`-shared token queue
+//! plus an index into it), the current [user data](super::user_data), and a handful of
+//! [`Rc`](std::rc::Rc)-shared or raw-pointer fields threaded from the parse. Cloning one clones
+//! those fields, not the span of source it covers or the children underneath it, so two handles
+//! to the same node - one to extract a span, another to recurse into its children - cost the same
+//! to obtain near the root of a large tree as on a one-token leaf:
+//!
+//! ```ignore
+//! fn visit(node: Node) -> Result<()> {
+//!     let span_copy = node.clone(); // O(1) - no subtree touched
+//!     record_span(span_copy.as_span());
+//!     for child in node.into_children() {
+//!         visit(child)?;
+//!     }
+//!     Ok(())
+//! }
+//! ```
+//!
+//! [Context](super::context) and the [error](super::error_recovery)/[warning](super::error_recovery)
+//! buffers are shared the same way: a clone mutates or records into the very same one the original
+//! was given, rather than an independent copy that changes wouldn't show up through.
+//!
+//! [`Node`]: crate::Node
@@ -0,0 +1,83 @@
+//! ## Filtering out interleaved nodes before matching
+//!
+//! Some rules interleave the nodes a consuming method actually cares about with ones that can't
+//! be made silent at the grammar level - a `comment` rule allowed to appear between any two
+//! tokens, say. [`match_nodes!`] matches the sequence it's given as-is, so such a rule would need
+//! every arm to account for an optional `comment` before, after, or between every other item.
+//!
+//! [`Nodes::exclude_rule`] and [`Nodes::filter_rule`] solve this by dropping nodes before
+//! `match_nodes!` ever sees them: `exclude_rule(Rule::comment)` removes every `comment` node from
+//! the remaining sequence, `filter_rule(Rule::comment)` keeps only them, and both preserve the
+//! order and user data of whatever's left.
+//!
+//! ```ignore
+//! impl CodeParser {
+//!     fn statement_list(input: Node) -> Result<Vec<Stmt>> {
+//!         match_nodes!(input.into_children().exclude_rule(Rule::comment);
+//!             [statement(s)..] => Ok(s),
+//!         )
+//!     }
+//!     ...
+//! }
+//! ```
+//!
+//! ## Skipping a rule at every level, for the whole parse
+//!
+//! [`Nodes::exclude_rule`]/[`Nodes::filter_rule`] act on one sequence at a time, so a rule that
+//! interleaves at several levels of the tree - a non-silent `WHITESPACE` rule, say, kept
+//! non-silent because one specific consuming method does care about its span - needs the call
+//! repeated in every consuming method it shows up under. [`Parser::parse_with_options`] instead
+//! takes a [`ParseOptions`] listing rules to drop from every [`Nodes`] sequence for the rest of
+//! that parse, at any depth of descent:
+//!
+//! ```ignore
+//! impl CodeParser {
+//!     fn statement_list(input: Node) -> Result<Vec<Stmt>> {
+//!         // No `.exclude_rule(Rule::comment)` needed here or in any nested consuming method -
+//!         // `comment` was already dropped from `input` before this method ever saw it.
+//!         match_nodes!(input.into_children();
+//!             [statement(s)..] => Ok(s),
+//!         )
+//!     }
+//!     ...
+//! }
+//!
+//! fn parse_code(input_str: &str) -> Result<Vec<Stmt>> {
+//!     let options = pest_consume::ParseOptions::new().skip_rule(Rule::comment);
+//!     let inputs = CodeParser::parse_with_options(Rule::file, input_str, options)?;
+//!     let input = inputs.single()?;
+//!     CodeParser::statement_list(input)
+//! }
+//! ```
+//!
+//! This is still a parse-time setting, not a grammar one: `comment` stays non-silent (no leading
+//! `_`) in the `.pest` file, so a method that genuinely needs to see it can still reach it through
+//! [`Node::as_pair`] and walk the raw [`pest::iterators::Pair`] tree directly - `skip_rule` only
+//! affects how [`Nodes`] built by this crate's own iteration methods look, never pest's own tree.
+//!
+//! ## Declaring the skip list once, instead of at every `parse` call
+//!
+//! There's no `#[parser(skip(WHITESPACE, COMMENT, NEWLINE))]` attribute to declare this once on
+//! the `impl` block, for the same reason [`extensible_dispatch`] gives for why there's no
+//! `#[pest_consume::parser]` at all: the consuming methods here are ordinary hand-written `fn`s,
+//! not output from an attribute macro, so there's no attribute for a skip list to be read off of.
+//! What does work is building the [`ParseOptions`] once - a `fn skip_options() -> ParseOptions<Rule>`
+//! on the parser type, or a shared `const`/`static` if `R` is cheap enough to build one from - and
+//! reusing it at every [`Parser::parse_with_options`] call, rather than repeating the
+//! `skip_rule(...)` chain at each call site.
+//!
+//! A skipped rule is dropped from a [`Nodes`] sequence before [`match_nodes!`] ever sees it, the
+//! same as an explicit [`Nodes::exclude_rule`] - so it's invisible to variadic and count-based
+//! matching too: `[statement(s)..]` never counts a skipped `comment` node towards `s`, and a
+//! count-based arm like `[statement(s), statement(t)]` only ever has to account for exactly two
+//! `statement`s, with no optional `comment` slots needed in between even though the grammar allows
+//! one there.
+//!
+//! [`match_nodes!`]: macro.match_nodes.html
+//! [`extensible_dispatch`]: super::extensible_dispatch
+//! [`Nodes`]: struct.Nodes.html
+//! [`Nodes::exclude_rule`]: struct.Nodes.html#method.exclude_rule
+//! [`Nodes::filter_rule`]: struct.Nodes.html#method.filter_rule
+//! [`Node::as_pair`]: struct.Node.html#method.as_pair
+//! [`ParseOptions`]: struct.ParseOptions.html
+//! [`Parser::parse_with_options`]: trait.Parser.html#method.parse_with_options
@@ -0,0 +1,31 @@
+//! ## Splitting a flat child sequence into sections
+//!
+//! Some grammars produce a flat list of children where a marker rule separates logical sections -
+//! imports, then declarations, say, written as `imports ~ (item)* ~ section_break ~ decls ~
+//! (item)*`. [`Nodes::filter_rule`] can pick out all the `item` nodes at once, but it can't tell
+//! which section each one came from; [`Nodes::exclude_rule`] can drop the marker, but the two
+//! sections still end up concatenated into one sequence.
+//!
+//! [`Nodes::split_at_rule`] keeps the grouping: it splits the remaining nodes into one [`Nodes`]
+//! per section, dropping every marker rather than including it in either neighbor, so each section
+//! can be handed to whichever consuming method fits it:
+//!
+//! ```ignore
+//! impl FileParser {
+//!     fn file(input: Node) -> Result<(Vec<Import>, Vec<Decl>)> {
+//!         let mut sections = input.into_children().split_at_rule(Rule::section_break);
+//!         let imports = Self::imports(sections.remove(0))?;
+//!         let decls = Self::decls(sections.remove(0))?;
+//!         Ok((imports, decls))
+//!     }
+//! }
+//! ```
+//!
+//! A marker-separated sequence of `n` markers always splits into `n + 1` groups - two adjacent
+//! markers, or one at either end, produce an empty group rather than merging with a neighbor, so
+//! the group a given section ends up in never shifts based on whether an earlier one was empty.
+//!
+//! [`Nodes`]: crate::Nodes
+//! [`Nodes::filter_rule`]: crate::Nodes::filter_rule
+//! [`Nodes::exclude_rule`]: crate::Nodes::exclude_rule
+//! [`Nodes::split_at_rule`]: crate::Nodes::split_at_rule
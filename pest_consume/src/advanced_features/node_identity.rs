@@ -0,0 +1,41 @@
+//! ## `NodeId` for external side tables
+//!
+//! [`Node::id`] gives back a [`NodeId`] built from this node's rule and span, for an analysis pass
+//! that wants to attach information to nodes - types, resolved symbols, lint results - without
+//! touching the tree itself:
+//!
+//! ```ignore
+//! fn annotate(root: Node) -> HashMap<NodeId<Rule>, Type> {
+//!     let mut types = HashMap::new();
+//!     for child in root.children_ref() {
+//!         types.insert(child.id(), infer_type(&child));
+//!     }
+//!     types
+//! }
+//! ```
+//!
+//! Two nodes built from the same grammar match - same rule, same byte range - always report the
+//! same id, so it's stable across every clone of a given [`Node`] (see [`node_cloning`]), and
+//! across [`Nodes::peek`]/[`Nodes::nth`] being called more than once for the same not-yet-consumed
+//! node. It's also deterministic for a given input: re-parsing the same text
+//! the same way assigns the same ids to the nodes at the same tree positions, whatever order a
+//! later pass happens to visit them in - useful for correlating two independent passes, or a pass
+//! re-run after an [`incremental_reparse`].
+//!
+//! ## Why rule and span are enough to be unique
+//!
+//! A rule and span alone aren't unique in general - nothing stops two *unrelated* nodes built from
+//! different rules at different positions from being compared, but within one parse tree, could an
+//! ancestor and a descendant ever share both? Only if some rule matched itself at the very same
+//! position with nothing in between, which would mean pest recursed into that rule again without
+//! consuming any input - exactly the left-recursion pattern pest doesn't terminate, so a grammar
+//! that could produce such a pair wouldn't successfully parse in the first place. Any tree pest
+//! actually hands back is free of the collision.
+//!
+//! [`Node::id`]: crate::Node::id
+//! [`NodeId`]: crate::NodeId
+//! [`Node`]: crate::Node
+//! [`Nodes::peek`]: crate::Nodes::peek
+//! [`Nodes::nth`]: crate::Nodes::nth
+//! [`node_cloning`]: super::node_cloning
+//! [`incremental_reparse`]: super::incremental_reparse
@@ -0,0 +1,38 @@
+//! ## Matching on pest's node tags instead of position
+//!
+//! A grammar rule that has more than one way to produce the same child rule - different branches
+//! of an alternation, say - can't always be told apart by position: `expr = { (lhs ~ "+" ~ rhs) |
+//! (rhs ~ "-" ~ lhs) }` puts the `lhs` `expr` first in one branch and second in the other.
+//! [pest's node tagging](https://pest.rs/book/grammars/syntax.html#tagged-node) solves this at
+//! the grammar level: `#tag = rule` attaches a string tag to a subexpression's match, retrievable
+//! from the resulting [`pest::iterators::Pair`] regardless of where it ended up in the sequence.
+//! Tagging requires pest_derive's `grammar-extras` feature.
+//!
+//! [`Node::tag`] exposes that tag on a [`Node`]. For the common case of binding several tagged
+//! children at once, [`match_nodes!`] accepts a pattern written entirely as `#tag => rule(binding)`
+//! items instead of the usual positional ones:
+//!
+//! ```ignore
+//! impl CalcParser {
+//!     fn expr(input: Node) -> Result<(i64, i64)> {
+//!         match_nodes!(input.into_children();
+//!             [#lhs => num(l), #rhs => num(r)] => Ok((l, r)),
+//!         )
+//!     }
+//!     ...
+//! }
+//! ```
+//!
+//! This matches regardless of which alternative of the grammar rule actually fired: every node in
+//! the sequence must carry exactly one of the listed tags, but not necessarily in the order
+//! listed. A node with no tag, an unlisted tag, or a tag used twice makes the whole arm not match,
+//! the same as a positional pattern whose shape doesn't fit.
+//!
+//! A tag-keyed pattern can't be mixed with positional slots, `?`, a trailing `..` capture, a
+//! `collect_*` body, or a guard - each of those still describes position, which a tagged pattern
+//! deliberately ignores.
+//!
+//! [`match_nodes!`]: macro.match_nodes.html
+//! [`Nodes`]: struct.Nodes.html
+//! [`Node`]: struct.Node.html
+//! [`Node::tag`]: struct.Node.html#method.tag
@@ -0,0 +1,33 @@
+//! ## Parsing an owned `String`
+//!
+//! Enabled by the `owned_parsing` feature. Every other entry point on [`Parser`](crate::Parser)
+//! borrows its `input_str` argument, so the [`Nodes`](crate::Nodes) it returns borrows it right
+//! back - fine for a function that parses and consumes in one go, but awkward for one that wants
+//! to hand a parsed tree off elsewhere: the caller has to keep the original `String` alive
+//! somewhere, and thread its lifetime through every signature that touches the result.
+//!
+//! [`Parser::parse_owned`] sidesteps this by taking `input` as an owned `String` and returning an
+//! [`OwnedNodes`], which stores `input` and the [`Nodes`] borrowed from it together in one
+//! self-referential value - built with the [`ouroboros`](https://docs.rs/ouroboros) crate - so it
+//! can be returned, stored in a struct, or moved across an `async` await point without a borrowed
+//! lifetime in sight.
+//!
+//! The trade-off: a self-referential struct can't simply hand its borrowed field back out by
+//! value, since there would then be nothing left guaranteeing the `String` it borrows from
+//! outlives it. So [`OwnedNodes::consume`] can only be called once - it takes the [`Nodes`] out to
+//! hand to your closure, consuming it there and then, and panics if called again.
+//!
+//! ```ignore
+//! use pest_consume::Parser as _;
+//!
+//! let mut owned = MyParser::parse_owned(Rule::program, input_string)?;
+//! let ast = owned.consume(|nodes| {
+//!     let input_node = nodes.single()?;
+//!     MyParser::program(input_node)
+//! })?;
+//! ```
+//!
+//! [`Nodes`]: crate::Nodes
+//! [`Parser::parse_owned`]: crate::Parser::parse_owned
+//! [`OwnedNodes`]: crate::OwnedNodes
+//! [`OwnedNodes::consume`]: crate::OwnedNodes::consume
@@ -0,0 +1,62 @@
+//! ## The guarantee: `Parser::parse` never panics on `&str` input
+//!
+//! [`Parser::parse`] and its siblings ([`parse_with_depth_limit`](crate::Parser::parse_with_depth_limit),
+//! [`parse_with_options`](crate::Parser::parse_with_options), [`parse_parented`](crate::Parser::parse_parented),
+//! [`parse_collecting_errors`](crate::Parser::parse_collecting_errors),
+//! [`parse_collecting_warnings`](crate::Parser::parse_collecting_warnings)) never panic for any
+//! `&str`, however pathological - malformed UTF-8 boundaries can't occur since `&str` is already
+//! guaranteed valid UTF-8, and a grammar rejecting the input surfaces as `Err`, not a panic. Every
+//! [`Nodes`] method this crate provides for walking the resulting tree ([`Nodes::single`],
+//! [`Nodes::exactly`], [`Nodes::match_optional_seq`], [`Nodes::match_wildcard_seq`],
+//! [`Nodes::match_tagged_seq`], ...) returns `None`/`Err` rather than panicking or indexing out of
+//! bounds on a shape it didn't expect, and so does [`match_nodes!`] itself - an arm that doesn't
+//! fit a sequence falls through to the next one rather than unwrapping something that isn't there.
+//! This is a property of the library's own code, not an incidental side effect: every `.unwrap()`
+//! or indexing operation left in this crate's internals (checked with a `grep` pass across
+//! `src/`, not by example) sits behind a count that was just verified a line or two above it - the
+//! same sequence length checked immediately before zipping it against a fixed number of bindings,
+//! for instance - so it can't observe a parse tree's actual shape going wrong underneath it.
+//!
+//! Three exceptions to the guarantee exist:
+//!
+//! - [`PrecClimber::new`] panics if two operators registered at the same precedence disagree on
+//!   associativity. This depends only on the fixed table the grammar author builds once, not on
+//!   any string later parsed through it, so no input can trigger it once the table is built - the
+//!   same way `Vec::with_capacity(usize::MAX)` isn't considered input-triggerable just because
+//!   some other `usize` would work.
+//! - [`Nodes::pratt_climb`] inherits [`pest::pratt_parser::PrattParserMap::parse`]'s own panics
+//!   (documented on `pratt_climb` itself) if the `PrattParser` table passed in doesn't account for
+//!   every operator rule the grammar can actually produce at a given position. This is pest's own
+//!   implementation underneath this crate's wrapper, not code this crate controls; fixing it would
+//!   mean reimplementing Pratt parsing from scratch rather than auditing a handful of `unwrap()`s,
+//!   which is out of scope here. As with [`PrecClimber::new`], the table is fixed at
+//!   grammar-authoring time - a fuzzer varying only the input string, not the `PrattParser` table
+//!   alongside it, won't reach this.
+//! - A grammar rule that recurses into itself (`nested = { "(" ~ nested ~ ")" | ... }`) can exhaust
+//!   the call stack on deeply nested input - confirmed while writing the regression test below, at
+//!   a nesting depth in the low thousands. This happens inside pest's own generated parser, which
+//!   builds the `Pairs` tree before any pest_consume code runs at all, so it can't be fixed by
+//!   auditing this crate's `unwrap()`s either; it also can't be caught with `catch_unwind`, since a
+//!   stack overflow aborts the process rather than unwinding it. [`Parser::parse_with_depth_limit`]
+//!   doesn't help here either - it bounds how deep a *consuming* method
+//!   ([`Node::into_children`](crate::Node::into_children)/[`Node::children_ref`](crate::Node::children_ref))
+//!   may descend, which is enforced only after pest has already built the whole tree. A grammar
+//!   whose nesting depth is attacker-controlled needs its own external guard - capping input size,
+//!   or running the parse on a thread with a known stack size - before it ever reaches this crate.
+//!
+//! See `tests/panic_safety.rs` for a regression test that runs a battery of adversarial strings -
+//! empty input, unmatched delimiters, deeply (but not unboundedly) nested recursion, non-ASCII
+//! text, input that's nothing but whitespace - through a representative grammar and confirms every
+//! one returns cleanly (`Ok` or `Err`) rather than unwinding.
+//!
+//! [`Parser::parse`]: trait.Parser.html#method.parse
+//! [`Parser::parse_with_depth_limit`]: trait.Parser.html#method.parse_with_depth_limit
+//! [`Nodes`]: struct.Nodes.html
+//! [`Nodes::single`]: struct.Nodes.html#method.single
+//! [`Nodes::exactly`]: struct.Nodes.html#method.exactly
+//! [`Nodes::match_optional_seq`]: struct.Nodes.html#method.match_optional_seq
+//! [`Nodes::match_wildcard_seq`]: struct.Nodes.html#method.match_wildcard_seq
+//! [`Nodes::match_tagged_seq`]: struct.Nodes.html#method.match_tagged_seq
+//! [`match_nodes!`]: macro.match_nodes.html
+//! [`PrecClimber::new`]: struct.PrecClimber.html#method.new
+//! [`Nodes::pratt_climb`]: struct.Nodes.html#method.pratt_climb
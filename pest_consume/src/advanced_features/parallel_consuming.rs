@@ -0,0 +1,53 @@
+//! ## Why there's no `rayon`-backed `Nodes::par_consume`
+//!
+//! [`Node`] and [`Nodes`] aren't [`Send`], so handing a child off to another thread to consume it
+//! isn't possible today. The audit below is why that isn't a small feature-gated addition - it
+//! would mean changing what every [`Node`]/[`Nodes`] *is*, not just adding a method behind a
+//! `rayon` feature flag.
+//!
+//! Three fields stand in the way:
+//!
+//! - `context_lock: Rc<RefCell<()>>` - the aliasing guard behind [`Node::context_mut`]. `Rc` and
+//!   `RefCell` are both `!Sync`, and `RefCell::borrow`/`borrow_mut` panic on a conflicting
+//!   borrow rather than block, which only gives the right answer ("two overlapping
+//!   `context_mut()` calls on sibling nodes is a bug") as long as every sibling runs on the same
+//!   thread. Across threads, the question isn't "panic or not" any more but "block or not", which
+//!   means swapping in a `Mutex` - a real behavior change for every caller, not just the parallel
+//!   ones, since `context_mut` would start blocking instead of panicking on conflicting access.
+//! - `parent_link: Option<Rc<ParentLink<'i, R>>>` - shared, reference-counted, and `!Send` for the
+//!   same reason; see [`parent_navigation`](super::parent_navigation).
+//! - `errors`/`warnings: Option<*mut Vec<Error<R>>>` - raw pointers into a buffer owned by the
+//!   call that started the parse. A `Vec::push` from two threads at once through these, without a
+//!   lock around them, is a data race; [`Node::emit_error`]/[`Node::warn`] would need that lock
+//!   even on the purely single-threaded path, since the pointer type can't tell which path it's
+//!   on.
+//!
+//! Fixing all three means `Rc` becomes `Arc`, `RefCell` becomes a real `Mutex`, and the error/
+//! warning buffer gets one too - on every [`Node`]/[`Nodes`], including the overwhelming majority
+//! that never cross a thread. That's a cost (atomics, possible lock contention) paid by every
+//! caller to make a minority use case possible, in exchange for a feature that could instead be
+//! had by collecting children into a `Vec` first and parallelizing over *that*:
+//!
+//! ```ignore
+//! fn program(input: Node) -> Result<Vec<FunctionDef>> {
+//!     let children: Vec<_> = input.into_children().collect();
+//!     children
+//!         .into_par_iter()
+//!         .map(|child| {
+//!             // Re-derive whatever `child` needs standalone - e.g. `child.as_str().to_owned()` -
+//!             // before crossing the `rayon` boundary, rather than sending the `Node` itself.
+//!             parse_function_def(child.as_str())
+//!         })
+//!         .collect()
+//! }
+//! ```
+//!
+//! which sidesteps the whole problem: nothing `!Send` ever crosses a thread, because nothing
+//! derived from [`Node`] does either. `Nodes::par_consume` isn't provided, since there's nothing
+//! it could do beyond that pattern other than hide the `Arc`/`Mutex` cost inside the common case.
+//!
+//! [`Node`]: struct.Node.html
+//! [`Nodes`]: struct.Nodes.html
+//! [`Node::context_mut`]: struct.Node.html#method.context_mut
+//! [`Node::emit_error`]: struct.Node.html#method.emit_error
+//! [`Node::warn`]: struct.Node.html#method.warn
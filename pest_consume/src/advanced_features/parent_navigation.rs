@@ -0,0 +1,52 @@
+//! ## Walking back up the tree with `Node::parent`
+//!
+//! The default consuming pass only goes downward: a [`Node`] gives you its [children], but pest's
+//! underlying [`Pair`] keeps no link back to where it came from, so there's no way to climb back
+//! up from, say, an identifier to the scope it's nested in.
+//!
+//! [`Parser::parse_parented`] builds that link. It's a separate entry point rather than something
+//! [`parse`][`Parser::parse`] always does, since maintaining it costs one `Rc` allocation per level
+//! of descent - worth avoiding for the common zero-copy path that never needs to look upward:
+//!
+//! ```ignore
+//! let inputs = CalcParser::parse_parented(Rule::calculation, input_str)?;
+//! let input = inputs.single()?;
+//! CalcParser::calculation(input)
+//! ```
+//!
+//! Every [`Node`] produced while descending from there - directly or through [`into_children`]/
+//! [`children_ref`] - carries a link back to its immediate parent, retrievable with
+//! [`Node::parent`]. A node with no parent (one of the top-level nodes the parse started from)
+//! returns `None`, the same as any [`Node`] obtained through the ordinary, unparented entry
+//! points.
+//!
+//! ## Naming where in the grammar an error came from
+//!
+//! A leaf rule like `expr` or `ident` often recurs in many places in a grammar - inside a
+//! `function`'s body, inside a top-level `const`, inside a `macro_arg` - so an [`Error`] that only
+//! names the failing rule can leave the reader guessing which occurrence it actually was.
+//! [`Node::rule_path`] walks the parent chain built by [`Parser::parse_parented`] and returns
+//! every ancestor's rule, from the outermost root down to (but not including) this node's own;
+//! [`Node::error`] uses it automatically, prefixing its message with the full chain down to this
+//! node when a parent chain is available:
+//!
+//! ```text
+//! file > const > expr: division by zero
+//! ```
+//!
+//! instead of just `division by zero`, when parsed with `parse_parented`.
+//!
+//! Outside `parse_parented`, [`Node::rule_path`] is always empty and [`Node::error`] falls back
+//! to the bare message, exactly as before.
+//!
+//! [`Pair`]: https://docs.rs/pest/latest/pest/iterators/struct.Pair.html
+//! [children]: struct.Node.html#method.into_children
+//! [`Node`]: struct.Node.html
+//! [`Node::parent`]: struct.Node.html#method.parent
+//! [`Node::rule_path`]: struct.Node.html#method.rule_path
+//! [`Node::error`]: struct.Node.html#method.error
+//! [`Error`]: struct.Error.html
+//! [`Parser::parse_parented`]: trait.Parser.html#method.parse_parented
+//! [`Parser::parse`]: trait.Parser.html#method.parse
+//! [`into_children`]: struct.Node.html#method.into_children
+//! [`children_ref`]: struct.Node.html#method.children_ref
@@ -0,0 +1,40 @@
+//! ## Bounding an untrusted input's cost before and during parsing
+//!
+//! [`Parser::parse_with_depth_limit`] bounds the consuming pass's recursion depth, and
+//! [`Parser::parse_with_cancel`] lets a caller interrupt a pass already in flight, but neither
+//! says anything about an input that's simply enormous - a 500MB payload takes real time and
+//! memory to run through pest at all, before the consuming pass even starts, and a tree with a
+//! huge number of small nodes (rather than a deeply *nested* one) can be expensive to walk even
+//! at a shallow depth.
+//!
+//! [`Parser::parse_with_limits`] combines all three costs a public-facing service is likely to
+//! care about into one [`ParseLimits`]:
+//!
+//! ```ignore
+//! let limits = ParseLimits::new()
+//!     .max_input_bytes(1_000_000)
+//!     .max_depth(200)
+//!     .max_nodes(10_000);
+//! let inputs = CalcParser::parse_with_limits(Rule::calculation, input_str, limits)?;
+//! CalcParser::calculation(inputs.single()?)
+//! ```
+//!
+//! [`ParseLimits::max_input_bytes`] is checked against `input_str` directly, before pest is ever
+//! invoked - the one check here that isn't part of the consuming pass at all.
+//! [`ParseLimits::max_depth`] behaves exactly like [`Parser::parse_with_depth_limit`].
+//! [`ParseLimits::max_nodes`] bounds the total number of nodes [`Nodes::next_node`] may produce
+//! across the whole pass - via [`Nodes::check_node_budget`], which [`match_nodes!`] calls
+//! alongside its existing depth and cancellation checks - catching a tree that's wide rather than
+//! deep, which a depth limit alone wouldn't. Any limit left unset stays unbounded, the same as
+//! every other entry point.
+//!
+//! [`Parser::parse_with_depth_limit`]: crate::Parser::parse_with_depth_limit
+//! [`Parser::parse_with_cancel`]: crate::Parser::parse_with_cancel
+//! [`Parser::parse_with_limits`]: crate::Parser::parse_with_limits
+//! [`ParseLimits`]: crate::ParseLimits
+//! [`ParseLimits::max_input_bytes`]: crate::ParseLimits::max_input_bytes
+//! [`ParseLimits::max_depth`]: crate::ParseLimits::max_depth
+//! [`ParseLimits::max_nodes`]: crate::ParseLimits::max_nodes
+//! [`Nodes::next_node`]: crate::Nodes::next_node
+//! [`Nodes::check_node_budget`]: crate::Nodes::check_node_budget
+//! [`match_nodes!`]: crate::match_nodes
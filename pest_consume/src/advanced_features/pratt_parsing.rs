@@ -0,0 +1,64 @@
+//! ## Pratt parsing
+//!
+//! [`Nodes::prec_climb`](crate::advanced_features::precedence_climbing) only knows about infix
+//! operators. An expression grammar with unary minus or a postfix factorial needs prefix and
+//! postfix operators too, which is exactly what [pest]'s own
+//! [`PrattParser`](pest::pratt_parser::PrattParser) handles. [`Nodes::pratt_climb`] wraps it
+//! without leaving the typed [`Node`] world: describe the operators with a `PrattParser` table,
+//! then provide closures for the primary expression and for each affix you use. `pratt_climb`
+//! converts every [pest](https://pest.rs) `Pair` the table hands back into a [`Node`] before
+//! calling your closures, so you can recurse straight back into your own consuming methods.
+//!
+//! ```ignore
+//! use pest_consume::pest::pratt_parser::{Assoc, Op, PrattParser};
+//!
+//! fn pratt() -> PrattParser<Rule> {
+//!     PrattParser::new()
+//!         .op(Op::infix(Rule::add, Assoc::Left) | Op::infix(Rule::sub, Assoc::Left))
+//!         .op(Op::infix(Rule::mul, Assoc::Left) | Op::infix(Rule::div, Assoc::Left))
+//!         .op(Op::prefix(Rule::neg))
+//!         .op(Op::postfix(Rule::fac))
+//! }
+//!
+//! impl CalcParser {
+//!     fn expr(input: Node) -> Result<f64> {
+//!         input.into_children().pratt_climb(
+//!             &pratt(),
+//!             Self::primary,
+//!             Some(|op: Node, rhs: Result<f64>| match op.as_rule() {
+//!                 Rule::neg => Ok(-rhs?),
+//!                 _ => unreachable!(),
+//!             }),
+//!             Some(|lhs: Result<f64>, op: Node| match op.as_rule() {
+//!                 Rule::fac => Ok((1..=(lhs? as u64)).product::<u64>() as f64),
+//!                 _ => unreachable!(),
+//!             }),
+//!             Some(|lhs: Result<f64>, op: Node, rhs: Result<f64>| match op.as_rule() {
+//!                 Rule::add => Ok(lhs? + rhs?),
+//!                 Rule::sub => Ok(lhs? - rhs?),
+//!                 Rule::mul => Ok(lhs? * rhs?),
+//!                 Rule::div => Ok(lhs? / rhs?),
+//!                 _ => unreachable!(),
+//!             }),
+//!         )
+//!     }
+//!     fn primary(input: Node) -> Result<f64> {
+//!         match_nodes!(input.into_children();
+//!             [number(n)] => Ok(n),
+//!             [expr(e)] => Ok(e),
+//!         )
+//!     }
+//!     ...
+//! }
+//! ```
+//!
+//! Pass `None` for any affix closure your table doesn't need - `prec_climb`'s infix-only examples
+//! translate directly by leaving `prefix` and `postfix` as `None`. The closures receive
+//! `Result<T, Error<Rule>>` operands rather than bare `T`, since a failure from deep inside the
+//! expression has to be threaded back out somehow; propagate it with `?` as shown above, or
+//! inspect it before deciding how to fold.
+//!
+//! [`Node`]: crate::Node
+//! [`Nodes`]: crate::Nodes
+//! [`Nodes::pratt_climb`]: crate::Nodes::pratt_climb
+//! [`parser`]: https://docs.rs/pest_consume_macros/1.0.1/pest_consume_macros/attr.parser.html
@@ -0,0 +1,79 @@
+//! ## Precedence climbing
+//!
+//! Expression grammars with infix operators of varying precedence and associativity usually
+//! flatten to a child sequence shaped like `primary (op primary)*`. Climbing that sequence by
+//! hand with [pest]'s `PrecClimber` means stepping outside the typed [`Node`] world: you end up
+//! matching on raw `Pair`s and threading the recursion yourself.
+//!
+//! [`Nodes::prec_climb`] does this instead, staying inside the typed [`Node`] world. It only
+//! handles infix operators; for prefix or postfix operators too, see
+//! [`pratt_parsing`](crate::advanced_features::pratt_parsing) instead. Describe the
+//! operators with a [`PrecClimber`] table, then provide two closures: one that turns a primary
+//! [`Node`] into your result type, and one that folds an operator node together with its two
+//! operands into a new result. `prec_climb` drives the standard precedence-climbing recurrence
+//! over the child sequence, calling back into your closures as it goes.
+//!
+//! ```ignore
+//! use pest_consume::{Assoc, PrecClimber};
+//!
+//! fn climber() -> PrecClimber<Rule> {
+//!     PrecClimber::new(vec![
+//!         (Rule::add, 1, Assoc::Left),
+//!         (Rule::sub, 1, Assoc::Left),
+//!         (Rule::mul, 2, Assoc::Left),
+//!         (Rule::div, 2, Assoc::Left),
+//!         (Rule::pow, 3, Assoc::Right),
+//!     ])
+//! }
+//!
+//! impl CalcParser {
+//!     fn expr(input: Node) -> Result<f64> {
+//!         input.into_children().prec_climb(
+//!             &climber(),
+//!             Self::primary,
+//!             |lhs, op, rhs| match op.as_rule() {
+//!                 Rule::add => Ok(lhs + rhs),
+//!                 Rule::sub => Ok(lhs - rhs),
+//!                 Rule::mul => Ok(lhs * rhs),
+//!                 Rule::div => Ok(lhs / rhs),
+//!                 Rule::pow => Ok(lhs.powf(rhs)),
+//!                 _ => unreachable!(),
+//!             },
+//!         )
+//!     }
+//!     fn primary(input: Node) -> Result<f64> {
+//!         match_nodes!(input.into_children();
+//!             [number(n)] => Ok(n),
+//!             [expr(e)] => Ok(e),
+//!         )
+//!     }
+//!     ...
+//! }
+//! ```
+//!
+//! A child sequence with no primary at all is a parse error, as is a dangling trailing operator
+//! with nothing following it; both surface as an [`Error`] built from the offending [`Node`],
+//! same as any other consuming failure. Building a [`PrecClimber`] with two operators sharing a
+//! precedence but disagreeing on associativity panics eagerly, at table-construction time, rather
+//! than deep into a parse.
+//!
+//! There's no `#[prec_climb(rule, CLIMBER)]` attribute to generate the call above - this crate
+//! has no `#[pest_consume::parser]` macro at all, as noted in
+//! [`custom_errors`](crate::advanced_features::custom_errors), so there's no attribute surface to
+//! add one to. `Nodes::prec_climb` is already the whole of what such an attribute would expand
+//! to: a single call taking the table and the two closures, not a multi-step wiring-up. The
+//! `lazy_static`-wrapped table in older examples is just one way to avoid rebuilding a
+//! [`PrecClimber`] on every call; an `fn climber() -> PrecClimber<Rule>` built fresh each time, as
+//! above, works too and is simpler when the table is cheap to construct.
+//!
+//! [`parser`]: https://docs.rs/pest_consume_macros/1.0.1/pest_consume_macros/attr.parser.html
+//! [`match_nodes!`]: macro.match_nodes.html
+//! [`Nodes`]: struct.Nodes.html
+//! [`Nodes::prec_climb`]: struct.Nodes.html#method.prec_climb
+//! [`Node`]: struct.Node.html
+//! [`Error`]: struct.Error.html
+//! [`Parser`]: trait.Parser.html
+//! [`PrecClimber`]: struct.PrecClimber.html
+//! [pest]: https://pest.rs
+//! [examples]: https://github.com/Nadrieril/pest_consume/tree/master/pest_consume/examples
+//! [dhall-rust-parser]: https://github.com/Nadrieril/dhall-rust/blob/master/dhall_syntax/src/parser.rs
@@ -0,0 +1,33 @@
+//! ## Bounding the consuming pass's recursion depth
+//!
+//! A consuming method that recurses into its own children - the ordinary shape for any grammar
+//! with a self-referential rule, like `expr = { term ~ (op ~ term)* }` where `term` can itself
+//! contain an `expr` - recurses exactly as deep as the input is nested. Adversarial input like
+//! `((((((...))))))` can nest deep enough to overflow the stack, and that recursion is ordinary
+//! Rust function calls the consuming method writes itself, not something this crate's own code
+//! drives - so there's nothing to bound unless the depth is tracked and checked along the way.
+//!
+//! [`Parser::parse_with_depth_limit`] does the tracking: every [`Node`] produced while descending
+//! from it, through [`into_children`]/[`children_ref`], carries a depth one greater than its
+//! parent's. [`match_nodes!`] checks that depth against the limit before trying any arm, returning
+//! a clean [`Error`] instead of dispatching into another level of recursion:
+//!
+//! ```ignore
+//! let inputs = CalcParser::parse_with_depth_limit(Rule::calculation, input_str, 200)?;
+//! let input = inputs.single()?;
+//! CalcParser::calculation(input)
+//! ```
+//!
+//! Dispatching by hand instead of through `match_nodes!` (e.g. via [`next_node`]) bypasses that
+//! automatic check, so call [`check_depth_limit`] directly in that case. Outside of
+//! `parse_with_depth_limit`, depth is always `0` and [`check_depth_limit`] always passes - the same
+//! unbounded behavior as every other entry point.
+//!
+//! [`Node`]: struct.Node.html
+//! [`Error`]: ../struct.Error.html
+//! [`match_nodes!`]: ../macro.match_nodes.html
+//! [`Parser::parse_with_depth_limit`]: trait.Parser.html#method.parse_with_depth_limit
+//! [`into_children`]: struct.Node.html#method.into_children
+//! [`children_ref`]: struct.Node.html#method.children_ref
+//! [`next_node`]: struct.Nodes.html#method.next_node
+//! [`check_depth_limit`]: struct.Nodes.html#method.check_depth_limit
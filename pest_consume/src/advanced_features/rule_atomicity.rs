@@ -0,0 +1,40 @@
+//! ## Recovering atomicity from the caller's own record of the grammar
+//!
+//! pest's [`@`/`$`/`!` modifiers](https://pest.rs/book/grammars/syntax.html#atomic) decide, once,
+//! while `pest_derive` turns a grammar into a parser, whether whitespace is implicitly skipped
+//! between a rule's parts. They're resolved entirely at that point; the token stream a finished
+//! parse produces - and that [`Node`]/[`Nodes`] wrap - carries no marker saying which modifier was
+//! active for a given match, so there's nothing for [`Node::in_atomic_context`] to read back from
+//! the parse alone. What it can do is walk back up a node's own parent chain and check each
+//! ancestor's rule against a set the caller supplies - the grammar's own atomic/compound-atomic
+//! rules, which the caller already knows, because they wrote the grammar:
+//!
+//! ```ignore
+//! // string      = ${ "\"" ~ string_char* ~ "\"" } // compound-atomic: keeps its inner structure
+//! // string_char = { !"\"" ~ ANY }
+//! fn atomic_rules() -> HashSet<Rule> {
+//!     [Rule::string].into_iter().collect()
+//! }
+//!
+//! impl TextParser {
+//!     fn string_char(input: Node) -> Result<char> {
+//!         // Inside `string`'s atomic context, so no implicit whitespace was skipped around this
+//!         // character - interpret it literally rather than re-tokenizing it as if it hadn't been.
+//!         debug_assert!(input.in_atomic_context(&atomic_rules()));
+//!         Ok(input.as_str().chars().next().unwrap())
+//!     }
+//! }
+//! ```
+//!
+//! This needs a parent chain to walk, so it only works on a [`Node`] produced by
+//! [`Parser::parse_parented`] (see [`parent_navigation`](super::parent_navigation)) - on any other
+//! entry point it can only ever see the node's own rule, since there's no chain to climb. It also
+//! doesn't model a `!`-marked rule resetting atomicity on the way back down from an atomic
+//! ancestor; a grammar that uses `!` to opt a nested rule back out of an enclosing atomic context
+//! should leave that rule out of `atomic_rules` in the first place rather than rely on this to
+//! notice the reset partway up the chain.
+//!
+//! [`Node`]: crate::Node
+//! [`Nodes`]: crate::Nodes
+//! [`Node::in_atomic_context`]: crate::Node::in_atomic_context
+//! [`Parser::parse_parented`]: crate::Parser::parse_parented
@@ -0,0 +1,40 @@
+//! ## Why a node's rule can't be a compile-time type parameter
+//!
+//! The macro-generated dispatch already only ever calls a consuming method with a [`Node`] parsed
+//! as the rule that method's name matches - `fn expr(input: Node) -> ...` is only ever handed an
+//! `expr` node, never, say, a `stmt` one, as long as every call goes through `Self::expr(...)`
+//! rather than constructing the match by hand. A `Node<{Rule::expr}>` wrapper that made this a
+//! type error instead of a naming convention isn't something this crate can add on stable Rust,
+//! though: `Rule` is a plain `enum` generated by [`pest_derive`] per grammar, and a value of it
+//! can't be used as a const generic parameter on stable - `enum`s aren't part of the small set of
+//! structural-match types (integers, `bool`, `char`, `&str`, ...) stable const generics accept.
+//! There's also no dispatch table to retrofit the check into: every call from one consuming
+//! method to another is an ordinary function call the compiler already type-checks the usual way,
+//! not a generated lookup this crate controls.
+//!
+//! What's left is a runtime check at the boundary instead of a compile-time one:
+//! [`Node::expect_rule`] returns the node back if it matches the given rule, or an [`Error`]
+//! pointing at it otherwise. For a helper shared between several rules that would otherwise
+//! fail confusingly further in - on whatever first assumption about the node's shape turns out
+//! to be wrong - asserting the rule immediately gives a caller a clear error at the one call that
+//! actually got it wrong:
+//!
+//! ```ignore
+//! impl ExprParser {
+//!     // Called from more than one place; the caller is trusted to pass an `expr`, but a future
+//!     // edit to one of those call sites shouldn't have to re-read this function to find out why
+//!     // things went wrong three match arms later.
+//!     fn fold_binary(input: Node) -> Result<Expr> {
+//!         let input = input.expect_rule(Rule::expr)?;
+//!         match_nodes!(input.into_children();
+//!             [term(lhs), op(op), expr(rhs)] => Ok(Expr::binary(lhs, op, rhs)),
+//!             [term(t)] => Ok(t),
+//!         )
+//!     }
+//! }
+//! ```
+//!
+//! [`Node`]: crate::Node
+//! [`Node::expect_rule`]: crate::Node::expect_rule
+//! [`Error`]: crate::Error
+//! [`pest_derive`]: https://docs.rs/pest_derive
@@ -0,0 +1,85 @@
+//! ## Deserializing straight into a struct, skipping hand-written consuming methods
+//!
+//! With the `serde` feature enabled, [`Node`] implements [`serde::Deserializer`], so a grammar
+//! whose shape already matches the data it describes can be decoded with `#[derive(Deserialize)]`
+//! instead of a consuming method per rule:
+//!
+//! ```ignore
+//! #[derive(serde::Deserialize)]
+//! struct Config {
+//!     name: String,
+//!     port: u16,
+//!     timeout: Option<u32>,
+//!     host: Vec<String>,
+//! }
+//!
+//! fn parse_config(input_str: &str) -> Result<Config> {
+//!     let inputs = ConfigParser::parse(Rule::config, input_str)?;
+//!     let node = inputs.single()?;
+//!     Config::deserialize(node).map_err(|e| node.error(e.to_string()))
+//! }
+//! ```
+//!
+//! for a grammar along the lines of:
+//!
+//! ```ignore
+//! config = { SOI ~ name ~ port ~ timeout? ~ host* ~ EOI }
+//! name = @{ (!NEWLINE ~ ANY)+ }
+//! port = @{ ASCII_DIGIT+ }
+//! timeout = @{ ASCII_DIGIT+ }
+//! host = @{ (!NEWLINE ~ ANY)+ }
+//! ```
+//!
+//! ## What a rule maps to
+//!
+//! - **A struct field** matches a child whose rule name (its `Debug` output, the same name
+//!   [`serde_serialization`] uses) equals the field's name - `port` above matches a `port` child,
+//!   regardless of where among `config`'s children it appears.
+//! - **An `Option<T>` field** is `Some` if exactly one child matches, `None` if none do - so an
+//!   optional rule (`timeout?` in the grammar above) works with no extra annotation.
+//! - **A `Vec<T>` field** collects every matching child, in the order they appear - the same
+//!   "zero or more" shape a trailing `name(bind)..` capture handles in [`match_nodes!`], reached
+//!   here by giving the field a repeated type instead.
+//! - **A scalar field** (`String`, any integer or float type, `bool`, `char`) parses the matching
+//!   child's [`Node::as_str`] via [`FromStr`], the same way a hand-written consuming method
+//!   usually would - [`Node::error`] isn't available to report a parse failure partway through
+//!   deserializing, so a bad scalar surfaces as a generic [`DeserializeError`] instead; see
+//!   "Error quality" below.
+//! - **A nested struct field** recurses the same way, matching that field's own children against
+//!   its own fields - so a grammar with nested rules maps to nested structs with no extra work.
+//! - **A node with no type hint at all** (deserializing into `serde_json::Value` or similar)
+//!   falls back to: a sequence of its children if it has any, or its matched text as a string
+//!   otherwise.
+//!
+//! ## What doesn't work
+//!
+//! - **Enums** aren't supported. Picking a variant from a node's shape alone only has one
+//!   unambiguous answer for the narrowest case - a rule that's a bare alternation producing
+//!   exactly one child either way - and guessing wrong silently for anything broader is worse
+//!   than refusing outright, so [`Node`]'s [`Deserializer`] impl always errors on
+//!   `deserialize_enum`. A rule with real alternatives still needs a hand-written consuming
+//!   method (or a `match_nodes!` arm inside one) to dispatch on which child actually showed up.
+//! - **Tuples and tuple structs** deserialize the same way a plain `Vec` would (every child, in
+//!   order, with no length check against what the tuple expects) rather than failing on a length
+//!   mismatch - there's no cheap way to check an expected arity against a node's children without
+//!   walking them, so this crate doesn't try.
+//!
+//! ## Error quality
+//!
+//! A struct field backed by more than one matching child, when the field type isn't `Vec<T>`,
+//! reports [`DeserializeError`] naming the offending rule. A scalar field whose text doesn't
+//! parse reports a [`DeserializeError`] wrapping the underlying [`FromStr`] error, but - unlike
+//! [`Node::error`] - without a span pointing back at the source, since a plain [`serde::de::Error`]
+//! has nowhere to carry one. For diagnostics good enough to show a user, prefer a hand-written
+//! consuming method for any rule whose scalar parsing can fail; this feature is for data grammars
+//! where that risk is already low (already-validated input, a config format with its own grammar-
+//! level checks) and skipping the boilerplate matters more.
+//!
+//! [`Node`]: crate::Node
+//! [`Node::as_str`]: crate::Node::as_str
+//! [`Node::error`]: crate::Node::error
+//! [`Deserializer`]: serde::Deserializer
+//! [`DeserializeError`]: crate::DeserializeError
+//! [`FromStr`]: std::str::FromStr
+//! [`match_nodes!`]: crate::match_nodes
+//! [`serde_serialization`]: super::serde_serialization
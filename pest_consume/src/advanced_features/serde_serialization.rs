@@ -0,0 +1,23 @@
+//! ## Serializing the parse tree with serde
+//!
+//! With the `serde` feature enabled, [`Node`] implements [`serde::Serialize`], so a parse tree (or
+//! any subtree reachable from a `Node`) can be dumped as JSON, RON, or any other serde format -
+//! handy for snapshot-testing a grammar or inspecting what actually got parsed.
+//!
+//! Each node serializes as a struct with its rule name (via [`Node::as_rule`]'s `Debug` output,
+//! since `R` itself isn't required to be `Serialize`), the text it matched, its span's start/end
+//! byte offsets, and its children, recursively. [User data](super::user_data) and
+//! [context](super::context) are not serialized - `D` and `Ctx` carry no inherent textual
+//! representation, and most uses of this feature just want the tree shape and the text.
+//!
+//! ```ignore
+//! fn field(input: Node) -> Result<String> {
+//!     // `input` can be dumped directly, e.g. for a snapshot test of the parse tree.
+//!     let dumped = serde_json::to_string_pretty(&input).unwrap();
+//!     Ok(input.as_str().to_owned())
+//! }
+//! ```
+//!
+//! [`Node`]: struct.Node.html
+//! [`Node::as_rule`]: struct.Node.html#method.as_rule
+
@@ -0,0 +1,48 @@
+//! ## Building a set of source edits for an autofix pass
+//!
+//! A linter or formatter that fixes several unrelated problems in one pass can't just rewrite the
+//! source as it finds each one - every [`Node`]'s span is a byte range into the *original* source,
+//! so fixing the first problem would shift the byte offsets every later [`Node`] still points at.
+//! [`Edits`] solves this the usual way: record every fix as a `(span, replacement)` pair while
+//! walking the tree, then apply all of them to the original source in one pass once the walk is
+//! done.
+//!
+//! ```ignore
+//! impl CSVParser {
+//!     fn file(input: Node) -> Result<String> {
+//!         let mut edits = Edits::new(input.as_str());
+//!         Self::collect_edits(input.children_ref(), &mut edits)?;
+//!         Ok(edits.apply())
+//!     }
+//!     fn collect_edits(mut fields: Nodes, edits: &mut Edits) -> Result<()> {
+//!         while let Some(field) = fields.next_node() {
+//!             if field.as_str().starts_with('+') {
+//!                 let fixed = field.as_str().trim_start_matches('+');
+//!                 edits
+//!                     .add(&field, fixed)
+//!                     .map_err(|e| field.error(e.to_string()))?;
+//!             }
+//!         }
+//!         Ok(())
+//!     }
+//! }
+//! ```
+//!
+//! [`Edits::add`] checks the new span against every edit already recorded and returns an
+//! [`OverlappingEditError`] instead of accepting one that overlaps (or touches the same boundary
+//! as) an existing edit - two edits covering the same text have no well-defined combined result,
+//! so the caller has to resolve that ambiguity itself rather than have one silently win.
+//! [`Edits::apply`] then replaces every recorded span in reverse offset order, so rewriting one
+//! edit's region never shifts the positions the other edits were recorded against.
+//!
+//! For edits collected from more than one consuming method, thread a single `Edits` through as
+//! [user data](super::user_data) (wrapped in `Rc<RefCell<_>>`, the same way any other
+//! shared-and-mutated state is threaded that way) or through [context](super::context) instead,
+//! so every method records into the same set rather than returning its own edits for a caller to
+//! merge by hand.
+//!
+//! [`Node`]: crate::Node
+//! [`Edits`]: crate::Edits
+//! [`Edits::add`]: crate::Edits::add
+//! [`Edits::apply`]: crate::Edits::apply
+//! [`OverlappingEditError`]: crate::OverlappingEditError
@@ -0,0 +1,45 @@
+//! ## Why a `PUSH`/`POP`/`PEEK` stack isn't visible to a consuming method
+//!
+//! [pest's stack](https://pest.rs/book/grammars/syntax.html#stack) (`PUSH`, `POP`, `PEEK`, and
+//! friends) lives entirely inside the `ParserState` that pest_derive's generated parser drives
+//! while matching - a private `Stack<SpanOrLiteral>` field that only that internal state machine
+//! ever touches. By the time `pest::Parser::parse` returns its `Pairs`, that state machine (and
+//! its stack with it) has already been dropped; neither `Pairs` nor the `Pair`s inside it carry
+//! any reference to it, and pest's public API has no accessor that would let anything - this
+//! crate included - reach back into a finished parse and ask what the stack looked like at some
+//! point during it. There's also no parse-time callback hook to intercept a `PUSH`/`POP`/`PEEK`
+//! as it happens: the state machine that executes them is entirely generated code, with no
+//! extension point pest itself exposes for a caller to plug into.
+//!
+//! So this isn't a gap this crate's [`Node`]/[`Nodes`] wrapping could close without pest adding
+//! that visibility upstream first - there is no post-parse stack to expose, and no parse-time
+//! hook to observe it through as it's built.
+//!
+//! In practice this usually doesn't cost anything: a `PEEK` succeeds by matching the *same text*
+//! that was `PUSH`ed, inline, at the position the grammar put it - and that matched text is
+//! visible the ordinary way, as part of whatever rule matched it, the same as any other matched
+//! span. Wrapping the pushed/peeked text in its own named rule turns it into an ordinary child
+//! [`Node`], with nothing stack-specific required to read it back:
+//!
+//! ```ignore
+//! // fence  = { PUSH(quote_chars) }
+//! // quoted = { fence ~ (!PEEK ~ ANY)* ~ POP }
+//! impl QuoteParser {
+//!     fn quoted(input: Node) -> Result<String> {
+//!         match_nodes!(input.into_children();
+//!             [fence(open), ..] => Ok(open), // the exact delimiter that was pushed
+//!         )
+//!     }
+//!     fn fence(input: Node) -> Result<String> {
+//!         Ok(input.as_str().to_owned())
+//!     }
+//! }
+//! ```
+//!
+//! For indentation tracking specifically - a common use of `PUSH`/`PEEK` - the indentation level
+//! itself is ordinarily re-derived from column position (see [`Node::line_col`]) rather than from
+//! the stack's contents, which sidesteps the question entirely.
+//!
+//! [`Node`]: crate::Node
+//! [`Nodes`]: crate::Nodes
+//! [`Node::line_col`]: crate::Node::line_col
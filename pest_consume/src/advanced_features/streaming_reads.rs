@@ -0,0 +1,39 @@
+//! ## Parsing from a `Read` source
+//!
+//! [`Parser::parse_from_reader`] buffers a [`std::io::Read`] source into a `String` you provide,
+//! then parses it exactly like [`Parser::parse`]:
+//!
+//! ```ignore
+//! let mut buf = String::new();
+//! let inputs = CSVParser::parse_from_reader(Rule::file, std::fs::File::open(path)?, &mut buf)?;
+//! let input = inputs.single()?;
+//! CSVParser::file(input)
+//! ```
+//!
+//! This is a convenience over reading the source yourself, not a bounded-memory streaming parser:
+//! the whole source ends up in `buf` before parsing starts, same as calling
+//! [`read_to_string`](std::io::Read::read_to_string) and [`parse`](crate::Parser::parse) by hand.
+//!
+//! True incremental parsing - reading and processing a multi-gigabyte source in bounded memory -
+//! isn't something this crate can offer without giving up its core guarantee. Every [`Node`] and
+//! [`Nodes`] borrows directly from one contiguous `&'i str` for the lifetime of the whole consume
+//! pass, which is what makes [`Node::as_str`] and [`Node::as_span`] free - no copying, no
+//! re-parsing, no arena. A streaming API that refilled or discarded parts of that buffer as it
+//! went would invalidate any `Node` still referencing the bytes that got reused, which nothing
+//! here is set up to prevent; making it sound would mean either copying every matched string out
+//! as it's produced (defeating the point of streaming) or an unsafe, self-referential buffer
+//! design this crate deliberately avoids elsewhere (see [`advanced_features::context`] for the
+//! lengths the existing pointer-based mechanisms already go to in order to stay sound).
+//!
+//! For a source too large to buffer whole, the grammar itself is usually the better place to
+//! introduce boundaries: parse it as a sequence of self-contained records (one call to
+//! [`Parser::parse`] per line, or per some other natural delimiter read with
+//! [`std::io::BufRead::read_line`]) rather than as a single grammar spanning the entire input.
+//!
+//! [`Parser::parse`]: trait.Parser.html#method.parse
+//! [`Parser::parse_from_reader`]: trait.Parser.html#method.parse_from_reader
+//! [`Node`]: struct.Node.html
+//! [`Node::as_str`]: struct.Node.html#method.as_str
+//! [`Node::as_span`]: struct.Node.html#method.as_span
+//! [`Nodes`]: struct.Nodes.html
+//! [`advanced_features::context`]: super::context
@@ -0,0 +1,37 @@
+//! ## Unit-testing a single consuming method
+//!
+//! As [`custom_errors`](super::custom_errors) notes, this crate has no `#[pest_consume::parser]`
+//! macro - a consuming method is an ordinary associated function, and [`match_nodes!`] expands to
+//! ordinary calls to whatever functions its arms name, resolved by the compiler the same way any
+//! other function call is. There's no generated dispatch table or macro-produced context for
+//! `match_nodes!` to depend on, so it works in any function, free or associated, as long as the
+//! named consuming methods are in scope - including a `#[test]` function that never calls
+//! [`Parser::parse`] on the grammar's top-level rule at all.
+//!
+//! To test one consuming method in isolation, parse starting from *its own* rule instead of the
+//! grammar's entry point - [`Parser::parse`] takes any variant of the grammar's `Rule` enum, not
+//! just the root one:
+//!
+//! ```ignore
+//! impl CSVParser {
+//!     fn field(input: Node) -> Result<f64> {
+//!         input.as_str().parse().map_err(|e| input.error(e))
+//!     }
+//! }
+//!
+//! #[test]
+//! fn field_parses_a_negative_number() {
+//!     // No `file`/`record` rule in sight - this exercises `CSVParser::field` on its own.
+//!     let inputs = CSVParser::parse(Rule::field, "-12.5").unwrap();
+//!     let input = inputs.single().unwrap();
+//!     assert_eq!(CSVParser::field(input).unwrap(), -12.5);
+//! }
+//! ```
+//!
+//! This works just as well for a method whose body is a [`match_nodes!`] call: the sequence it
+//! matches against is simply the children of whatever node was parsed, so parsing from that
+//! method's own rule hands `match_nodes!` exactly the sequence it would have seen reached through
+//! the whole grammar, without constructing the rest of the tree around it.
+//!
+//! [`match_nodes!`]: macro.match_nodes.html
+//! [`Parser::parse`]: trait.Parser.html#method.parse
@@ -0,0 +1,28 @@
+//! ## Pointing at exactly what was left over
+//!
+//! A consuming method that takes as many nodes as it understands - `match_nodes!` with a
+//! trailing `..`, or a manual loop that stops once it sees a rule it doesn't recognize - can end
+//! up with nodes still unconsumed. [`Nodes::error`] can report that *something* is wrong, but it
+//! only points at the very next node, not the whole stretch of input the caller never looked at.
+//!
+//! [`Nodes::remaining_span`] covers exactly that stretch: the span from the start of the next
+//! unconsumed node to the end of the last one, or `None` if nothing is left.
+//!
+//! ```ignore
+//! fn statement_list(mut input: Nodes) -> Result<Vec<Stmt>> {
+//!     let mut stmts = Vec::new();
+//!     while let Some(rule) = input.peek_rule() {
+//!         if rule != Rule::stmt {
+//!             break;
+//!         }
+//!         stmts.push(Self::stmt(input.next_node().unwrap())?);
+//!     }
+//!     if let Some(span) = input.remaining_span() {
+//!         return Err(input.error(format!("unexpected trailing content: {:?}", span.as_str())));
+//!     }
+//!     Ok(stmts)
+//! }
+//! ```
+//!
+//! [`Nodes::error`]: crate::Nodes::error
+//! [`Nodes::remaining_span`]: crate::Nodes::remaining_span
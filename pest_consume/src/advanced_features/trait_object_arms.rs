@@ -0,0 +1,29 @@
+//! ## Arms that return different concrete types behind a shared trait
+//!
+//! A rule with several productions - e.g. a statement grammar where `if_stmt`, `let_stmt`, and
+//! `expr_stmt` are all separate rules producing different concrete types that implement a common
+//! `Statement` trait - doesn't need any special support from [`match_nodes!`] to dispatch on
+//! them. Each arm's `=> expr` is an ordinary Rust expression, so it can box its result into the
+//! trait object itself, exactly as a hand-written `match` would:
+//!
+//! ```ignore
+//! impl StmtParser {
+//!     fn statement(input: Node) -> Result<Box<dyn Statement>> {
+//!         match_nodes!(input.into_children();
+//!             [if_stmt(s)] => Ok(Box::new(s) as Box<dyn Statement>),
+//!             [let_stmt(s)] => Ok(Box::new(s) as Box<dyn Statement>),
+//!             [expr_stmt(s)] => Ok(Box::new(s) as Box<dyn Statement>),
+//!         )
+//!     }
+//! }
+//! ```
+//!
+//! There's no `match_nodes_boxed!` variant that inserts the `Box::new(...) as Box<dyn Trait>`
+//! for you, since the macro has no way to know which trait a caller means it to box into (nor,
+//! short of the caller naming it, where the common type ends and an arm's own postprocessing
+//! begins - an arm might want to box the whole expression, just one sub-value, or not box at
+//! all). Writing it once per arm is also exactly as much boilerplate as an ordinary `match` over
+//! the same productions would need, so there's nothing for `match_nodes!` to save here beyond
+//! what the three `Box::new(...) as Box<dyn Trait>` casts already say on their own.
+//!
+//! [`match_nodes!`]: ../macro.match_nodes.html
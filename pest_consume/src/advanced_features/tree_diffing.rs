@@ -0,0 +1,59 @@
+//! ## Structurally diffing two parse trees
+//!
+//! [`Node::diff`] compares `self` against another [`Node`] - typically the same rule re-parsed
+//! from an edited version of the input, via [`incremental_reparse`] - and reports every
+//! [`NodeDiff`] between them:
+//!
+//! ```ignore
+//! let old_root = OldParser::parse(Rule::file, old_text)?.single()?;
+//! let new_root = NewParser::parse(Rule::file, new_text)?.single()?;
+//! for diff in old_root.diff(&new_root) {
+//!     println!("{diff:?}");
+//! }
+//! ```
+//!
+//! e.g. `Changed { path: "file[0]/block[1]/stmt[2]", old_text: "x + 1", new_text: "x + 2" }` for
+//! an edit that only touched one leaf, or `Added { path: "file[0]/block[1]", rule: stmt, text:
+//! "return x;" }` for a newly inserted statement.
+//!
+//! ## What counts as one diff
+//!
+//! Every [`NodeDiff`] is one of:
+//!
+//! - **`Added`/`Removed`** - a child present on only one side, reported once for the whole
+//!   subtree rather than recursing into it: there's nothing to compare a wholly new node against,
+//!   so [`Node::diff`] doesn't try.
+//! - **`Changed`** - the same rule at the same position on both sides, but different matched
+//!   text, reported only for a leaf (a node with no children). A composite node's own text is
+//!   redundant with whatever `Added`/`Removed`/`Changed` entries its children already produced,
+//!   so it isn't reported a second time at the parent's level.
+//!
+//! Comparison is positional, by child index - the same way [`Node::structural_eq`] walks two
+//! trees - rather than an edit-distance alignment that hunts for the smallest possible diff.
+//! Inserting or removing a sibling partway through a list shifts every index after it, so
+//! everything from that point on in the two sibling lists is reported as one `Removed`/`Added`
+//! pair per position rather than "one sibling was inserted, the rest are unchanged". For the
+//! common editor case - one edit, re-parsing just the smallest enclosing rule via
+//! [`Parser::reparse`] and diffing just that subtree against its previous version - this is
+//! rarely a problem in practice, since the edit is usually contained well inside the rule being
+//! diffed; it matters most for a diff spanning a large list with an edit near the front.
+//!
+//! ## Powering incremental re-analysis
+//!
+//! An analysis pass keyed by [`Node::id`] - see [`node_identity`] - can use a diff's path and rule
+//! to invalidate exactly the side-table entries under a changed subtree, then re-run only on the
+//! nodes a `Added`/`Removed`/`Changed` entry actually touched, leaving every subtree with no
+//! diff entry (and everything dominated by one) untouched. [`NodePath`]'s `Display` impl is
+//! stable and human-readable for exactly this kind of log line, or for a snapshot test that wants
+//! to assert "this edit only changed this one subtree" without diffing the raw source text, which
+//! says nothing about which grammar rule actually moved.
+//!
+//! [`Node`]: crate::Node
+//! [`Node::diff`]: crate::Node::diff
+//! [`Node::id`]: crate::Node::id
+//! [`Node::structural_eq`]: crate::Node::structural_eq
+//! [`NodeDiff`]: crate::NodeDiff
+//! [`NodePath`]: crate::NodePath
+//! [`Parser::reparse`]: crate::Parser::reparse
+//! [`incremental_reparse`]: super::incremental_reparse
+//! [`node_identity`]: super::node_identity
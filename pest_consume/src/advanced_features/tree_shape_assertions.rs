@@ -0,0 +1,31 @@
+//! ## Asserting a parse tree's shape from a DSL string
+//!
+//! Enabled by the `testing` feature. A grammar test that wants to assert the whole shape of a
+//! parsed tree otherwise has to either match on every node and rule by hand, or compare against a
+//! tree built up the same verbose way - both far more code than the thing being tested.
+//! [`assert_parses_as`] takes [`Parser::parse`]'s own `Result` directly, plus a compact
+//! s-expression-like description of the shape it should have:
+//!
+//! ```ignore
+//! use pest_consume::assert_parses_as;
+//!
+//! #[test]
+//! fn parses_a_function_with_one_statement() {
+//!     assert_parses_as(
+//!         MyParser::parse(Rule::func, "fn f(x) { return x; }"),
+//!         "func(ident, block(stmt))",
+//!     );
+//! }
+//! ```
+//!
+//! Every node is its rule name, bare for a leaf or `rule_name(child, child, ...)` for one with
+//! children, siblings comma-separated at every level - nothing else about a node is compared:
+//! spans, matched text, [`user_data`](crate::Node::user_data), and [`context`](crate::Node::context)
+//! are all ignored, since the shape is usually the only thing a test like this wants to pin down.
+//! Whitespace in the description is insignificant, so a deeply nested shape can be laid out across
+//! several lines however reads best. [`assert_parses_as`] panics on a mismatch, printing both the
+//! expected and the actual shape, and panics printing the [`Error`](crate::Error) if the parse
+//! itself failed - either way, same as any other failed assertion in a `#[test]` function.
+//!
+//! [`assert_parses_as`]: crate::assert_parses_as
+//! [`Parser::parse`]: crate::Parser::parse
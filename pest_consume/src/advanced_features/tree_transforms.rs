@@ -0,0 +1,47 @@
+//! ## Why a node-level rewrite pass isn't possible
+//!
+//! A [`Node`] is a thin wrapper around a [`pest::iterators::Pair`], and a [`Nodes`] around a
+//! [`pest::iterators::Pairs`] - both built, once, by pest's own generated parser state machine
+//! from the grammar's match, as an index into a shared `Rc<Vec<QueueableToken>>` token queue. That
+//! queue, and the `Pairs`/`Pair` constructors that build a view over it, are private to pest
+//! itself; neither this crate nor any other downstream of it can hand back a *different* queue -
+//! with some nodes folded together, re-ruled, or dropped - and have the rest of pest's machinery
+//! (span/text lookup, `into_inner`, ...) treat it as an ordinary parse result. There's no
+//! parse-time hook either: the state machine that builds the queue is entirely `pest_derive`
+//! generated code with no extension point for a caller to intercept a node as it's produced.
+//!
+//! So folding adjacent nodes or lowering sugar can't happen by rewriting the [`Node`] tree itself
+//! before a consuming method sees it. In practice the normalization still has a natural home,
+//! just one level up or down from the tree:
+//!
+//! - **At the grammar level**, for sugar that's purely syntactic: write the rule so the fragments
+//!   are already one node by the time pest produces it (`literal_fragment+` collapsed into one
+//!   `@{ literal_fragment+ }` atomic rule, say), rather than normalizing several sibling nodes
+//!   into one after the fact.
+//! - **At the consuming-method level**, for anything that needs real logic to fold or lower:
+//!   write the pass as an ordinary consuming method over the raw children, the same as any other
+//!   rule - `Nodes::map_to_vec`/[`Node::children_ref`] already give read access to every child
+//!   before deciding how to combine them, so "fold adjacent fragments" is just what that method's
+//!   body does with what it's handed, not a separate pre-pass.
+//! - **At the source-text level**, when the normalization is really a source rewrite (stripping
+//!   escapes, re-indenting) rather than a tree shape change: see [`source_edits`], which is built
+//!   for exactly this - collecting `(span, replacement)` edits while walking the *existing* tree
+//!   and applying them to the original text once, rather than trying to keep a second, rewritten
+//!   tree in sync with the first.
+//!
+//! This also rules out a `Nodes::map`/`flat_map` that would build a *new* [`Nodes`] - with children
+//! reordered, inserted, or synthesized - for a caller to feed back into [`match_nodes!`]: every
+//! [`match_nodes!`] arm matches directly against the `Pairs` queue underneath the `Nodes` it's
+//! given, so there's no sequence it could accept other than one already backed by that queue, and
+//! a synthetic [`Node`] with no real [`pest::iterators::Pair`] behind it - one that could report a
+//! made-up rule and text but no span - isn't a `Node` this crate knows how to build in the first
+//! place, for the same reason above. `Nodes::map_to_vec` already covers the actual use case of
+//! transforming children into something new; it just hands back the `Vec<T>` it built instead of
+//! pretending that's still a matchable `Nodes`.
+//!
+//! [`Node`]: crate::Node
+//! [`match_nodes!`]: crate::match_nodes
+//! [`Nodes`]: crate::Nodes
+//! [`Node::children_ref`]: crate::Node::children_ref
+//! [`Nodes::map_to_vec`]: crate::Nodes::map_to_vec
+//! [`source_edits`]: super::source_edits
@@ -0,0 +1,52 @@
+//! ## Observing a parse tree without consuming it
+//!
+//! [`match_nodes!`] is built around *consuming* a tree - every rule turns its children into some
+//! value, and the whole parse collapses into whatever the root rule returns. That's the wrong
+//! shape for a pass that only wants to look at the tree - a nesting-depth check, a count of how
+//! many times some rule appears, a lint that flags a pattern without producing any replacement -
+//! since there's no value to build up the way a real consuming method has one.
+//!
+//! [`Node::walk`] covers that case directly: given a [`Visitor`], it visits this node and every
+//! descendant, depth-first, pre-order, calling [`Visitor::enter`] on the way down and
+//! [`Visitor::leave`] on the way back up. Both methods default to doing nothing, so a visitor only
+//! implements the one it needs - a nesting-depth visitor only needs `enter`/`leave` to push/pop a
+//! counter, not both halves of some heavier interface:
+//!
+//! ```ignore
+//! struct DepthCounter {
+//!     current: usize,
+//!     max: usize,
+//! }
+//!
+//! impl Visitor<'_, Rule> for DepthCounter {
+//!     fn enter(&mut self, node: &Node) -> WalkControl {
+//!         if node.as_rule() == Rule::block {
+//!             self.current += 1;
+//!             self.max = self.max.max(self.current);
+//!         }
+//!         WalkControl::Continue
+//!     }
+//!
+//!     fn leave(&mut self, node: &Node) {
+//!         if node.as_rule() == Rule::block {
+//!             self.current -= 1;
+//!         }
+//!     }
+//! }
+//!
+//! let mut counter = DepthCounter { current: 0, max: 0 };
+//! root.walk(&mut counter);
+//! ```
+//!
+//! Returning [`WalkControl::SkipChildren`] from `enter` prunes that node's subtree - useful when a
+//! nested rule has its own, separately-walked meaning (a nested function's own block shouldn't
+//! count toward an outer metrics pass, say). `leave` still runs for the skipped node itself, once
+//! its children would otherwise have finished, so a push/pop pair in `enter`/`leave` stays
+//! balanced either way.
+//!
+//! [`match_nodes!`]: crate::match_nodes
+//! [`Node::walk`]: crate::Node::walk
+//! [`Visitor`]: crate::Visitor
+//! [`Visitor::enter`]: crate::Visitor::enter
+//! [`Visitor::leave`]: crate::Visitor::leave
+//! [`WalkControl::SkipChildren`]: crate::WalkControl::SkipChildren
@@ -12,7 +12,32 @@
 //! The data needs to be `Clone`, and will be cloned often so it should be cheap to clone.
 //! A common usage is to have this data be a reference, which are free to clone.
 //!
-//! If you need mutable access to some data, use [`Cell`] or [`RefCell`].
+//! That `Clone` bound is on the type actually passed as user data, not on whatever it points to -
+//! so a `&'d Data` works as user data even when `Data` itself has no `Clone` impl at all, since
+//! cloning a reference only copies the pointer, never touching the `Data` behind it. A database
+//! handle or some other resource that can't (or shouldn't) be cloned can be passed by reference
+//! straight to [`Parser::parse_with_userdata`]:
+//!
+//! ```ignore
+//! let inputs = CSVParser::parse_with_userdata(Rule::file, input_str, &db_handle)?;
+//! ```
+//!
+//! with no `Rc`/`RefCell` wrapper needed - that's only for when the data must be mutated from
+//! inside the consuming pass, not merely shared and read.
+//!
+//! If you need mutable access to some data, use [`Cell`] or [`RefCell`]. For something heavier,
+//! like an interner or an arena that should be threaded through the whole consume pass by
+//! mutable reference instead of being cloned at every node, see [`context`](super::context)
+//! instead.
+//!
+//! [`Node::user_data`] already hands back a `&D`, not a clone - reaching for `Node::user_data_ref`
+//! isn't necessary, there's nothing the plain, borrowing accessor doesn't already do. What *is*
+//! unavoidable is the `D: Clone` bound itself: every [`Node`]/[`Nodes`] descended from a parent
+//! (via [`Node::into_children`], [`Node::children_ref`], [`Nodes::fork`], ...) gets its own `D`,
+//! cloned from the parent's, since each sibling and child needs to carry its own independently.
+//! If `D` is something non-trivial to clone (an interner handle that sometimes allocates, say),
+//! that's the sign to stop passing it as user data and thread it through [`context`](super::context)
+//! by mutable reference instead, which is cloned nowhere.
 //!
 //! ```ignore
 //! struct AppSettings { ... }
@@ -34,7 +59,6 @@
 //!     CSVParser::file(input)
 //! }
 //!
-//! #[pest_consume::parser]
 //! impl CSVParser {
 //!     fn field(input: Node) -> Result<f64> {
 //!         // The settings can be retrieved from any Node.
@@ -48,13 +72,63 @@
 //! }
 //! ```
 //!
+//! ## Mixing a method that needs no data with ones that do
+//!
+//! Every consuming method is an ordinary associated function, so nothing stops one from being
+//! typed `fn rule(input: Node<'i, Rule>)` - the default, data-free `Node` - in the same `impl` as
+//! others typed against `Node<'i, Rule, &AppSettings>`. The only friction shows up when one calls
+//! the other: a `Node<'i, Rule, &AppSettings>` can't be passed directly to a function expecting
+//! `Node<'i, Rule>`, since they're different instantiations of the same type. [`Node::with_user_data`]
+//! bridges that gap by rebuilding the node around a different `D`, keeping its span, context, and
+//! error/warning buffers intact:
+//!
+//! ```ignore
+//! impl CSVParser {
+//!     fn field(input: Node<'i, Rule, &AppSettings>) -> Result<f64> {
+//!         // `trim` needs no settings at all - hand it a data-free node instead.
+//!         Self::trim(input.with_user_data(()))?;
+//!         ...
+//!     }
+//!     fn trim(input: Node<'i, Rule>) -> Result<&'i str> {
+//!         Ok(input.as_str().trim())
+//!     }
+//! }
+//! ```
+//!
+//! ## Swapping the value mid-traversal, not just the type
+//!
+//! [`Node::with_user_data`] isn't limited to bridging between two different `D` types - calling
+//! it with a new value of the *same* `D` works just as well, for data that should change on the
+//! way down a subtree rather than stay fixed for the whole parse. Entering a new lexical scope is
+//! the common case: descending into a `block` should give everything under it a different
+//! "current scope" than whatever the parent scope was, without reaching for a `RefCell`-based
+//! stack that every consuming method would have to push and pop in lockstep with the recursion.
+//! Since [`Node::with_user_data`] takes `&self` and returns a new, independent [`Node`], the
+//! original keeps its own data - descending into the rebuilt node's children is what carries the
+//! new value forward, not any change to the node you called it on:
+//!
+//! ```ignore
+//! impl BlockParser {
+//!     fn block(input: Node<Rc<Scope>>) -> Result<Vec<Stmt>> {
+//!         let inner_scope = Rc::new(Scope::nested_in(input.user_data()));
+//!         pest_consume::match_nodes!(input.with_user_data(inner_scope).into_children();
+//!             [stmt(stmts)..] => Ok(stmts),
+//!         )
+//!     }
+//! }
+//! ```
+//!
 //! [`parser`]: https://docs.rs/pest_consume_macros/1.0.1/pest_consume_macros/attr.parser.html
 //! [`match_nodes`]: macro.match_nodes.html
 //! [`Nodes`]: struct.Nodes.html
+//! [`Nodes::fork`]: struct.Nodes.html#method.fork
 //! [`Node`]: struct.Node.html
+//! [`Node::into_children`]: struct.Node.html#method.into_children
+//! [`Node::children_ref`]: struct.Node.html#method.children_ref
 //! [`Node::as_str`]: struct.Node.html#method.as_str
 //! [`Node::error`]: struct.Node.html#method.as_error
 //! [`Node::user_data`]: struct.Node.html#method.user_data
+//! [`Node::with_user_data`]: struct.Node.html#method.with_user_data
 //! [`Parser`]: trait.Parser.html
 //! [`Parser::parse`]: trait.Parser.html#method.parse
 //! [`Parser::parse_with_userdata`]: trait.Parser.html#method.parse_with_userdata
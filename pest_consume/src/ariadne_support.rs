@@ -0,0 +1,63 @@
+//! The [`AriadneReportBuilder`] type described in [`crate::advanced_features::ariadne_diagnostics`].
+
+use std::ops::Range;
+
+use ariadne::{Label, Report, ReportKind};
+use pest::error::InputLocation;
+use pest::RuleType;
+
+use crate::Error;
+
+/// Builds an [`ariadne::Report`] from an [`Error`]'s own message and span, plus whatever extra
+/// labeled spans [`with_label`](Self::with_label) attaches - e.g. "this identifier" at the error's
+/// own span, and "first defined here" at a second one. Build one with
+/// [`IntoAriadneReport::report_builder`].
+pub struct AriadneReportBuilder<R: RuleType> {
+    error: Error<R>,
+    labels: Vec<(Range<usize>, String)>,
+}
+
+impl<R: RuleType> AriadneReportBuilder<R> {
+    /// Attach a secondary label pointing at `span`, in addition to the primary one built from the
+    /// error itself. Can be called more than once to attach several.
+    pub fn with_label(mut self, span: pest::Span<'_>, message: impl ToString) -> Self {
+        self.labels.push((span.start()..span.end(), message.to_string()));
+        self
+    }
+
+    /// Build the [`ariadne::Report`]. Print it with
+    /// [`Report::eprint`]/[`Report::print`](ariadne::Report::print) against an
+    /// [`ariadne::Source`] built from the same source text the original parse was run on.
+    pub fn build(self) -> Report<'static, Range<usize>> {
+        let message = self.error.variant.message().into_owned();
+        let (offset, primary_span) = match self.error.location {
+            InputLocation::Pos(pos) => (pos, pos..pos),
+            InputLocation::Span((start, end)) => (start, start..end),
+        };
+        Report::build(ReportKind::Error, (), offset)
+            .with_message(&message)
+            .with_label(Label::new(primary_span).with_message(message))
+            .with_labels(
+                self.labels
+                    .into_iter()
+                    .map(|(span, message)| Label::new(span).with_message(message)),
+            )
+            .finish()
+    }
+}
+
+/// Extension trait starting an [`AriadneReportBuilder`] from an [`Error`]. See
+/// [`advanced_features::ariadne_diagnostics`](crate::advanced_features::ariadne_diagnostics).
+pub trait IntoAriadneReport<R: RuleType> {
+    /// Begin building an [`ariadne::Report`] from this error.
+    fn report_builder(self) -> AriadneReportBuilder<R>;
+}
+
+impl<R: RuleType> IntoAriadneReport<R> for Error<R> {
+    fn report_builder(self) -> AriadneReportBuilder<R> {
+        AriadneReportBuilder {
+            error: self,
+            labels: Vec::new(),
+        }
+    }
+}
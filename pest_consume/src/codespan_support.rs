@@ -0,0 +1,29 @@
+//! The [`IntoCodespanDiagnostic`] trait described in
+//! [`crate::advanced_features::codespan_diagnostics`].
+
+use codespan_reporting::diagnostic::{Diagnostic, Label};
+use pest::error::InputLocation;
+use pest::RuleType;
+
+use crate::Error;
+
+/// Extension trait converting an [`Error`] into a [`codespan_reporting::diagnostic::Diagnostic`].
+/// See [`advanced_features::codespan_diagnostics`](crate::advanced_features::codespan_diagnostics).
+pub trait IntoCodespanDiagnostic<R: RuleType> {
+    /// Convert this error into a [`Diagnostic`] with a primary label at the error's span in
+    /// `file_id`, ready to feed to a [`codespan_reporting::files::Files`] database for rendering.
+    fn into_diagnostic<FileId>(self, file_id: FileId) -> Diagnostic<FileId>;
+}
+
+impl<R: RuleType> IntoCodespanDiagnostic<R> for Error<R> {
+    fn into_diagnostic<FileId>(self, file_id: FileId) -> Diagnostic<FileId> {
+        let message = self.variant.message().into_owned();
+        let range = match self.location {
+            InputLocation::Pos(pos) => pos..pos,
+            InputLocation::Span((start, end)) => start..end,
+        };
+        Diagnostic::error()
+            .with_message(message.clone())
+            .with_labels(vec![Label::primary(file_id, range).with_message(message)])
+    }
+}
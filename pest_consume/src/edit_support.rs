@@ -0,0 +1,108 @@
+//! The [`Edits`]/[`OverlappingEditError`] types described in
+//! [`crate::advanced_features::source_edits`].
+
+use std::fmt;
+
+use pest::RuleType;
+
+use crate::Node;
+
+/// An edit recorded by [`Edits::add`], applied by [`Edits::apply`] once every edit for a pass has
+/// been collected. Carries the byte range it replaces so overlap can be checked against every
+/// other recorded edit, and so all of them can be applied in a single right-to-left pass over the
+/// source without any one edit shifting the byte offsets the others were recorded against.
+struct Edit {
+    start: usize,
+    end: usize,
+    replacement: String,
+}
+
+/// A set of non-overlapping source edits, built up across a consume pass and applied all at once.
+///
+/// Rewriting the source after each individual fix would invalidate every span recorded against
+/// it - the whole point of an autofix pass is to find every fixable node first, then apply
+/// everything together. `Edits` accumulates `(span, replacement)` pairs via [`Edits::add`],
+/// checking each new one against every edit already recorded, then rewrites the original source
+/// in one pass via [`Edits::apply`]. See [`advanced_features::source_edits`](crate::advanced_features::source_edits).
+pub struct Edits<'i> {
+    source: &'i str,
+    edits: Vec<Edit>,
+}
+
+impl<'i> Edits<'i> {
+    /// A fresh, empty edit set over `source`.
+    pub fn new(source: &'i str) -> Self {
+        Edits {
+            source,
+            edits: Vec::new(),
+        }
+    }
+
+    /// Records replacing `node`'s span with `replacement`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`OverlappingEditError`] - without recording the edit - if `node`'s span
+    /// overlaps, or is adjacent to (shares a boundary with), a span already recorded. Two edits
+    /// that touch the same position have no well-defined combined result, so this crate refuses
+    /// to guess rather than pick an arbitrary resolution order.
+    pub fn add<R: RuleType, D: Clone, Ctx>(
+        &mut self,
+        node: &Node<'i, R, D, Ctx>,
+        replacement: impl Into<String>,
+    ) -> Result<(), OverlappingEditError> {
+        let span = node.as_span();
+        let (start, end) = (span.start(), span.end());
+        if let Some(existing) = self
+            .edits
+            .iter()
+            .find(|edit| start <= edit.end && edit.start <= end)
+        {
+            return Err(OverlappingEditError {
+                first: (existing.start, existing.end),
+                second: (start, end),
+            });
+        }
+        self.edits.push(Edit {
+            start,
+            end,
+            replacement: replacement.into(),
+        });
+        Ok(())
+    }
+
+    /// Applies every recorded edit to the original source, in reverse offset order, so that
+    /// rewriting one edit's region never shifts the byte offsets the edits before it in the
+    /// source were recorded against. Returns the source unchanged if no edit was ever recorded.
+    pub fn apply(mut self) -> String {
+        self.edits.sort_by_key(|edit| std::cmp::Reverse(edit.start));
+        let mut result = self.source.to_owned();
+        for edit in &self.edits {
+            result.replace_range(edit.start..edit.end, &edit.replacement);
+        }
+        result
+    }
+}
+
+/// Two edits recorded on the same [`Edits`] cover overlapping (or touching) spans, returned by
+/// [`Edits::add`]. Carries both spans' `(start, end)` byte offsets for a caller that wants to
+/// report which nodes collided rather than just that something did.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OverlappingEditError {
+    /// The `(start, end)` byte offsets of the edit already recorded.
+    pub first: (usize, usize),
+    /// The `(start, end)` byte offsets of the edit that was rejected.
+    pub second: (usize, usize),
+}
+
+impl fmt::Display for OverlappingEditError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "edit at {}..{} overlaps edit already recorded at {}..{}",
+            self.second.0, self.second.1, self.first.0, self.first.1
+        )
+    }
+}
+
+impl std::error::Error for OverlappingEditError {}
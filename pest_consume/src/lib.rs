@@ -0,0 +1,3910 @@
+//! A few helpers on top of [pest] to write a full parser.
+//!
+//! See the [`advanced_features`] module for documentation on the advanced features of this
+//! crate.
+//!
+//! [pest]: https://pest.rs
+
+pub mod advanced_features {
+    //! Advanced features of this crate, documented on their own so the main docs stay focused on
+    //! the common path.
+    pub mod allocation_profile;
+    pub mod async_consuming;
+    #[cfg(feature = "ariadne")]
+    pub mod ariadne_diagnostics;
+    pub mod batch_parsing;
+    pub mod byte_input;
+    pub mod cancellation;
+    #[cfg(feature = "codespan")]
+    pub mod codespan_diagnostics;
+    pub mod collections;
+    pub mod comment_trivia;
+    pub mod context;
+    pub mod custom_errors;
+    pub mod embedded_grammars;
+    pub mod entry_point;
+    pub mod error_recovery;
+    pub mod extensible_dispatch;
+    pub mod grammar_coverage;
+    pub mod incremental_reparse;
+    pub mod keyword_dispatch;
+    pub mod memoization;
+    #[cfg(feature = "miette")]
+    pub mod miette_diagnostics;
+    pub mod named_sources;
+    pub mod no_std_support;
+    pub mod node_cloning;
+    pub mod node_filtering;
+    pub mod node_grouping;
+    pub mod node_identity;
+    pub mod node_tags;
+    #[cfg(feature = "owned_parsing")]
+    pub mod owned_parsing;
+    pub mod panic_safety;
+    pub mod parallel_consuming;
+    pub mod parent_navigation;
+    pub mod parse_limits;
+    pub mod pratt_parsing;
+    pub mod precedence_climbing;
+    pub mod recursion_limit;
+    pub mod rule_atomicity;
+    pub mod rule_enforcement;
+    #[cfg(feature = "serde")]
+    pub mod serde_deserialization;
+    #[cfg(feature = "serde")]
+    pub mod serde_serialization;
+    pub mod source_edits;
+    pub mod stack_matching;
+    pub mod streaming_reads;
+    pub mod struct_mapping;
+    pub mod testing_consuming_methods;
+    pub mod trailing_content;
+    pub mod trait_object_arms;
+    pub mod tree_diffing;
+    #[cfg(feature = "testing")]
+    pub mod tree_shape_assertions;
+    pub mod tree_transforms;
+    pub mod tree_visitor;
+    pub mod user_data;
+}
+
+#[cfg(feature = "ariadne")]
+mod ariadne_support;
+#[cfg(feature = "codespan")]
+mod codespan_support;
+mod edit_support;
+#[cfg(feature = "miette")]
+mod miette_support;
+#[cfg(feature = "owned_parsing")]
+mod owned_parsing_support;
+mod pratt;
+mod precedence;
+#[cfg(feature = "std")]
+mod read_support;
+#[cfg(feature = "serde")]
+mod serde_deserialize_support;
+#[cfg(feature = "serde")]
+mod serde_support;
+#[cfg(feature = "testing")]
+mod testing_support;
+
+pub use pest;
+#[cfg(feature = "ariadne")]
+pub use ariadne_support::{AriadneReportBuilder, IntoAriadneReport};
+#[cfg(feature = "codespan")]
+pub use codespan_support::IntoCodespanDiagnostic;
+pub use edit_support::{Edits, OverlappingEditError};
+#[cfg(feature = "miette")]
+pub use miette_support::{IntoMietteError, MietteError};
+#[cfg(feature = "owned_parsing")]
+pub use owned_parsing_support::OwnedNodes;
+pub use precedence::{Assoc, PrecClimber};
+#[cfg(feature = "std")]
+pub use read_support::ReadError;
+#[cfg(feature = "serde")]
+pub use serde_deserialize_support::DeserializeError;
+#[cfg(feature = "testing")]
+pub use testing_support::assert_parses_as;
+
+use std::cell::{Cell, Ref, RefCell, RefMut};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::hash::Hash;
+use std::ops::Deref;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use pest::iterators::{Pair, Pairs};
+use pest::error::ErrorVariant;
+use pest::RuleType;
+
+/// The error type used throughout this crate; a plain re-export of [`pest::error::Error`].
+pub type Error<R> = pest::error::Error<R>;
+
+/// One input's result from [`Parser::parse_batch`]: its name, alongside either its parsed
+/// [`Nodes`] or the [`Error`] it failed with.
+pub type BatchResult<'a, R, D> = (String, Result<Nodes<'a, R, D>, Error<R>>);
+
+/// What [`Parser::parse_prefix`] returns on success: the matched [`Nodes`], alongside whatever of
+/// the input was left unconsumed.
+pub type PrefixMatch<'a, R> = (Nodes<'a, R>, &'a str);
+
+/// What [`Parser::parse_partial`] returns: a best-effort [`Nodes`] for however much of the input
+/// parsed successfully, if any did, alongside the [`Error`] pest's own grammar match failed with -
+/// present unless the whole input parsed cleanly. See [`advanced_features::error_recovery`] for
+/// when the `Nodes` half is `Some`.
+pub type PartialParse<'a, R> = (Option<Nodes<'a, R>>, Option<Error<R>>);
+
+/// Sorts `errors` by where each one starts in the source - [`Error::new_from_span`] and
+/// [`Error::new_from_pos`] both leave that in the public `location` field, as either
+/// `InputLocation::Span((start, _))` or `InputLocation::Pos(start)` - then drops exact duplicates
+/// left adjacent by the sort. Useful after accumulating errors from more than one place in a
+/// consuming pass (e.g. [`Node::emit_error`], or [`Nodes::consume_with_recovery`]'s per-group
+/// errors), since the order they were recorded in follows traversal order, not where they are in
+/// the source, which is rarely what's worth showing a user first. The sort is stable, so errors
+/// that start at the same position (nested inside one another, say) keep whatever relative order
+/// they were recorded in.
+///
+/// [`Error::new_from_span`]: https://docs.rs/pest/latest/pest/error/struct.Error.html#method.new_from_span
+/// [`Error::new_from_pos`]: https://docs.rs/pest/latest/pest/error/struct.Error.html#method.new_from_pos
+pub fn sort_errors_by_position<R: RuleType>(errors: &mut Vec<Error<R>>) {
+    errors.sort_by_key(|error| match error.location {
+        pest::error::InputLocation::Pos(pos) => pos,
+        pest::error::InputLocation::Span((start, _)) => start,
+    });
+    errors.dedup();
+}
+
+/// A value paired with the source [`Span`](pest::Span) it was parsed from, built by
+/// [`Node::parse_spanned`]. `Deref`s to the wrapped value, so it can usually be used as a drop-in
+/// replacement for `T` itself, with [`Spanned::span`] available when the span is needed too (e.g.
+/// to build an [`Error`] that only surfaces later, once the value has been moved away from its
+/// originating `Node`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Spanned<'i, T> {
+    value: T,
+    span: pest::Span<'i>,
+}
+
+impl<'i, T> Spanned<'i, T> {
+    /// The span this value was parsed from.
+    pub fn span(&self) -> pest::Span<'i> {
+        self.span
+    }
+
+    /// Unwrap into the bare value, discarding the span.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<'i, T> Deref for Spanned<'i, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+/// An [`Error`] paired with a caller-defined code `C`, built by [`Node::error_coded`]. Since
+/// [`Error`] is a plain re-export of [`pest::error::Error`], there's no field on it to carry
+/// anything beyond a message - `CodedError` is a thin wrapper a consuming method can return
+/// directly (or convert into its own error type, the same way [`Node::error_as`] converts a plain
+/// [`Error`]) to match on `C` downstream instead of grepping the message for a category.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CodedError<R: RuleType, C> {
+    code: C,
+    error: Error<R>,
+}
+
+impl<R: RuleType, C> CodedError<R, C> {
+    /// The code this error was built with.
+    pub fn code(&self) -> &C {
+        &self.code
+    }
+
+    /// Unwrap into the underlying [`Error`], discarding the code.
+    pub fn into_error(self) -> Error<R> {
+        self.error
+    }
+}
+
+impl<R: RuleType, C: fmt::Debug> fmt::Display for CodedError<R, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (code {:?})", self.error, self.code)
+    }
+}
+
+impl<R: RuleType, C: fmt::Debug> std::error::Error for CodedError<R, C> {}
+
+/// A memoization cache for [`Node::memoize`], keyed by `(rule, span)`. Own one alongside the rest
+/// of a parse's state - a local variable, a field on [user data](advanced_features::user_data) or
+/// [context](advanced_features::context) - and pass it to every call to [`Node::memoize`] that
+/// should share its cache. See [`advanced_features::memoization`].
+pub struct Memo<'i, R, T> {
+    cache: RefCell<HashMap<(R, pest::Span<'i>), T>>,
+}
+
+impl<'i, R: RuleType, T: Clone> Memo<'i, R, T> {
+    /// An empty cache.
+    pub fn new() -> Self {
+        Memo {
+            cache: RefCell::new(HashMap::new()),
+        }
+    }
+}
+
+impl<'i, R: RuleType, T: Clone> Default for Memo<'i, R, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Where [`Node::walk`]'s traversal goes next after a call to [`Visitor::enter`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WalkControl {
+    /// Descend into this node's children as usual.
+    Continue,
+    /// Skip this node's children entirely - [`Visitor::leave`] still runs for this node once
+    /// they would have finished, but none of them are visited.
+    SkipChildren,
+}
+
+/// A tree visitor for [`Node::walk`] - for an analysis (nesting depth, a metrics count, a lint)
+/// that wants to observe a parse tree rather than transform it, which doesn't fit the
+/// consuming/[`match_nodes!`] model. Both methods default to doing nothing, so a visitor only
+/// needs to override the one(s) it cares about. See [`advanced_features::tree_visitor`].
+pub trait Visitor<'i, R, D = (), Ctx = ()> {
+    /// Called on a node before any of its children are visited. Returning
+    /// [`WalkControl::SkipChildren`] skips straight to [`leave`](Self::leave) for this same node,
+    /// without visiting its descendants at all.
+    fn enter(&mut self, _node: &Node<'i, R, D, Ctx>) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called on a node once every child [`enter`](Self::enter) chose to descend into has itself
+    /// been fully visited - or immediately after [`enter`](Self::enter), if that returned
+    /// [`WalkControl::SkipChildren`].
+    fn leave(&mut self, _node: &Node<'i, R, D, Ctx>) {}
+}
+
+/// A stable identifier for a [`Node`], suitable as a `HashMap<NodeId<R>, _>` key for an external
+/// side table that attaches information to nodes without touching the tree itself - see
+/// [`advanced_features::node_identity`]. Built from the same rule and span every clone of a given
+/// [`Node`] reports, so it's stable across clones, and deterministic for a given input: parsing
+/// the same text the same way always assigns the same ids to the nodes at the same tree
+/// positions, regardless of which order a consuming pass happens to visit them in.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct NodeId<R> {
+    rule: R,
+    start: usize,
+    end: usize,
+}
+
+/// Where a [`NodeDiff`] occurred, as a sequence of `(rule, child index)` steps from the root of
+/// whichever [`Node::diff`] call produced it - e.g. `file[0]/stmt[2]` for the third child of the
+/// first top-level item. `Display`s the same way, for a human-readable line in a test failure or
+/// an incremental-reparse log; see [`advanced_features::tree_diffing`].
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct NodePath<R>(Vec<(R, usize)>);
+
+impl<R: RuleType> fmt::Display for NodePath<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0.is_empty() {
+            return write!(f, "<root>");
+        }
+        for (i, (rule, index)) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, "/")?;
+            }
+            write!(f, "{rule:?}[{index}]")?;
+        }
+        Ok(())
+    }
+}
+
+/// One difference found by [`Node::diff`], located by its [`NodePath`]. See
+/// [`advanced_features::tree_diffing`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum NodeDiff<R> {
+    /// A node present at `path` in the second tree with no counterpart in the first.
+    Added { path: NodePath<R>, rule: R, text: String },
+    /// A node present at `path` in the first tree with no counterpart in the second.
+    Removed { path: NodePath<R>, rule: R, text: String },
+    /// The same rule at `path` in both trees, but with different matched text - only reported for
+    /// a leaf (a node with no children), since a composite node's own text is redundant with
+    /// whatever `Added`/`Removed`/`Changed` entries its children already produced.
+    Changed { path: NodePath<R>, old_text: String, new_text: String },
+}
+
+/// A single node of the parse tree, together with whatever [user data](advanced_features::user_data)
+/// was threaded through the parse, and whatever [context](advanced_features::context) it was
+/// threaded through by reference.
+///
+/// `D` is the type of the user data, and defaults to `()` for parses that don't need any. `Ctx` is
+/// the type of the context, and likewise defaults to `()`.
+pub struct Node<'i, R, D = (), Ctx = ()> {
+    pair: Pair<'i, R>,
+    user_data: D,
+    context: *mut Ctx,
+    context_lock: Rc<RefCell<()>>,
+    errors: Option<*mut Vec<Error<R>>>,
+    /// Set only by [`Parser::parse_collecting_warnings`], and shared by every [`Node`]/[`Nodes`]
+    /// descended from the same call. See [`Node::warn`].
+    warnings: Option<*mut Vec<Error<R>>>,
+    /// `None` outside of [`Parser::parse_parented`], where no parent chain is built at all. See
+    /// [`advanced_features::parent_navigation`].
+    parent_link: Option<Rc<ParentLink<'i, R>>>,
+    /// How many [`Node::into_children`]/[`Node::children_ref`] calls deep this node is, relative to
+    /// [`Parser::parse_with_depth_limit`]'s roots. Always `0` for any other entry point. See
+    /// [`advanced_features::recursion_limit`].
+    depth: usize,
+    /// Set only by [`Parser::parse_with_depth_limit`]/[`Parser::parse_with_limits`], and shared by
+    /// every [`Node`]/[`Nodes`] descended from the same call.
+    max_depth: Option<usize>,
+    /// Set only by [`Parser::parse_with_limits`], and shared by every [`Node`]/[`Nodes`] descended
+    /// from the same call. See [`Nodes::check_node_budget`].
+    max_nodes: Option<usize>,
+    /// How many nodes [`Nodes::next_node`] has produced so far across the whole consuming pass -
+    /// shared by every [`Node`]/[`Nodes`] descended from the same [`Parser::parse_with_limits`]
+    /// call, `None` for any other entry point, which track no budget at all.
+    node_count: Option<Rc<Cell<usize>>>,
+    /// Set only by [`Parser::parse_with_options`], and shared by every [`Node`]/[`Nodes`]
+    /// descended from the same call. Rules listed here never appear while iterating a [`Nodes`]
+    /// sequence's children, at any depth of descent. See
+    /// [`advanced_features::node_filtering`].
+    skip_rules: Option<Rc<Vec<R>>>,
+    /// This node's position among the siblings it was produced alongside, starting at `0` - or
+    /// `None` for a node with no such context, e.g. one built directly by [`Node::new`] or
+    /// returned by [`Node::parent`]. See [`Node::sibling_index`].
+    sibling_index: Option<usize>,
+    /// Set only by [`Parser::parse_with_cancel`], and shared by every [`Node`]/[`Nodes`] descended
+    /// from the same call. See [`Node::check_cancelled`].
+    cancel_token: Option<&'i AtomicBool>,
+    /// Set only by [`Parser::parse_with_coverage`], and shared by every [`Node`]/[`Nodes`]
+    /// descended from the same call. Every rule a [`Node`] is actually produced as - via
+    /// [`Nodes::next_node`] or anything built on it - is recorded here as it's encountered. See
+    /// [`advanced_features::grammar_coverage`].
+    coverage: Option<*mut HashSet<R>>,
+    /// Set only by [`Parser::parse_named`], and shared by every [`Node`]/[`Nodes`] descended from
+    /// the same call. Attached to every [`Error`] built from this node, so its `Display` names the
+    /// source it came from. See [`advanced_features::named_sources`].
+    path: Option<Rc<str>>,
+    /// Set only by [`Parser::parse_with_trivia`], and shared by every [`Node`]/[`Nodes`] descended
+    /// from the same call. See [`Node::leading_trivia`]/[`Node::trailing_trivia`].
+    trivia: Option<TriviaRules<R>>,
+}
+
+impl<'i, R: RuleType, D: Clone, Ctx> Node<'i, R, D, Ctx> {
+    /// The rule this node was parsed as.
+    pub fn as_rule(&self) -> R {
+        self.pair.as_rule()
+    }
+
+    /// The name of the rule this node was parsed as, e.g. `"expr"` for a node matched by the
+    /// grammar's `expr` rule. For rule-agnostic tooling - logging, a generic traversal utility -
+    /// that would rather not match on every [`Node::as_rule`] variant by hand. [`RuleType`]
+    /// doesn't expose rule names as `&'static str`, only via [`Debug`](fmt::Debug), so this
+    /// allocates; prefer [`Node::as_rule`] directly on any hot path.
+    pub fn rule_name(&self) -> String {
+        format!("{:?}", self.as_rule())
+    }
+
+    /// Whether this node was parsed as `rule`. A shorthand for `self.as_rule() == rule`.
+    pub fn matches_rule(&self, rule: R) -> bool {
+        self.as_rule() == rule
+    }
+
+    /// Whether this node was parsed as any of `rules`. A shorthand for
+    /// `matches!(self.as_rule(), ...)` across a caller-provided slice rather than a pattern
+    /// spelled out at the call site, for a set of rules chosen at runtime or shared between
+    /// several call sites.
+    pub fn matches_any(&self, rules: &[R]) -> bool {
+        rules.contains(&self.as_rule())
+    }
+
+    /// `self` if it was parsed as `rule`, an [`error`](Self::error) pointing at it otherwise. A
+    /// runtime-checked alternative to [`Node::as_rule`]/[`Node::matches_rule`] for a consuming
+    /// method that receives a `Node` from somewhere other than the macro-generated dispatch - a
+    /// helper shared between several rules, say - and wants to assert its rule up front rather
+    /// than fail confusingly further in.
+    pub fn expect_rule(self, rule: R) -> Result<Self, Error<R>> {
+        if self.as_rule() == rule {
+            Ok(self)
+        } else {
+            Err(self.error(format!(
+                "expected a `{:?}` node, found `{:?}`",
+                rule,
+                self.as_rule()
+            )))
+        }
+    }
+
+    /// Whether `self` and `other` match the same rule, span the same text, and have recursively
+    /// structurally-equal children, independent of where in their (possibly different) inputs each
+    /// was matched. Deliberately doesn't compare [`user_data`](Self::user_data) or
+    /// [`context`](Self::context) - two nodes built from the same grammar rule and the same text
+    /// are the same AST fragment regardless of what a particular parse threaded alongside them.
+    /// There's no [`PartialEq`]/[`Eq`] impl on `Node` itself, since neither byte-offset equality
+    /// (misleading when comparing fragments from different inputs) nor this structural notion is
+    /// an obvious-enough default to claim `==` for silently.
+    pub fn structural_eq<D2: Clone, Ctx2>(&self, other: &Node<'_, R, D2, Ctx2>) -> bool {
+        if self.as_rule() != other.as_rule() || self.as_str() != other.as_str() {
+            return false;
+        }
+        let mut ours = self.children_ref();
+        let mut theirs = other.children_ref();
+        loop {
+            match (ours.next(), theirs.next()) {
+                (Some(a), Some(b)) => {
+                    if !a.structural_eq(&b) {
+                        return false;
+                    }
+                }
+                (None, None) => return true,
+                _ => return false,
+            }
+        }
+    }
+
+    /// Feeds this node's structure - rule, matched text, then recursively each child - into
+    /// `state`, consistently with [`Node::structural_eq`]: two nodes that compare equal under it
+    /// always hash the same way. See [`Node::structural_eq`] for what's deliberately excluded.
+    pub fn structural_hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.as_rule().hash(state);
+        self.as_str().hash(state);
+        for child in self.children_ref() {
+            child.structural_hash(state);
+        }
+    }
+
+    /// Structurally diffs `self` against `other`, reporting every [`NodeDiff`] found, each keyed
+    /// by the [`NodePath`] of the node it's about. Builds on the same positional, by-index
+    /// comparison [`Node::structural_eq`] uses rather than re-aligning children that moved -
+    /// inserting or removing an early sibling shifts every index after it, and is reported as that
+    /// whole run of siblings changing, not chased down to a minimal edit script. See
+    /// [`advanced_features::tree_diffing`] for what that means for an incremental re-analysis that
+    /// wants to skip unchanged subtrees.
+    pub fn diff<D2: Clone, Ctx2>(&self, other: &Node<'_, R, D2, Ctx2>) -> Vec<NodeDiff<R>> {
+        let mut diffs = Vec::new();
+        Self::diff_at(NodePath(Vec::new()), self, other, &mut diffs);
+        diffs
+    }
+
+    fn diff_at<D2: Clone, Ctx2>(
+        path: NodePath<R>,
+        ours: &Node<'i, R, D, Ctx>,
+        theirs: &Node<'_, R, D2, Ctx2>,
+        diffs: &mut Vec<NodeDiff<R>>,
+    ) {
+        if ours.as_rule() != theirs.as_rule() {
+            diffs.push(NodeDiff::Removed {
+                path: path.clone(),
+                rule: ours.as_rule(),
+                text: ours.as_str().to_owned(),
+            });
+            diffs.push(NodeDiff::Added {
+                path,
+                rule: theirs.as_rule(),
+                text: theirs.as_str().to_owned(),
+            });
+            return;
+        }
+        let mut our_children = ours.children_ref();
+        let mut their_children = theirs.children_ref();
+        if our_children.is_empty() && their_children.is_empty() {
+            if ours.as_str() != theirs.as_str() {
+                diffs.push(NodeDiff::Changed {
+                    path,
+                    old_text: ours.as_str().to_owned(),
+                    new_text: theirs.as_str().to_owned(),
+                });
+            }
+            return;
+        }
+        let mut index = 0;
+        loop {
+            match (our_children.next(), their_children.next()) {
+                (Some(a), Some(b)) => {
+                    let mut child_path = path.0.clone();
+                    child_path.push((a.as_rule(), index));
+                    Self::diff_at(NodePath(child_path), &a, &b, diffs);
+                }
+                (Some(a), None) => {
+                    let mut child_path = path.0.clone();
+                    child_path.push((a.as_rule(), index));
+                    diffs.push(NodeDiff::Removed {
+                        path: NodePath(child_path),
+                        rule: a.as_rule(),
+                        text: a.as_str().to_owned(),
+                    });
+                }
+                (None, Some(b)) => {
+                    let mut child_path = path.0.clone();
+                    child_path.push((b.as_rule(), index));
+                    diffs.push(NodeDiff::Added {
+                        path: NodePath(child_path),
+                        rule: b.as_rule(),
+                        text: b.as_str().to_owned(),
+                    });
+                }
+                (None, None) => break,
+            }
+            index += 1;
+        }
+    }
+
+    /// A [`NodeId`] identifying this node for an external side table, e.g. `HashMap<NodeId<R>,
+    /// _>`, without modifying the tree to carry the extra information directly. Built from this
+    /// node's rule and span rather than tracked during the walk, so it needs no opt-in
+    /// `Parser::parse_with_*` entry point and is always available; see
+    /// [`advanced_features::node_identity`] for why that's enough to make it unique within one
+    /// parse.
+    pub fn id(&self) -> NodeId<R> {
+        let span = self.as_span();
+        NodeId {
+            rule: self.as_rule(),
+            start: span.start(),
+            end: span.end(),
+        }
+    }
+
+    /// The [`#tag`](https://pest.rs/book/grammars/syntax.html#tagged-node) attached to this node
+    /// in the grammar, if any, e.g. `"lhs"` for a node matched by `#lhs = expr`. Useful for
+    /// telling apart repeated occurrences of the same rule - see [`match_nodes!`]'s tag patterns.
+    pub fn tag(&self) -> Option<&str> {
+        self.pair.as_node_tag()
+    }
+
+    /// The text this node spans.
+    pub fn as_str(&self) -> &'i str {
+        self.pair.as_str()
+    }
+
+    /// The raw bytes this node spans, for a format that embeds binary data (base64, hex, ...)
+    /// alongside ordinary text. Since pest only ever matches on valid UTF-8 input, a node's span
+    /// always falls on a `char` boundary at both ends - the same guarantee [`Node::as_str`] relies
+    /// on - so this is never a partial multi-byte character, just `as_str().as_bytes()` without
+    /// the trip through `str`.
+    pub fn as_bytes(&self) -> &'i [u8] {
+        self.pair.as_str().as_bytes()
+    }
+
+    /// The underlying [`pest::Span`] this node spans.
+    pub fn as_span(&self) -> pest::Span<'i> {
+        self.pair.as_span()
+    }
+
+    /// The complete original input this node was parsed from, not just the slice this node itself
+    /// spans - the same string that was passed to [`Parser::parse`] or one of its siblings. Saves
+    /// having to separately plumb the source string alongside every [`Node`] just to reach it
+    /// again, e.g. for [`Node::render_context`] or [`Node::leading_trivia`]/
+    /// [`Node::trailing_trivia`], both of which need to look outside this node's own span.
+    pub fn input(&self) -> &'i str {
+        self.pair.as_span().get_input()
+    }
+
+    /// The matched text with leading and trailing ASCII whitespace stripped. Useful for a leaf
+    /// rule that can't easily be made atomic enough in the grammar to exclude surrounding
+    /// insignificant whitespace from its own match. Returns an empty slice, not a panic, if the
+    /// whole match is whitespace.
+    pub fn as_str_trimmed(&self) -> &'i str {
+        self.trim_span().as_str()
+    }
+
+    /// [`Node::as_span`] tightened to exclude leading and trailing ASCII whitespace, so an error
+    /// built from it underlines only the meaningful characters. See [`Node::as_str_trimmed`] for
+    /// the matching text.
+    pub fn trim_span(&self) -> pest::Span<'i> {
+        let s = self.as_str();
+        let start = s.len() - s.trim_start_matches(|c: char| c.is_ascii_whitespace()).len();
+        let trimmed_len = s.trim_matches(|c: char| c.is_ascii_whitespace()).len();
+        self.as_span()
+            .get(start..start + trimmed_len)
+            .expect("start..start + trimmed_len is a substring of as_str() by construction")
+    }
+
+    /// A [`pest::Span`] from the start of `self` to the end of `other` - e.g. from an opening
+    /// paren to its closing one, to span a whole parenthesized expression including everything
+    /// between them, without having to pick the two spans apart and re-glue their offsets by
+    /// hand. Errors, pointing at `self`, if `self` and `other` weren't parsed from the same input,
+    /// or if `other` ends before `self` starts.
+    pub fn span_to<D2: Clone, Ctx2>(&self, other: &Node<'i, R, D2, Ctx2>) -> Result<pest::Span<'i>, Error<R>> {
+        let input = self.input();
+        if !std::ptr::eq(input, other.input()) {
+            return Err(self.error("span_to: `self` and `other` were parsed from different inputs"));
+        }
+        pest::Span::new(input, self.as_span().start(), other.as_span().end())
+            .ok_or_else(|| self.error("span_to: `other` ends before `self` starts"))
+    }
+
+    /// The `(line, column)` of the start of this node, both 1-indexed, as reported by
+    /// [`pest::Position::line_col`]. For a zero-width node this is the position where the match
+    /// started, not a panic.
+    pub fn line_col(&self) -> (usize, usize) {
+        self.pair.as_span().start_pos().line_col()
+    }
+
+    /// The `(line, column)` of the end of this node. See [`Node::line_col`] for the start.
+    pub fn end_line_col(&self) -> (usize, usize) {
+        self.pair.as_span().end_pos().line_col()
+    }
+
+    /// Renders this node's span as a source excerpt with a caret underline, rustc-style, for CLI
+    /// error output that wants more context than [`Error`]'s own one-line-at-a-time rendering
+    /// gives. `lines_before`/`lines_after` control how many extra lines of context surround the
+    /// span on either side; a span crossing line boundaries underlines every line it touches, one
+    /// line at a time. Tabs are expanded to a fixed width in both the printed line and the caret
+    /// underneath it, so the caret still lines up with the character pest's own (byte-based,
+    /// tab-agnostic) column counting points at.
+    pub fn render_context(&self, lines_before: usize, lines_after: usize) -> String {
+        render_context(self.pair.as_span(), lines_before, lines_after)
+    }
+
+    /// Build an [`Error`] pointing at this node, with the given message. If this parse was started
+    /// with [`Parser::parse_named`], the error's `Display` also names the source it came from, via
+    /// [`pest::error::Error::with_path`]. If it was started with [`Parser::parse_parented`], the
+    /// message is also prefixed with this node's [`rule_path`](Self::rule_path) followed by its
+    /// own rule, e.g. `file > function > block > statement > expr: ...` - the same leaf rule often
+    /// recurs in many contexts in a deeply nested grammar, and the bare message alone doesn't say
+    /// which one this error actually came from.
+    pub fn error(&self, message: impl ToString) -> Error<R> {
+        self.apply_path(Error::new_from_span(
+            ErrorVariant::CustomError {
+                message: self.message_with_rule_path(message.to_string()),
+            },
+            self.pair.as_span(),
+        ))
+    }
+
+    /// Prefixes `message` with this node's rule path, as described on [`Node::error`] - or leaves
+    /// it untouched outside [`Parser::parse_parented`], where [`Node::rule_path`] is always empty.
+    fn message_with_rule_path(&self, message: String) -> String {
+        let path = self.rule_path();
+        if path.is_empty() {
+            return message;
+        }
+        let chain: Vec<String> = path
+            .iter()
+            .map(|rule| format!("{rule:?}"))
+            .chain(std::iter::once(self.rule_name()))
+            .collect();
+        format!("{}: {message}", chain.join(" > "))
+    }
+
+    /// Attach this node's source path, if any, to `error` - the common tail of every [`Error`]
+    /// constructor on this `Node`.
+    fn apply_path(&self, error: Error<R>) -> Error<R> {
+        match &self.path {
+            Some(path) => error.with_path(path),
+            None => error,
+        }
+    }
+
+    /// Like [`Node::error`], but converted into a custom error type `E` via `E: From<Error<R>>`,
+    /// for consuming methods that report semantic errors of their own instead of `Error<R>`. See
+    /// [`advanced_features::custom_errors`].
+    pub fn error_as<E: From<Error<R>>>(&self, message: impl ToString) -> E {
+        self.error(message).into()
+    }
+
+    /// Like [`Node::error`], but bundled with a caller-defined code `C` into a [`CodedError`],
+    /// for downstream code that wants to match on an error category programmatically instead of
+    /// inspecting the message. `C` is whatever the caller's own error taxonomy needs - an enum of
+    /// categories, a numeric code, ... - and travels alongside the built [`Error`] rather than
+    /// living on it, since [`Error`] is a plain re-export of [`pest::error::Error`] with no field
+    /// to carry one.
+    pub fn error_coded<C>(&self, code: C, message: impl ToString) -> CodedError<R, C> {
+        CodedError {
+            code,
+            error: self.error(message),
+        }
+    }
+
+    /// Parse this node's [`as_str`](Self::as_str) via `T`'s [`FromStr`](std::str::FromStr), converting a failure into
+    /// a [`Node::error`] located at this node rather than a bare `T::Err`. Collapses the common leaf
+    /// rule `input.as_str().parse::<f64>().map_err(|e| input.error(e.to_string()))` into
+    /// `input.parse_str::<f64>()`.
+    pub fn parse_str<T: std::str::FromStr>(&self) -> Result<T, Error<R>>
+    where
+        T::Err: ToString,
+    {
+        self.as_str().parse().map_err(|e: T::Err| self.error(e.to_string()))
+    }
+
+    /// This node's [`as_str`](Self::as_str) as a single `char`, for a leaf rule that always
+    /// matches exactly one Unicode scalar value - an operator, a digit, an escape character.
+    /// Errors, with this node's span, if the match is empty or spans more than one `char`; a
+    /// multi-byte character still counts as one, same as [`str::chars`] counting.
+    pub fn as_char(&self) -> Result<char, Error<R>> {
+        let mut chars = self.as_str().chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Ok(c),
+            _ => Err(self.error(format!(
+                "expected exactly one character, found {:?}",
+                self.as_str()
+            ))),
+        }
+    }
+
+    /// Re-parses this node's [`as_str`](Self::as_str) with a second, unrelated grammar `P`,
+    /// starting from `rule` - for a node whose matched text is itself a whole embedded language,
+    /// e.g. an expression sub-grammar spliced into a larger config format. A thin wrapper around
+    /// `P::parse(rule, self.as_str())`, spelled out once so the cross-grammar boundary is just
+    /// another method call rather than code every embedding site has to repeat. See
+    /// [`advanced_features::embedded_grammars`].
+    pub fn parse_embedded<P>(&self, rule: P::Rule) -> Result<Nodes<'i, P::Rule>, Error<P::Rule>>
+    where
+        P: Parser + pest::Parser<P::Rule>,
+    {
+        <P as Parser>::parse(rule, self.as_str())
+    }
+
+    /// Run `f` on this node, caching the result in `memo` keyed by this node's `(rule, span)` so a
+    /// later call with an identical key - the same sub-span consumed again, e.g. via backtracking
+    /// across grammar alternatives - returns the cached value instead of running `f` again. `T`
+    /// must be `Clone` since the cached value is handed out by value on every hit, not by
+    /// reference. See [`advanced_features::memoization`].
+    pub fn memoize<T: Clone, E>(&self, memo: &Memo<'i, R, T>, f: impl FnOnce(&Self) -> Result<T, E>) -> Result<T, E> {
+        let key = (self.as_rule(), self.pair.as_span());
+        if let Some(cached) = memo.cache.borrow().get(&key) {
+            return Ok(cached.clone());
+        }
+        let value = f(self)?;
+        memo.cache.borrow_mut().insert(key, value.clone());
+        Ok(value)
+    }
+
+    /// Like [`Node::error`], but pointing at `span` instead of this node's whole span - for a
+    /// semantic check that knows exactly which sub-slice is at fault (e.g. one argument of a call
+    /// node that spans the whole call). `span` isn't required to be a sub-span of this node's own;
+    /// any [`pest::Span`] borrowed from the same input works.
+    pub fn error_with_span(&self, message: impl ToString, span: pest::Span<'i>) -> Error<R> {
+        self.apply_path(Error::new_from_span(
+            ErrorVariant::CustomError {
+                message: message.to_string(),
+            },
+            span,
+        ))
+    }
+
+    /// Like [`Node::error_with_span`], but finds `needle` as a substring of this node's own
+    /// [`as_str`](Self::as_str) and points at the first occurrence, rather than requiring the
+    /// caller to already have a [`pest::Span`] in hand. Falls back to this node's whole span if
+    /// `needle` doesn't occur in it.
+    pub fn error_at_str(&self, message: impl ToString, needle: &str) -> Error<R> {
+        let span = self
+            .as_str()
+            .find(needle)
+            .and_then(|start| self.as_span().get(start..start + needle.len()))
+            .unwrap_or_else(|| self.as_span());
+        self.error_with_span(message, span)
+    }
+
+    /// Build an [`Error`] reporting that this node's rule has no consuming method to handle it -
+    /// `"no consuming method for rule X"`, pointing at this node. [`match_nodes!`] already refuses
+    /// to compile over a rule with no matching `Self::rule_name` function, since it resolves that
+    /// call the same way any other Rust code would; this is for a hand-rolled dispatch
+    /// ([`advanced_features::extensible_dispatch`]) that falls through to a rule it doesn't
+    /// recognize instead, which has no such compile-time check to lean on. Equivalent to
+    /// `self.error(format!("no consuming method for rule {:?}", self.as_rule()))`, spelled out once
+    /// so every hand-rolled dispatch reports the same message.
+    pub fn error_no_consuming_method(&self) -> Error<R> {
+        self.error(format!("no consuming method for rule {:?}", self.as_rule()))
+    }
+
+    /// The user data threaded through the parse.
+    pub fn user_data(&self) -> &D {
+        &self.user_data
+    }
+
+    /// A clone of this node, with its user data replaced by `data`. Everything else - span,
+    /// [`context`](Self::context), errors/warnings buffers, depth, and the rest of what the parse
+    /// threaded through - carries over unchanged. Useful for calling a consuming method that needs
+    /// different (or no) user data than the caller's own, without threading a second type through
+    /// [`Parser::parse_with_userdata`] - e.g. a method typed `fn rule(input: Node<'i, Rule>)` with
+    /// no data at all, called as `Self::rule(input.with_user_data(()))` from one that does carry
+    /// some. Unlike [`Node::new`], which builds an unrelated node from scratch and so loses the
+    /// context, error buffer, and parent chain this one carries, `with_user_data` keeps all of it -
+    /// only `D` itself changes.
+    pub fn with_user_data<D2: Clone>(&self, data: D2) -> Node<'i, R, D2, Ctx> {
+        Node {
+            pair: self.pair.clone(),
+            user_data: data,
+            context: self.context,
+            context_lock: Rc::clone(&self.context_lock),
+            errors: self.errors,
+            warnings: self.warnings,
+            parent_link: self.parent_link.clone(),
+            depth: self.depth,
+            max_depth: self.max_depth,
+            max_nodes: self.max_nodes,
+            node_count: self.node_count.clone(),
+            skip_rules: self.skip_rules.clone(),
+            sibling_index: self.sibling_index,
+            cancel_token: self.cancel_token,
+            coverage: self.coverage,
+            path: self.path.clone(),
+            trivia: self.trivia,
+        }
+    }
+
+    /// A shared borrow of the context threaded through the parse, as described in
+    /// [`advanced_features::context`]. For parses that don't use [`Parser::parse_with_context`],
+    /// this is just `&()`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if another `Node`/`Nodes` sharing this context currently holds a
+    /// [`context_mut`](Self::context_mut) borrow - e.g. two sibling `Node`s produced by the same
+    /// `Nodes` both reaching this before either is done with it.
+    pub fn context(&self) -> Ref<'_, Ctx> {
+        let lock = self.context_lock.borrow();
+        Ref::map(lock, |()| {
+            // Safety: `context` either points at a live `Ctx` that `Parser::parse_with_context`
+            // borrowed mutably for at least as long as this whole consume pass (see that method),
+            // or, when no context was threaded through, is a dangling-but-aligned pointer to the
+            // zero-sized `()` - sound to dereference, since reading a `()` never actually touches
+            // memory. `self.context_lock`, just borrowed above and shared with every sibling
+            // `Node`/`Nodes` derived from the same context, is what actually rules out a
+            // concurrent `context_mut` borrow aliasing this one.
+            unsafe { &*self.context }
+        })
+    }
+
+    /// A mutable borrow of the context threaded through the parse. See [`Node::context`] for a
+    /// shared borrow, and [`advanced_features::context`] for the full picture.
+    ///
+    /// # Panics
+    ///
+    /// Panics if another `Node`/`Nodes` sharing this context currently holds any
+    /// [`context`](Self::context) or `context_mut` borrow.
+    pub fn context_mut(&self) -> RefMut<'_, Ctx> {
+        let lock = self.context_lock.borrow_mut();
+        RefMut::map(lock, |()| {
+            // Safety: see `Node::context`.
+            unsafe { &mut *self.context }
+        })
+    }
+
+    /// Record a non-fatal `error` into the shared buffer from
+    /// [`Parser::parse_collecting_errors`], instead of aborting the whole parse, as described in
+    /// [`advanced_features::error_recovery`]. Outside of `parse_collecting_errors`, there is no
+    /// buffer to record into, so this is a no-op.
+    pub fn emit_error(&self, error: Error<R>) {
+        if let Some(errors) = self.errors {
+            // Safety: `errors` was derived from a `&mut Vec<Error<R>>` that
+            // `Parser::parse_collecting_errors` keeps borrowed for the whole consume pass, and is
+            // never touched anywhere else during that pass.
+            unsafe { (*errors).push(error) };
+        }
+    }
+
+    /// Record a non-fatal warning pointing at this node into the shared buffer from
+    /// [`Parser::parse_collecting_warnings`], for a lint-like pass that wants to report more than
+    /// one issue without aborting the parse. Outside of `parse_collecting_warnings`, there is no
+    /// buffer to record into, so this is a no-op. Unlike [`Node::emit_error`], there's no way to
+    /// hand in an already-built [`Error`] - a warning is always built from `message` and this
+    /// node's own span, since a warning is never surfaced on its own the way a collected error can
+    /// be with [`Node::error`] first.
+    pub fn warn(&self, message: impl ToString) {
+        if let Some(warnings) = self.warnings {
+            let warning = self.error(message);
+            // Safety: see `Node::emit_error` - the same reasoning applies to `warnings`.
+            unsafe { (*warnings).push(warning) };
+        }
+    }
+
+    /// This node's children, as a fresh [`Nodes`] value.
+    pub fn into_children(self) -> Nodes<'i, R, D, Ctx> {
+        let span = self.pair.as_span();
+        let child_link = self.child_link();
+        let original = filtered_pairs(self.pair.clone().into_inner(), &self.skip_rules);
+        let pairs = filtered_pairs(self.pair.into_inner(), &self.skip_rules);
+        Nodes {
+            pairs,
+            original,
+            user_data: self.user_data,
+            context: self.context,
+            context_lock: self.context_lock,
+            errors: self.errors,
+            warnings: self.warnings,
+            parent_span: span,
+            parent_link: child_link,
+            depth: self.depth + 1,
+            max_depth: self.max_depth,
+            max_nodes: self.max_nodes,
+            node_count: self.node_count.clone(),
+            skip_rules: self.skip_rules,
+            next_sibling_index: 0,
+            cancel_token: self.cancel_token,
+            coverage: self.coverage,
+            path: self.path.clone(),
+            trivia: self.trivia,
+        }
+    }
+
+    /// Like [`Node::into_children`], but returns an [`Error`] instead of a silently empty
+    /// [`Nodes`] when this node turns out to have no children at all. Useful for a rule that
+    /// should always be compound - asserting that up front catches a grammar/consuming-method
+    /// mismatch immediately, rather than having it surface later as a confusing empty match in
+    /// [`match_nodes!`].
+    pub fn try_into_children(self) -> Result<Nodes<'i, R, D, Ctx>, Error<R>> {
+        let error = self.error(format!("{} has no children to consume", self.rule_name()));
+        let children = self.into_children();
+        if children.is_empty() {
+            Err(error)
+        } else {
+            Ok(children)
+        }
+    }
+
+    /// This node's children, as a fresh [`Nodes`] value, without consuming the node. Use this
+    /// instead of [`Node::into_children`] when you still need the node itself afterwards, e.g. to
+    /// check the number of children before dispatching into [`match_nodes!`].
+    pub fn children_ref(&self) -> Nodes<'i, R, D, Ctx> {
+        Nodes {
+            pairs: filtered_pairs(self.pair.clone().into_inner(), &self.skip_rules),
+            original: filtered_pairs(self.pair.clone().into_inner(), &self.skip_rules),
+            user_data: self.user_data.clone(),
+            context: self.context,
+            context_lock: Rc::clone(&self.context_lock),
+            errors: self.errors,
+            warnings: self.warnings,
+            parent_span: self.pair.as_span(),
+            parent_link: self.child_link(),
+            depth: self.depth + 1,
+            max_depth: self.max_depth,
+            max_nodes: self.max_nodes,
+            node_count: self.node_count.clone(),
+            skip_rules: self.skip_rules.clone(),
+            next_sibling_index: 0,
+            cancel_token: self.cancel_token,
+            coverage: self.coverage,
+            path: self.path.clone(),
+            trivia: self.trivia,
+        }
+    }
+
+    /// The number of direct children of this node matching `rule`, without consuming it. A cheap
+    /// check before dispatching into [`match_nodes!`] - e.g. to tell whether a function has any
+    /// parameters - rather than writing the filter-and-count over [`Node::children_ref`] by hand.
+    pub fn count_children(&self, rule: R) -> usize {
+        self.children_ref().filter(|child| child.as_rule() == rule).count()
+    }
+
+    /// The [`ParentLink`] this node's own children should carry, built by pushing this node onto
+    /// its own `parent_link` - or `None` outside of [`Parser::parse_parented`], where there's no
+    /// chain to extend.
+    fn child_link(&self) -> Option<Rc<ParentLink<'i, R>>> {
+        let link = self.parent_link.as_ref()?;
+        Some(Rc::new(ParentLink::Node {
+            pair: self.pair.clone(),
+            parent: Rc::clone(link),
+        }))
+    }
+
+    /// The underlying [`pest::iterators::Pair`].
+    pub fn as_pair(&self) -> &Pair<'i, R> {
+        &self.pair
+    }
+
+    /// Consume this node into the underlying [`pest::iterators::Pair`], for a pest feature (e.g.
+    /// [`Pair::as_node_tag`] beyond what [`Node::tag`] surfaces, or a manual re-walk of
+    /// [`Pair::into_inner`]) this crate doesn't wrap yet. [`Node::new`] re-enters pest_consume from
+    /// the result, carrying forward this node's user data.
+    pub fn into_pair(self) -> Pair<'i, R> {
+        self.pair
+    }
+
+    /// This node's parent, if it has one. Only available on a [`Node`] produced by
+    /// [`Parser::parse_parented`] - otherwise always `None`, even for a node that does have a
+    /// parent in the grammar's tree, since no parent chain was built while descending into it. See
+    /// [`advanced_features::parent_navigation`].
+    pub fn parent(&self) -> Option<Node<'i, R, D, Ctx>> {
+        match self.parent_link.as_deref()? {
+            ParentLink::Root => None,
+            ParentLink::Node { pair, parent } => Some(Node {
+                pair: pair.clone(),
+                user_data: self.user_data.clone(),
+                context: self.context,
+                context_lock: Rc::clone(&self.context_lock),
+                errors: self.errors,
+                warnings: self.warnings,
+                parent_link: Some(Rc::clone(parent)),
+                depth: self.depth.saturating_sub(1),
+                max_depth: self.max_depth,
+                max_nodes: self.max_nodes,
+                node_count: self.node_count.clone(),
+                skip_rules: self.skip_rules.clone(),
+                sibling_index: None,
+                cancel_token: self.cancel_token,
+                coverage: self.coverage,
+                path: self.path.clone(),
+                trivia: self.trivia,
+            }),
+        }
+    }
+
+    /// The rule of every ancestor of this node, from the outermost root down to (but not
+    /// including) this node's own rule. Only available on a [`Node`] produced by
+    /// [`Parser::parse_parented`] - otherwise always empty, the same way [`Node::parent`] is
+    /// always `None` without a parent chain to walk. See
+    /// [`advanced_features::parent_navigation`].
+    pub fn rule_path(&self) -> Vec<R> {
+        let mut path = Vec::new();
+        let mut link = self.parent_link.as_deref();
+        while let Some(ParentLink::Node { pair, parent }) = link {
+            path.push(pair.as_rule());
+            link = Some(parent);
+        }
+        path.reverse();
+        path
+    }
+
+    /// Whether this node was parsed while some rule in `atomic_rules` was active on the way down
+    /// to it - that is, whether `self` or one of its ancestors is in that set. pest resolves the
+    /// `@`/`$`/`!` modifiers once, while `pest_derive` generates the parser, and the resulting
+    /// token stream this crate wraps keeps no trace of which modifier was in effect for a given
+    /// match - so `atomic_rules` has to be the caller's own record of which of the grammar's rules
+    /// are declared atomic or compound-atomic, there's nothing to read it back from post-parse.
+    /// Requires [`Parser::parse_parented`], like [`Node::parent`] itself - without a parent chain
+    /// to walk, this can only ever see `self`. Doesn't account for a `!`-marked rule resetting
+    /// atomicity partway back up the chain; a grammar that relies on that should account for it
+    /// in how it builds `atomic_rules` instead. See [`advanced_features::rule_atomicity`].
+    pub fn in_atomic_context(&self, atomic_rules: &HashSet<R>) -> bool {
+        let mut current = Some(self.clone());
+        while let Some(node) = current {
+            if atomic_rules.contains(&node.as_rule()) {
+                return true;
+            }
+            current = node.parent();
+        }
+        false
+    }
+
+    /// How many [`into_children`](Node::into_children)/[`children_ref`](Node::children_ref) calls
+    /// deep this node is, relative to the roots passed to [`Parser::parse_with_depth_limit`] - or
+    /// always `0` for any other entry point, which track no depth at all. See
+    /// [`advanced_features::recursion_limit`].
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// This node's position among the siblings it was produced alongside, starting at `0` - e.g.
+    /// `0` for the first node [`Nodes::next_node`]/[`Nodes::peek`] hands out from a given
+    /// sequence, `1` for the next, and so on. Lets a consuming method special-case the first or
+    /// last element of a list (e.g. trailing-comma handling) without restructuring the grammar to
+    /// tag it. `None` for a [`Node`] with no such sequence behind it - one built directly by
+    /// [`Node::new`], or returned by [`Node::parent`].
+    pub fn sibling_index(&self) -> Option<usize> {
+        self.sibling_index
+    }
+
+    /// The comments immediately before this node - reconstructed from the source text between the
+    /// end of its previous sibling (or the start of its parent, if it's the first child) and the
+    /// start of this node's own span. Requires [`Parser::parse_with_trivia`]; always empty
+    /// otherwise, and always empty for a node with no [`Node::parent`] (there's no sibling context
+    /// to look at). Stops at the first piece of source text that's neither `comment_rule` nor
+    /// `whitespace_rule` - most commonly a literal the grammar matched without giving it a pair of
+    /// its own, e.g. the `"("` right before an expression. See
+    /// [`advanced_features::comment_trivia`].
+    pub fn leading_trivia(&self) -> Vec<&'i str> {
+        self.trivia_gap(Trivia::Leading)
+    }
+
+    /// The comments immediately after this node - the mirror image of [`Node::leading_trivia`],
+    /// reconstructed from the source text between the end of this node's own span and the start of
+    /// its next sibling (or the end of its parent, if it's the last child). Same requirements and
+    /// the same stops-at-the-first-non-trivia-text caveat as [`Node::leading_trivia`].
+    pub fn trailing_trivia(&self) -> Vec<&'i str> {
+        self.trivia_gap(Trivia::Trailing)
+    }
+
+    /// Shared implementation of [`Node::leading_trivia`]/[`Node::trailing_trivia`]: finds the gap
+    /// of source text on the requested side of this node, by looking up its siblings through
+    /// [`Node::parent`], then tokenizes that gap against `trivia`'s `comment_rule`/
+    /// `whitespace_rule`.
+    fn trivia_gap(&self, side: Trivia) -> Vec<&'i str> {
+        let Some(trivia) = self.trivia else { return Vec::new() };
+        let Some(index) = self.sibling_index else { return Vec::new() };
+        let Some(ParentLink::Node { pair: parent_pair, .. }) = self.parent_link.as_deref() else {
+            return Vec::new();
+        };
+        let siblings: Vec<_> = parent_pair.clone().into_inner().collect();
+        let Some(this_span) = siblings.get(index).map(|pair| pair.as_span()) else {
+            return Vec::new();
+        };
+        let gap_start = match side {
+            Trivia::Leading if index == 0 => parent_pair.as_span().start(),
+            Trivia::Leading => siblings[index - 1].as_span().end(),
+            Trivia::Trailing => this_span.end(),
+        };
+        let gap_end = match side {
+            Trivia::Leading => this_span.start(),
+            Trivia::Trailing if index + 1 == siblings.len() => parent_pair.as_span().end(),
+            Trivia::Trailing => siblings[index + 1].as_span().start(),
+        };
+        let Some(gap) = this_span.get_input().get(gap_start..gap_end) else {
+            return Vec::new();
+        };
+        tokenize_trivia(trivia, gap)
+    }
+
+    /// Every descendant of this node - not just its direct [children](Self::children_ref), but
+    /// theirs in turn, and so on - in pre-order (a node before any of its own descendants). Doesn't
+    /// include this node itself. See [`Node::find_all`] to filter by rule while walking.
+    pub fn descendants(&self) -> impl Iterator<Item = Node<'i, R, D, Ctx>> {
+        let mut stack: Vec<_> = self.children_ref().collect();
+        stack.reverse();
+        std::iter::from_fn(move || {
+            let node = stack.pop()?;
+            let mut children: Vec<_> = node.children_ref().collect();
+            children.reverse();
+            stack.append(&mut children);
+            Some(node)
+        })
+    }
+
+    /// Every [`descendant`](Self::descendants) of this node matching `rule`, in pre-order. A
+    /// shorthand for a "find all identifiers"-style query (e.g. computing a symbol list) without
+    /// writing the recursive walk by hand.
+    pub fn find_all(&self, rule: R) -> impl Iterator<Item = Node<'i, R, D, Ctx>> {
+        self.descendants().filter(move |node| node.as_rule() == rule)
+    }
+
+    /// Reconstructs this node's source text, calling `f` on every node encountered (this one
+    /// included, then each of its children, recursively) to ask whether to substitute its text.
+    /// `Some(replacement)` uses `replacement` in place of that node's own text and skips descending
+    /// any further into it; `None` falls back to gluing this node's own [`Node::as_str`] back
+    /// together around whatever its children themselves reconstruct to, so any text that isn't
+    /// itself a child (whitespace, punctuation, comments) survives untouched. With an `f` that
+    /// always returns `None`, this always produces exactly [`Node::as_str`] of `self` - the
+    /// backbone of a rename-style refactor that wants to substitute just a handful of identifier
+    /// nodes while reproducing everything else byte-for-byte.
+    pub fn reconstruct(&self, mut f: impl FnMut(&Node<'i, R, D, Ctx>) -> Option<String>) -> String {
+        self.reconstruct_with(&mut f)
+    }
+
+    fn reconstruct_with(&self, f: &mut impl FnMut(&Node<'i, R, D, Ctx>) -> Option<String>) -> String {
+        if let Some(replacement) = f(self) {
+            return replacement;
+        }
+        let text = self.as_str();
+        let base = self.pair.as_span().start();
+        let mut result = String::with_capacity(text.len());
+        let mut pos = 0;
+        for child in self.children_ref() {
+            let child_span = child.as_span();
+            result.push_str(&text[pos..child_span.start() - base]);
+            result.push_str(&child.reconstruct_with(f));
+            pos = child_span.end() - base;
+        }
+        result.push_str(&text[pos..]);
+        result
+    }
+
+    /// Run a consuming closure over this node, and bundle its result with the node's span into a
+    /// [`Spanned`] - a shorthand for the common pattern of capturing `input.as_span()` before
+    /// consuming `input`, then carrying both along together.
+    pub fn parse_spanned<T, E>(
+        self,
+        f: impl FnOnce(Self) -> Result<T, E>,
+    ) -> Result<Spanned<'i, T>, E> {
+        let span = self.as_span();
+        let value = f(self)?;
+        Ok(Spanned { value, span })
+    }
+
+    /// Render this node's subtree as an indented outline of rule names and truncated matched
+    /// text, for `eprintln!("{}", node.debug_tree())`-style debugging when a [`match_nodes!`] arm
+    /// unexpectedly fails to match. This is also how [`Node`]'s [`Debug`](fmt::Debug) impl renders.
+    pub fn debug_tree(&self) -> String {
+        let base_depth = self.depth;
+        let mut lines = vec![Self::debug_tree_line(self, 0)];
+        for node in self.descendants() {
+            lines.push(Self::debug_tree_line(&node, node.depth.saturating_sub(base_depth)));
+        }
+        lines.join("\n")
+    }
+
+    fn debug_tree_line(node: &Self, indent: usize) -> String {
+        format!(
+            "{}{:?} {:?}",
+            "  ".repeat(indent),
+            node.as_rule(),
+            truncate_for_debug(node.as_str()),
+        )
+    }
+
+    /// Walks this node's subtree depth-first, pre-order - this node, then its children in order,
+    /// each recursively - calling `visitor`'s [`Visitor::enter`] and [`Visitor::leave`] around
+    /// every node. See [`advanced_features::tree_visitor`].
+    pub fn walk(&self, visitor: &mut impl Visitor<'i, R, D, Ctx>) {
+        if visitor.enter(self) == WalkControl::SkipChildren {
+            visitor.leave(self);
+            return;
+        }
+        for child in self.children_ref() {
+            child.walk(visitor);
+        }
+        visitor.leave(self);
+    }
+}
+
+impl<'i, R: RuleType> Node<'i, R> {
+    /// The smallest [`pest::Span`] enclosing every node in `nodes`, e.g. merging several of a
+    /// call's argument nodes into one span covering all of them. `None` for an empty slice, or if
+    /// `nodes` weren't all parsed from the same input - there's no single node to blame for either,
+    /// unlike [`Node::span_to`], so this reports failure by returning `None` rather than an
+    /// [`Error`]. Lives on `Node<'i, R>` rather than `self`, since merging is a property of the
+    /// whole slice, not of any one node in it.
+    pub fn span_merge<D2: Clone, Ctx2>(nodes: &[Node<'i, R, D2, Ctx2>]) -> Option<pest::Span<'i>> {
+        let input = nodes.first()?.input();
+        if nodes.iter().any(|node| !std::ptr::eq(node.input(), input)) {
+            return None;
+        }
+        let start = nodes.iter().map(|node| node.as_span().start()).min()?;
+        let end = nodes.iter().map(|node| node.as_span().end()).max()?;
+        pest::Span::new(input, start, end)
+    }
+}
+
+impl<'i, R: RuleType, D: Clone, Ctx> fmt::Debug for Node<'i, R, D, Ctx> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.debug_tree())
+    }
+}
+
+/// Sharing, not deep-copying - see [`advanced_features::node_cloning`].
+impl<'i, R: RuleType, D: Clone, Ctx> Clone for Node<'i, R, D, Ctx> {
+    fn clone(&self) -> Self {
+        self.with_user_data(self.user_data.clone())
+    }
+}
+
+/// Truncate, for [`Node::debug_tree`], to keep one subtree's outline from overwhelming the
+/// output with a single long string (e.g. a whole matched string literal).
+const DEBUG_TREE_MAX_LEN: usize = 40;
+
+fn truncate_for_debug(s: &str) -> std::borrow::Cow<'_, str> {
+    if s.chars().count() <= DEBUG_TREE_MAX_LEN {
+        return std::borrow::Cow::Borrowed(s);
+    }
+    let truncated: String = s.chars().take(DEBUG_TREE_MAX_LEN).collect();
+    std::borrow::Cow::Owned(format!("{truncated}..."))
+}
+
+/// How many display columns [`render_context`] expands a tab to, both in the printed source line
+/// and in the caret underneath it, so the two stay aligned regardless of the reader's own
+/// terminal's tab width.
+const RENDER_CONTEXT_TAB_WIDTH: usize = 4;
+
+fn render_context_expand_tabs(line: &str) -> String {
+    line.chars()
+        .map(|c| if c == '\t' { " ".repeat(RENDER_CONTEXT_TAB_WIDTH) } else { c.to_string() })
+        .collect()
+}
+
+/// The display column (0-indexed, tabs expanded) of the `col`-th character (1-indexed, as
+/// reported by [`pest::Position::line_col`]) of `line`.
+fn render_context_display_column(line: &str, col: usize) -> usize {
+    line.chars()
+        .take(col.saturating_sub(1))
+        .map(|c| if c == '\t' { RENDER_CONTEXT_TAB_WIDTH } else { 1 })
+        .sum()
+}
+
+/// Implements [`Node::render_context`] on a bare [`pest::Span`], so it can also back a rendering
+/// helper built from a raw span rather than a [`Node`], if that's ever needed.
+fn render_context(span: pest::Span<'_>, lines_before: usize, lines_after: usize) -> String {
+    let input = span.get_input();
+    let lines: Vec<&str> = input.split('\n').collect();
+    let (start_line, start_col) = span.start_pos().line_col();
+    let (end_line, end_col) = span.end_pos().line_col();
+    let first_line = start_line.saturating_sub(lines_before).max(1);
+    let last_line = (end_line + lines_after).min(lines.len());
+    let gutter_width = last_line.to_string().len();
+
+    let mut out = Vec::new();
+    for line_no in first_line..=last_line {
+        let text = lines[line_no - 1];
+        let expanded = render_context_expand_tabs(text);
+        out.push(format!("{line_no:>gutter_width$} | {expanded}"));
+        if line_no < start_line || line_no > end_line {
+            continue;
+        }
+        let underline_start = if line_no == start_line {
+            render_context_display_column(text, start_col)
+        } else {
+            0
+        };
+        let underline_end = if line_no == end_line {
+            render_context_display_column(text, end_col)
+        } else {
+            expanded.chars().count()
+        };
+        let underline_end = underline_end.max(underline_start + 1);
+        out.push(format!(
+            "{:gutter_width$} | {}{}",
+            "",
+            " ".repeat(underline_start),
+            "^".repeat(underline_end - underline_start),
+        ));
+    }
+    out.join("\n")
+}
+
+/// The underlying source of pairs for a [`Nodes`]: either pest's own iterator directly, or an
+/// already-filtered `Vec` after [`Nodes::filter_rule`]/[`Nodes::exclude_rule`] have picked out a
+/// subset. Kept as an enum rather than always collecting into a `Vec`, so the common unfiltered
+/// path stays exactly as cheap as it was before these existed.
+enum NodesIter<'i, R> {
+    All(Pairs<'i, R>),
+    Filtered(std::vec::IntoIter<Pair<'i, R>>),
+}
+
+impl<'i, R: RuleType> NodesIter<'i, R> {
+    fn peek(&self) -> Option<Pair<'i, R>> {
+        match self {
+            NodesIter::All(pairs) => pairs.peek(),
+            NodesIter::Filtered(iter) => iter.as_slice().first().cloned(),
+        }
+    }
+
+    fn clone(&self) -> Self {
+        match self {
+            NodesIter::All(pairs) => NodesIter::All(pairs.clone()),
+            NodesIter::Filtered(iter) => NodesIter::Filtered(iter.as_slice().to_vec().into_iter()),
+        }
+    }
+
+    fn len(&self) -> usize {
+        match self {
+            NodesIter::All(pairs) => pairs.len(),
+            NodesIter::Filtered(iter) => iter.as_slice().len(),
+        }
+    }
+}
+
+impl<'i, R: RuleType> Iterator for NodesIter<'i, R> {
+    type Item = Pair<'i, R>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            NodesIter::All(pairs) => pairs.next(),
+            NodesIter::Filtered(iter) => iter.next(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<'i, R: RuleType> ExactSizeIterator for NodesIter<'i, R> {
+    fn len(&self) -> usize {
+        NodesIter::len(self)
+    }
+}
+
+/// Builds the [`NodesIter`] for a freshly-descended-into sequence of `pairs`, dropping every node
+/// whose rule is in `skip_rules` as set by [`Parser::parse_with_options`]. Stays on the cheap
+/// `NodesIter::All` path when there's nothing to skip, the same as [`Nodes::filter_rule`]/
+/// [`Nodes::exclude_rule`] staying off it only when actually called.
+fn filtered_pairs<'i, R: RuleType>(
+    pairs: Pairs<'i, R>,
+    skip_rules: &Option<Rc<Vec<R>>>,
+) -> NodesIter<'i, R> {
+    match skip_rules {
+        None => NodesIter::All(pairs),
+        Some(skip_rules) => {
+            let kept: Vec<_> = pairs.filter(|pair| !skip_rules.contains(&pair.as_rule())).collect();
+            NodesIter::Filtered(kept.into_iter())
+        }
+    }
+}
+
+/// One link in the parent chain built by [`Parser::parse_parented`], as described in
+/// [`advanced_features::parent_navigation`]. Shared via `Rc` so that descending into a node's
+/// children allocates exactly one new link, rather than cloning the whole chain up to the root.
+enum ParentLink<'i, R> {
+    /// Marks a node with no parent of its own - the root(s) passed to the consuming pass.
+    Root,
+    /// `pair` is some node's parent; `parent` continues the chain up to that parent's own parent.
+    Node {
+        pair: Pair<'i, R>,
+        parent: Rc<ParentLink<'i, R>>,
+    },
+}
+
+/// A type-erased entry point into the grammar's own generated parser, captured by
+/// [`Parser::parse_with_trivia`] so that [`Node::leading_trivia`]/[`Node::trailing_trivia`] can
+/// tokenize a gap of source text against `comment_rule`/`whitespace_rule` without [`Node`] itself
+/// needing to know the concrete type implementing [`Parser`].
+type TriviaParseFn<R> = for<'a> fn(R, &'a str) -> Result<Pairs<'a, R>, pest::error::Error<R>>;
+
+/// Set only by [`Parser::parse_with_trivia`]. See [`advanced_features::comment_trivia`].
+#[derive(Clone, Copy)]
+struct TriviaRules<R> {
+    comment_rule: R,
+    whitespace_rule: R,
+    parse_fn: TriviaParseFn<R>,
+}
+
+/// Which side of a [`Node`] [`Node::trivia_gap`] is reconstructing trivia for.
+enum Trivia {
+    Leading,
+    Trailing,
+}
+
+/// Tokenizes `gap` against `comment_rule`/`whitespace_rule`, preferring a split that accounts for
+/// every byte of `gap` - see [`full_tokenize_trivia`] - and falling back to a simple greedy scan
+/// (stopping at the first byte neither rule can start on) when no such split exists, most commonly
+/// because `gap` contains a literal the grammar matched without giving it a pair of its own.
+fn tokenize_trivia<R: RuleType>(trivia: TriviaRules<R>, gap: &str) -> Vec<&str> {
+    full_tokenize_trivia(trivia, gap).unwrap_or_else(|| greedy_tokenize_trivia(trivia, gap))
+}
+
+/// Tokenizes `gap` against `comment_rule`/`whitespace_rule`, succeeding only if the whole of
+/// `gap` is accounted for. A [`pest::Parser::parse`] of a silent rule never hands back a [`Pair`]
+/// to read a match length off of, and since the match succeeds on a prefix of whatever it's given
+/// (ignoring leftover), re-parsing `whitespace_rule` against longer and longer candidates can never
+/// tell greedy, maximal-munch consumption (the one the grammar's own engine actually performs) apart
+/// from an artificially short one caused by the candidate ending early. Requiring the *entire* gap
+/// to be consumed - trying every length `comment_rule` could plausibly have matched, and recursing
+/// on what's left after each - sidesteps that ambiguity: only the split that corresponds to how the
+/// gap was really produced can possibly account for all of it. `whitespace_rule` is assumed to match
+/// one unit per application (the idiom every pest grammar follows, letting pest's own generated
+/// `skip` repeat it externally instead of looping inside the rule itself - see
+/// `pest_generator::generator::generate_skip`), so only its shortest match is ever tried; trying
+/// every length there too would let a single whitespace application "absorb" unrelated text the same
+/// way an under-constrained `comment_rule` search would.
+///
+/// # Complexity
+///
+/// Naively recursing on every candidate split revisits the same remaining suffix of `gap` through
+/// more than one path - the same segmentation-search blowup as naive word-break - so for a
+/// `comment_rule` that can plausibly end at many positions, the branching recursion is exponential
+/// in the length of `gap`. [`full_tokenize_trivia_memo`] does the same search but caches the result
+/// for each starting offset into `gap` the first time it's reached, so every offset is explored at
+/// most once: `O(n)` distinct offsets, each doing `O(n)` work to try every candidate split, for
+/// `O(n^2)` reparses overall rather than unbounded blowup on an adversarially long gap.
+fn full_tokenize_trivia<R: RuleType>(trivia: TriviaRules<R>, gap: &str) -> Option<Vec<&str>> {
+    full_tokenize_trivia_memo(trivia, gap, 0, &mut HashMap::new())
+}
+
+/// The memoized implementation behind [`full_tokenize_trivia`]: same search, starting from byte
+/// offset `start` into `gap`, but keyed by `start` in `memo` so that a later call reached by a
+/// different split of the gap reuses the earlier result instead of re-exploring the same suffix.
+fn full_tokenize_trivia_memo<'i, R: RuleType>(
+    trivia: TriviaRules<R>,
+    gap: &'i str,
+    start: usize,
+    memo: &mut HashMap<usize, Option<Vec<&'i str>>>,
+) -> Option<Vec<&'i str>> {
+    if let Some(cached) = memo.get(&start) {
+        return cached.clone();
+    }
+    let rest = &gap[start..];
+    let result = (|| {
+        if rest.is_empty() {
+            return Some(Vec::new());
+        }
+        if let Some(len) = shortest_matching_prefix(trivia.parse_fn, trivia.whitespace_rule, rest) {
+            if len > 0 {
+                if let Some(result) = full_tokenize_trivia_memo(trivia, gap, start + len, memo) {
+                    return Some(result);
+                }
+            }
+        }
+        if let Some(min_len) = shortest_matching_prefix(trivia.parse_fn, trivia.comment_rule, rest) {
+            if min_len > 0 {
+                let candidate_ends = rest
+                    .char_indices()
+                    .map(|(i, _)| i)
+                    .chain(std::iter::once(rest.len()))
+                    .filter(|&end| end >= min_len);
+                for end in candidate_ends {
+                    if let Some(mut rest_tokens) = full_tokenize_trivia_memo(trivia, gap, start + end, memo) {
+                        let mut tokens = vec![&rest[..end]];
+                        tokens.append(&mut rest_tokens);
+                        return Some(tokens);
+                    }
+                }
+            }
+        }
+        None
+    })();
+    memo.insert(start, result.clone());
+    result
+}
+
+/// Repeatedly peels `whitespace_rule` then `comment_rule` off the front of `gap`, collecting every
+/// piece of text matched as `comment_rule`, until neither matches. Unlike [`full_tokenize_trivia`],
+/// each match is taken to be exactly [`shortest_matching_prefix`] long, so a `comment_rule` with a
+/// greedy tail (e.g. "rest of the line") only ever recovers its shortest possible match here - this
+/// is only used once [`full_tokenize_trivia`] has already given up on accounting for the whole gap.
+fn greedy_tokenize_trivia<R: RuleType>(trivia: TriviaRules<R>, gap: &str) -> Vec<&str> {
+    let mut comments = Vec::new();
+    let mut rest = gap;
+    loop {
+        if let Some(len) = shortest_matching_prefix(trivia.parse_fn, trivia.whitespace_rule, rest) {
+            if len > 0 {
+                rest = &rest[len..];
+                continue;
+            }
+        }
+        if let Some(len) = shortest_matching_prefix(trivia.parse_fn, trivia.comment_rule, rest) {
+            if len > 0 {
+                comments.push(&rest[..len]);
+                rest = &rest[len..];
+                continue;
+            }
+        }
+        break;
+    }
+    comments
+}
+
+/// The length of the shortest prefix of `text` that `rule` matches on its own, or `None` if no
+/// prefix does. `comment_rule`/`whitespace_rule` are declared `_{ ... }` (silent) in every grammar
+/// that needs this feature, so a standalone [`pest::Parser::parse`] of either never hands back a
+/// [`Pair`] to read a match length off of - re-parsing an increasingly long prefix is the only way
+/// left to find where the match actually ends.
+fn shortest_matching_prefix<R: RuleType>(parse_fn: TriviaParseFn<R>, rule: R, text: &str) -> Option<usize> {
+    text.char_indices()
+        .map(|(i, _)| i)
+        .chain(std::iter::once(text.len()))
+        .find(|&end| parse_fn(rule, &text[..end]).is_ok())
+}
+
+/// Per-parse options for [`Parser::parse_with_options`], as described in
+/// [`advanced_features::node_filtering`]. Unlike silencing a rule in the grammar itself (`_{ ... }`),
+/// these only affect how this one parse's [`Nodes`] sequences are iterated - pest's own [`Pairs`]
+/// tree, and anything reading it directly, is unaffected.
+pub struct ParseOptions<R> {
+    skip_rules: Vec<R>,
+}
+
+impl<R: RuleType> ParseOptions<R> {
+    /// No rules skipped - equivalent to plain [`Parser::parse`] until [`ParseOptions::skip_rule`]
+    /// is called.
+    pub fn new() -> Self {
+        ParseOptions {
+            skip_rules: Vec::new(),
+        }
+    }
+
+    /// Never surface a node of `rule` while iterating a [`Nodes`] sequence's children, at any
+    /// depth of descent - as if `rule` were silenced (`_`) in the grammar, but only for this
+    /// parse. Can be called more than once to skip several rules.
+    pub fn skip_rule(mut self, rule: R) -> Self {
+        self.skip_rules.push(rule);
+        self
+    }
+}
+
+impl<R: RuleType> Default for ParseOptions<R> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Resource limits for [`Parser::parse_with_limits`], as described in
+/// [`advanced_features::parse_limits`]. Every limit defaults to unbounded - the behavior of
+/// every other entry point - until set.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ParseLimits {
+    max_input_bytes: Option<usize>,
+    max_depth: Option<usize>,
+    max_nodes: Option<usize>,
+}
+
+impl ParseLimits {
+    /// No limits set - equivalent to plain [`Parser::parse`] until at least one of
+    /// [`ParseLimits::max_input_bytes`], [`ParseLimits::max_depth`], or
+    /// [`ParseLimits::max_nodes`] is called.
+    pub fn new() -> Self {
+        ParseLimits {
+            max_input_bytes: None,
+            max_depth: None,
+            max_nodes: None,
+        }
+    }
+
+    /// Rejects the input outright, before pest even runs, once it's longer than `max_input_bytes`
+    /// bytes - the one check here that happens ahead of parsing, rather than during the consuming
+    /// pass.
+    pub fn max_input_bytes(mut self, max_input_bytes: usize) -> Self {
+        self.max_input_bytes = Some(max_input_bytes);
+        self
+    }
+
+    /// Bounds how many [`Node::into_children`]/[`Node::children_ref`] calls deep the consuming
+    /// pass may go, the same as [`Parser::parse_with_depth_limit`]. See
+    /// [`advanced_features::recursion_limit`].
+    pub fn max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = Some(max_depth);
+        self
+    }
+
+    /// Bounds how many nodes [`Nodes::next_node`] may produce across the whole consuming pass,
+    /// via [`Nodes::check_node_budget`].
+    pub fn max_nodes(mut self, max_nodes: usize) -> Self {
+        self.max_nodes = Some(max_nodes);
+        self
+    }
+}
+
+/// A sequence of sibling nodes - typically the children of some [`Node`], or the top-level pairs
+/// produced by a parse.
+pub struct Nodes<'i, R, D = (), Ctx = ()> {
+    pairs: NodesIter<'i, R>,
+    user_data: D,
+    context: *mut Ctx,
+    /// Shared by every `Node`/`Nodes` derived from the same context, so that two of them can
+    /// never produce aliasing `context`/`context_mut` borrows even though `context` itself is
+    /// freely copied between them. See [`Node::context_mut`].
+    context_lock: Rc<RefCell<()>>,
+    errors: Option<*mut Vec<Error<R>>>,
+    /// Set only by [`Parser::parse_collecting_warnings`], and shared by every [`Node`]/[`Nodes`]
+    /// descended from the same call. See [`Node::warn`].
+    warnings: Option<*mut Vec<Error<R>>>,
+    /// Span to point errors at when there is no specific offending node to blame (e.g. the
+    /// sequence is empty, or shorter than expected).
+    parent_span: pest::Span<'i>,
+    /// The [`ParentLink`] every [`Node`] produced from this sequence should carry as its own
+    /// `parent_link`. `None` outside of [`Parser::parse_parented`].
+    parent_link: Option<Rc<ParentLink<'i, R>>>,
+    /// See [`Node::depth`].
+    depth: usize,
+    /// Set only by [`Parser::parse_with_depth_limit`]/[`Parser::parse_with_limits`], and shared by
+    /// every [`Node`]/[`Nodes`] descended from the same call.
+    max_depth: Option<usize>,
+    /// Set only by [`Parser::parse_with_limits`], and shared by every [`Node`]/[`Nodes`] descended
+    /// from the same call. See [`Nodes::check_node_budget`].
+    max_nodes: Option<usize>,
+    /// How many nodes [`Nodes::next_node`] has produced so far across the whole consuming pass -
+    /// shared by every [`Node`]/[`Nodes`] descended from the same [`Parser::parse_with_limits`]
+    /// call, `None` for any other entry point, which track no budget at all.
+    node_count: Option<Rc<Cell<usize>>>,
+    /// Set only by [`Parser::parse_with_options`], and shared by every [`Node`]/[`Nodes`]
+    /// descended from the same call. Rules listed here are filtered out of `pairs` as soon as a
+    /// child sequence is built, at any depth of descent. See
+    /// [`advanced_features::node_filtering`].
+    skip_rules: Option<Rc<Vec<R>>>,
+    /// The [`Node::sibling_index`] to stamp on the next node [`Nodes::next_node`]/[`Nodes::peek`]
+    /// hands out, counting up from `0`.
+    next_sibling_index: usize,
+    /// Set only by [`Parser::parse_with_cancel`], and shared by every [`Node`]/[`Nodes`] descended
+    /// from the same call. See [`Nodes::check_cancelled`].
+    cancel_token: Option<&'i AtomicBool>,
+    /// Set only by [`Parser::parse_with_coverage`], and shared by every [`Node`]/[`Nodes`]
+    /// descended from the same call. See [`advanced_features::grammar_coverage`].
+    coverage: Option<*mut HashSet<R>>,
+    /// Set only by [`Parser::parse_named`], and shared by every [`Node`]/[`Nodes`] descended from
+    /// the same call. See [`Node::error`].
+    path: Option<Rc<str>>,
+    /// Set only by [`Parser::parse_with_trivia`], and shared by every [`Node`]/[`Nodes`] descended
+    /// from the same call. See [`Node::leading_trivia`]/[`Node::trailing_trivia`].
+    trivia: Option<TriviaRules<R>>,
+    /// A clone of `pairs` taken before anything was ever consumed from this particular view -
+    /// i.e. as of the last time it was built fresh, whether that's this sequence's original
+    /// construction or a later [`Nodes::filter_rule`]/[`Nodes::exclude_rule`]/
+    /// [`Nodes::split_at_rule`] replacing it with a new one. See [`Nodes::clone_reset`].
+    original: NodesIter<'i, R>,
+}
+
+impl<'i, R: RuleType, D: Clone> Node<'i, R, D> {
+    /// Build a fresh [`Node`] directly from pest's own [`Pair`], carrying the given user data - the
+    /// other direction of [`Node::into_pair`], for re-entering pest_consume after using a raw pest
+    /// feature this crate doesn't wrap. The result has no [`context`](Node::context) beyond `&()`,
+    /// no [`parent`](Node::parent), and doesn't record into [`Parser::parse_collecting_errors`]'s
+    /// error buffer even if the original node did.
+    pub fn new(pair: Pair<'i, R>, user_data: D) -> Self {
+        Node {
+            pair,
+            user_data,
+            context: std::ptr::NonNull::dangling().as_ptr(),
+            context_lock: Rc::new(RefCell::new(())),
+            errors: None,
+            warnings: None,
+            parent_link: None,
+            depth: 0,
+            max_depth: None,
+            max_nodes: None,
+            node_count: None,
+            skip_rules: None,
+            sibling_index: None,
+            cancel_token: None,
+            coverage: None,
+            path: None,
+            trivia: None,
+        }
+    }
+}
+
+impl<'i, R: RuleType, D: Clone> Nodes<'i, R, D> {
+    /// Build a fresh [`Nodes`] directly from pest's own [`Pairs`], carrying the given user data.
+    /// `input_str` is used to build errors that don't point at any specific node.
+    pub fn new(pairs: Pairs<'i, R>, user_data: D, input_str: &'i str) -> Self {
+        Nodes {
+            pairs: NodesIter::All(pairs.clone()),
+            original: NodesIter::All(pairs),
+            user_data,
+            context: std::ptr::NonNull::dangling().as_ptr(),
+            context_lock: Rc::new(RefCell::new(())),
+            errors: None,
+            warnings: None,
+            parent_span: pest::Span::new(input_str, 0, input_str.len())
+                .unwrap_or_else(|| pest::Span::new(input_str, 0, 0).unwrap()),
+            parent_link: None,
+            depth: 0,
+            max_depth: None,
+            max_nodes: None,
+            node_count: None,
+            skip_rules: None,
+            next_sibling_index: 0,
+            cancel_token: None,
+            coverage: None,
+            path: None,
+            trivia: None,
+        }
+    }
+}
+
+impl<'i, R: RuleType> Nodes<'i, R> {
+    /// Build a fresh [`Nodes`] for [`Parser::parse_collecting_errors`], with no user data, that
+    /// records into `errors` whenever a consuming method calls [`Node::emit_error`]. `input_str`
+    /// is used to build errors that don't point at any specific node.
+    pub fn new_collecting_errors(
+        pairs: Pairs<'i, R>,
+        input_str: &'i str,
+        errors: &'i mut Vec<Error<R>>,
+    ) -> Self {
+        Nodes {
+            pairs: NodesIter::All(pairs.clone()),
+            original: NodesIter::All(pairs),
+            user_data: (),
+            context: std::ptr::NonNull::dangling().as_ptr(),
+            context_lock: Rc::new(RefCell::new(())),
+            errors: Some(errors as *mut Vec<Error<R>>),
+            warnings: None,
+            parent_span: pest::Span::new(input_str, 0, input_str.len())
+                .unwrap_or_else(|| pest::Span::new(input_str, 0, 0).unwrap()),
+            parent_link: None,
+            depth: 0,
+            max_depth: None,
+            max_nodes: None,
+            node_count: None,
+            skip_rules: None,
+            next_sibling_index: 0,
+            cancel_token: None,
+            coverage: None,
+            path: None,
+            trivia: None,
+        }
+    }
+
+    /// Build a fresh [`Nodes`] for [`Parser::parse_collecting_warnings`], with no user data, that
+    /// records into `warnings` whenever a consuming method calls [`Node::warn`]. `input_str` is
+    /// used to build errors that don't point at any specific node.
+    pub fn new_collecting_warnings(
+        pairs: Pairs<'i, R>,
+        input_str: &'i str,
+        warnings: &'i mut Vec<Error<R>>,
+    ) -> Self {
+        Nodes {
+            pairs: NodesIter::All(pairs.clone()),
+            original: NodesIter::All(pairs),
+            user_data: (),
+            context: std::ptr::NonNull::dangling().as_ptr(),
+            context_lock: Rc::new(RefCell::new(())),
+            errors: None,
+            warnings: Some(warnings as *mut Vec<Error<R>>),
+            parent_span: pest::Span::new(input_str, 0, input_str.len())
+                .unwrap_or_else(|| pest::Span::new(input_str, 0, 0).unwrap()),
+            parent_link: None,
+            depth: 0,
+            max_depth: None,
+            max_nodes: None,
+            node_count: None,
+            skip_rules: None,
+            next_sibling_index: 0,
+            cancel_token: None,
+            coverage: None,
+            path: None,
+            trivia: None,
+        }
+    }
+
+    /// Build a fresh [`Nodes`] for [`Parser::parse_parented`], with no user data, where every
+    /// [`Node`] produced while descending into it carries a [`Node::parent`] link back up the
+    /// tree. `input_str` is used to build errors that don't point at any specific node.
+    pub fn new_parented(pairs: Pairs<'i, R>, input_str: &'i str) -> Self {
+        Nodes {
+            pairs: NodesIter::All(pairs.clone()),
+            original: NodesIter::All(pairs),
+            user_data: (),
+            context: std::ptr::NonNull::dangling().as_ptr(),
+            context_lock: Rc::new(RefCell::new(())),
+            errors: None,
+            warnings: None,
+            parent_span: pest::Span::new(input_str, 0, input_str.len())
+                .unwrap_or_else(|| pest::Span::new(input_str, 0, 0).unwrap()),
+            parent_link: Some(Rc::new(ParentLink::Root)),
+            depth: 0,
+            max_depth: None,
+            max_nodes: None,
+            node_count: None,
+            skip_rules: None,
+            next_sibling_index: 0,
+            cancel_token: None,
+            coverage: None,
+            path: None,
+            trivia: None,
+        }
+    }
+
+    /// Build a fresh [`Nodes`] for [`Parser::parse_with_depth_limit`], with no user data, where
+    /// [`match_nodes!`] refuses to dispatch into an arm once [`Node::depth`] would exceed
+    /// `max_depth`, returning a clean `Err` instead of letting the consuming pass's own recursion
+    /// overflow the stack. `input_str` is used to build errors that don't point at any specific
+    /// node. See [`advanced_features::recursion_limit`].
+    pub fn new_with_depth_limit(pairs: Pairs<'i, R>, input_str: &'i str, max_depth: usize) -> Self {
+        Nodes {
+            pairs: NodesIter::All(pairs.clone()),
+            original: NodesIter::All(pairs),
+            user_data: (),
+            context: std::ptr::NonNull::dangling().as_ptr(),
+            context_lock: Rc::new(RefCell::new(())),
+            errors: None,
+            warnings: None,
+            parent_span: pest::Span::new(input_str, 0, input_str.len())
+                .unwrap_or_else(|| pest::Span::new(input_str, 0, 0).unwrap()),
+            parent_link: None,
+            depth: 0,
+            max_depth: Some(max_depth),
+            max_nodes: None,
+            node_count: None,
+            skip_rules: None,
+            next_sibling_index: 0,
+            cancel_token: None,
+            coverage: None,
+            path: None,
+            trivia: None,
+        }
+    }
+
+    /// Build a fresh [`Nodes`] for [`Parser::parse_with_cancel`], with no user data, where
+    /// [`match_nodes!`] refuses to dispatch into an arm once `cancel_token` has been set,
+    /// returning a clean `Err` instead of letting the consuming pass run any further. `input_str`
+    /// is used to build errors that don't point at any specific node. See
+    /// [`advanced_features::cancellation`].
+    pub fn new_with_cancel_token(
+        pairs: Pairs<'i, R>,
+        input_str: &'i str,
+        cancel_token: &'i AtomicBool,
+    ) -> Self {
+        Nodes {
+            pairs: NodesIter::All(pairs.clone()),
+            original: NodesIter::All(pairs),
+            user_data: (),
+            context: std::ptr::NonNull::dangling().as_ptr(),
+            context_lock: Rc::new(RefCell::new(())),
+            errors: None,
+            warnings: None,
+            parent_span: pest::Span::new(input_str, 0, input_str.len())
+                .unwrap_or_else(|| pest::Span::new(input_str, 0, 0).unwrap()),
+            parent_link: None,
+            depth: 0,
+            max_depth: None,
+            max_nodes: None,
+            node_count: None,
+            skip_rules: None,
+            next_sibling_index: 0,
+            cancel_token: Some(cancel_token),
+            coverage: None,
+            path: None,
+            trivia: None,
+        }
+    }
+
+    /// Build a fresh [`Nodes`] for [`Parser::parse_with_coverage`], with no user data, recording
+    /// the [`Rule`](pest::RuleType) of every node consumed - via [`Nodes::next_node`] or
+    /// [`match_nodes!`], which is built on top of it - into `coverage` as it's visited. `input_str`
+    /// is used to build errors that don't point at any specific node. See
+    /// [`advanced_features::grammar_coverage`].
+    pub fn new_with_coverage(pairs: Pairs<'i, R>, input_str: &'i str, coverage: &'i mut HashSet<R>) -> Self {
+        Nodes {
+            pairs: NodesIter::All(pairs.clone()),
+            original: NodesIter::All(pairs),
+            user_data: (),
+            context: std::ptr::NonNull::dangling().as_ptr(),
+            context_lock: Rc::new(RefCell::new(())),
+            errors: None,
+            warnings: None,
+            parent_span: pest::Span::new(input_str, 0, input_str.len())
+                .unwrap_or_else(|| pest::Span::new(input_str, 0, 0).unwrap()),
+            parent_link: None,
+            depth: 0,
+            max_depth: None,
+            max_nodes: None,
+            node_count: None,
+            skip_rules: None,
+            next_sibling_index: 0,
+            cancel_token: None,
+            coverage: Some(coverage as *mut HashSet<R>),
+            path: None,
+            trivia: None,
+        }
+    }
+
+    /// Build a fresh [`Nodes`] for [`Parser::parse_named`], with no user data, attaching `path` to
+    /// every [`Error`] built from a [`Node`]/[`Nodes`] descended from it, so its `Display` names the
+    /// source it came from. `input_str` is used to build errors that don't point at any specific
+    /// node. See [`advanced_features::named_sources`].
+    pub fn new_with_path(pairs: Pairs<'i, R>, input_str: &'i str, path: &str) -> Self {
+        Nodes {
+            pairs: NodesIter::All(pairs.clone()),
+            original: NodesIter::All(pairs),
+            user_data: (),
+            context: std::ptr::NonNull::dangling().as_ptr(),
+            context_lock: Rc::new(RefCell::new(())),
+            errors: None,
+            warnings: None,
+            parent_span: pest::Span::new(input_str, 0, input_str.len())
+                .unwrap_or_else(|| pest::Span::new(input_str, 0, 0).unwrap()),
+            parent_link: None,
+            depth: 0,
+            max_depth: None,
+            max_nodes: None,
+            node_count: None,
+            skip_rules: None,
+            next_sibling_index: 0,
+            cancel_token: None,
+            coverage: None,
+            path: Some(Rc::from(path)),
+            trivia: None,
+        }
+    }
+
+    /// Build a fresh [`Nodes`] for [`Parser::parse_with_trivia`], with no user data, where every
+    /// [`Node`] produced while descending into it carries both a [`Node::parent`] link - needed to
+    /// find its siblings' spans - and `comment_rule`/`whitespace_rule`, so
+    /// [`Node::leading_trivia`]/[`Node::trailing_trivia`] can recover the source text surrounding
+    /// it. `input_str` is used to build errors that don't point at any specific node. See
+    /// [`advanced_features::comment_trivia`].
+    pub fn new_with_trivia(
+        pairs: Pairs<'i, R>,
+        input_str: &'i str,
+        comment_rule: R,
+        whitespace_rule: R,
+        parse_fn: TriviaParseFn<R>,
+    ) -> Self {
+        Nodes {
+            pairs: NodesIter::All(pairs.clone()),
+            original: NodesIter::All(pairs),
+            user_data: (),
+            context: std::ptr::NonNull::dangling().as_ptr(),
+            context_lock: Rc::new(RefCell::new(())),
+            errors: None,
+            warnings: None,
+            parent_span: pest::Span::new(input_str, 0, input_str.len())
+                .unwrap_or_else(|| pest::Span::new(input_str, 0, 0).unwrap()),
+            parent_link: Some(Rc::new(ParentLink::Root)),
+            depth: 0,
+            max_depth: None,
+            max_nodes: None,
+            node_count: None,
+            skip_rules: None,
+            next_sibling_index: 0,
+            cancel_token: None,
+            coverage: None,
+            path: None,
+            trivia: Some(TriviaRules { comment_rule, whitespace_rule, parse_fn }),
+        }
+    }
+
+    /// Build a fresh [`Nodes`] for [`Parser::parse_with_options`], with no user data, where every
+    /// rule listed in `options` is dropped from `pairs` - and from every child sequence built by
+    /// descending further, at any depth - before [`match_nodes!`] or manual iteration ever sees
+    /// it. `input_str` is used to build errors that don't point at any specific node. See
+    /// [`advanced_features::node_filtering`].
+    pub fn new_with_options(pairs: Pairs<'i, R>, input_str: &'i str, options: ParseOptions<R>) -> Self {
+        let skip_rules = if options.skip_rules.is_empty() {
+            None
+        } else {
+            Some(Rc::new(options.skip_rules))
+        };
+        Nodes {
+            pairs: filtered_pairs(pairs.clone(), &skip_rules),
+            original: filtered_pairs(pairs, &skip_rules),
+            user_data: (),
+            context: std::ptr::NonNull::dangling().as_ptr(),
+            context_lock: Rc::new(RefCell::new(())),
+            errors: None,
+            warnings: None,
+            parent_span: pest::Span::new(input_str, 0, input_str.len())
+                .unwrap_or_else(|| pest::Span::new(input_str, 0, 0).unwrap()),
+            parent_link: None,
+            depth: 0,
+            max_depth: None,
+            max_nodes: None,
+            node_count: None,
+            skip_rules,
+            next_sibling_index: 0,
+            cancel_token: None,
+            coverage: None,
+            path: None,
+            trivia: None,
+        }
+    }
+
+    /// Build a fresh [`Nodes`] for [`Parser::parse_with_limits`], with no user data, enforcing
+    /// whichever of `limits`'s [`max_depth`](ParseLimits::max_depth)/
+    /// [`max_nodes`](ParseLimits::max_nodes) are set the same way
+    /// [`new_with_depth_limit`](Self::new_with_depth_limit) enforces a depth limit alone -
+    /// [`match_nodes!`] checks both before trying any arm, returning a clean `Err` instead of
+    /// letting the consuming pass run past either budget. `limits`'s
+    /// [`max_input_bytes`](ParseLimits::max_input_bytes) has nothing to do here: it's checked
+    /// against `input_str` by [`Parser::parse_with_limits`] itself, before pest ever runs, so
+    /// there's no node-budget bookkeeping for it to carry forward. `input_str` is used to build
+    /// errors that don't point at any specific node. See [`advanced_features::parse_limits`].
+    pub fn new_with_limits(pairs: Pairs<'i, R>, input_str: &'i str, limits: ParseLimits) -> Self {
+        Nodes {
+            pairs: NodesIter::All(pairs.clone()),
+            original: NodesIter::All(pairs),
+            user_data: (),
+            context: std::ptr::NonNull::dangling().as_ptr(),
+            context_lock: Rc::new(RefCell::new(())),
+            errors: None,
+            warnings: None,
+            parent_span: pest::Span::new(input_str, 0, input_str.len())
+                .unwrap_or_else(|| pest::Span::new(input_str, 0, 0).unwrap()),
+            parent_link: None,
+            depth: 0,
+            max_depth: limits.max_depth,
+            max_nodes: limits.max_nodes,
+            node_count: limits.max_nodes.map(|_| Rc::new(Cell::new(0))),
+            skip_rules: None,
+            next_sibling_index: 0,
+            cancel_token: None,
+            coverage: None,
+            path: None,
+            trivia: None,
+        }
+    }
+}
+
+impl<'i, R: RuleType, D: Clone, Ctx> Nodes<'i, R, D, Ctx> {
+    /// Build a fresh [`Nodes`] directly from pest's own [`Pairs`], carrying the given user data
+    /// and a mutable borrow of `context` threaded through the whole pass, as described in
+    /// [`advanced_features::context`]. `input_str` is used to build errors that don't point at any
+    /// specific node.
+    pub fn new_with_context(
+        pairs: Pairs<'i, R>,
+        user_data: D,
+        input_str: &'i str,
+        context: &'i mut Ctx,
+    ) -> Self {
+        Nodes {
+            pairs: NodesIter::All(pairs.clone()),
+            original: NodesIter::All(pairs),
+            user_data,
+            context: context as *mut Ctx,
+            context_lock: Rc::new(RefCell::new(())),
+            errors: None,
+            warnings: None,
+            parent_span: pest::Span::new(input_str, 0, input_str.len())
+                .unwrap_or_else(|| pest::Span::new(input_str, 0, 0).unwrap()),
+            parent_link: None,
+            depth: 0,
+            max_depth: None,
+            max_nodes: None,
+            node_count: None,
+            skip_rules: None,
+            next_sibling_index: 0,
+            cancel_token: None,
+            coverage: None,
+            path: None,
+            trivia: None,
+        }
+    }
+
+    /// A clone of the next node, without consuming it. Mirrors [`std::iter::Peekable`], for
+    /// hand-rolled iteration over a [`Nodes`] sequence that needs lookahead to decide how to
+    /// proceed - a context-sensitive construct `match_nodes!` can't express - while keeping the
+    /// user data and lifetime attached like [`Nodes::next_node`] does.
+    pub fn peek(&self) -> Option<Node<'i, R, D, Ctx>> {
+        let pair = self.pairs.peek()?;
+        Some(self.clone_node_for(pair, self.next_sibling_index))
+    }
+
+    /// A clone of the `n`th node from here (0-indexed), without consuming it or any node before
+    /// it. Like [`Nodes::peek`], but for random access further into the sequence - e.g. a test
+    /// that asserts something about the third top-level item without first consuming the other
+    /// two. `O(n)`, same as `Iterator::nth` on any other sequence without direct indexing.
+    pub fn nth(&self, n: usize) -> Option<Node<'i, R, D, Ctx>> {
+        let pair = self.pairs.clone().nth(n)?;
+        Some(self.clone_node_for(pair, self.next_sibling_index + n))
+    }
+
+    /// A clone of the last remaining node, without consuming anything. `None` if this sequence is
+    /// empty. Not named `last`: `Nodes` already implements [`Iterator`], whose own `last` takes
+    /// `self` by value and consumes the whole sequence to get there, and a by-value method always
+    /// wins method resolution over a by-reference one of the same name - an inherent `last(&self)`
+    /// here would just be shadowed, silently turning every call site into a full consume. `peek_last`
+    /// mirrors [`Nodes::peek`]'s naming instead of colliding.
+    pub fn peek_last(&self) -> Option<Node<'i, R, D, Ctx>> {
+        let len = self.len();
+        self.nth(len.checked_sub(1)?)
+    }
+
+    /// Builds a [`Node`] sharing this sequence's state, for a `pair` at `sibling_index` within it
+    /// - the common tail of [`Nodes::peek`], [`Nodes::nth`], and [`Nodes::last`].
+    fn clone_node_for(&self, pair: Pair<'i, R>, sibling_index: usize) -> Node<'i, R, D, Ctx> {
+        Node {
+            pair,
+            user_data: self.user_data.clone(),
+            context: self.context,
+            context_lock: Rc::clone(&self.context_lock),
+            errors: self.errors,
+            warnings: self.warnings,
+            parent_link: self.parent_link.clone(),
+            depth: self.depth,
+            max_depth: self.max_depth,
+            max_nodes: self.max_nodes,
+            node_count: self.node_count.clone(),
+            skip_rules: self.skip_rules.clone(),
+            sibling_index: Some(sibling_index),
+            cancel_token: self.cancel_token,
+            coverage: self.coverage,
+            path: self.path.clone(),
+            trivia: self.trivia,
+        }
+    }
+
+    /// The rule of the next node, without consuming it. A convenience over [`Nodes::peek`] for
+    /// when only the rule is needed.
+    pub fn peek_rule(&self) -> Option<R> {
+        self.pairs.peek().map(|p| p.as_rule())
+    }
+
+    /// The rules of the next (up to) `n` nodes, without consuming anything. Used by
+    /// [`match_nodes!`] to check a candidate arm's shape before committing to it.
+    pub fn peek_rules(&self, n: usize) -> Vec<R> {
+        self.pairs.clone().take(n).map(|p| p.as_rule()).collect()
+    }
+
+    /// The rules of every node left, in order, without consuming anything. Used by
+    /// [`match_nodes!`]'s fallthrough error to report the actual shape no arm handled; also handy
+    /// to `eprintln!()` by hand while figuring out why an arm didn't match.
+    pub fn rules(&self) -> Vec<R> {
+        self.pairs.clone().map(|p| p.as_rule()).collect()
+    }
+
+    /// The number of nodes left. See also [`Nodes::is_empty`], and the [`ExactSizeIterator`] impl
+    /// for using this sequence as a plain iterator.
+    pub fn len(&self) -> usize {
+        self.pairs.len()
+    }
+
+    /// Whether there are no nodes left.
+    pub fn is_empty(&self) -> bool {
+        self.pairs.peek().is_none()
+    }
+
+    /// How many [`Node::into_children`]/[`Node::children_ref`] calls deep the nodes in this
+    /// sequence are, relative to the roots passed to [`Parser::parse_with_depth_limit`] - or always
+    /// `0` for any other entry point, which track no depth at all. See
+    /// [`advanced_features::recursion_limit`].
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Checks this sequence's [`depth`](Nodes::depth) against the limit set by
+    /// [`Parser::parse_with_depth_limit`], if any, returning a clean [`Error`] instead of letting
+    /// the consuming pass's own recursion run any deeper. [`match_nodes!`] calls this itself before
+    /// trying any arm; call it directly only when dispatching by hand instead of through
+    /// `match_nodes!`. Always `Ok` outside of `parse_with_depth_limit`. See
+    /// [`advanced_features::recursion_limit`].
+    pub fn check_depth_limit(&self) -> Result<(), Error<R>> {
+        match self.max_depth {
+            Some(max_depth) if self.depth > max_depth => {
+                Err(self.error(format!("recursion depth limit of {max_depth} exceeded")))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Checks whether [`Parser::parse_with_cancel`]'s cancellation token has been set, returning
+    /// a clean [`Error`] instead of letting the consuming pass run any further over cancelled
+    /// work. [`match_nodes!`] calls this itself before trying any arm, the same way it calls
+    /// [`Nodes::check_depth_limit`]; call it directly only when dispatching by hand instead of
+    /// through `match_nodes!`. Always `Ok` outside of `parse_with_cancel`. See
+    /// [`advanced_features::cancellation`].
+    pub fn check_cancelled(&self) -> Result<(), Error<R>> {
+        match self.cancel_token {
+            Some(token) if token.load(Ordering::Relaxed) => Err(self.error("parse cancelled")),
+            _ => Ok(()),
+        }
+    }
+
+    /// Checks the total number of nodes [`Nodes::next_node`] has produced so far, across the
+    /// whole consuming pass, against the budget set by [`Parser::parse_with_limits`], if any,
+    /// returning a clean [`Error`] instead of letting the pass keep visiting more nodes than that
+    /// budget allows. [`match_nodes!`] calls this itself before trying any arm, the same way it
+    /// calls [`Nodes::check_depth_limit`]; call it directly only when dispatching by hand instead
+    /// of through `match_nodes!`. Always `Ok` outside of `parse_with_limits`. See
+    /// [`advanced_features::parse_limits`].
+    pub fn check_node_budget(&self) -> Result<(), Error<R>> {
+        match (self.max_nodes, &self.node_count) {
+            (Some(max_nodes), Some(node_count)) if node_count.get() > max_nodes => {
+                Err(self.error(format!("node budget of {max_nodes} exceeded")))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Keep only the remaining nodes matching `rule`, preserving order. Useful when a rule
+    /// interleaves meaningful children with ones that can't be made silent in the grammar (e.g. a
+    /// `comment` rule that can appear anywhere) - filter them out before handing the sequence to
+    /// [`match_nodes!`], which sees only the filtered nodes and so doesn't need to account for
+    /// them. See also [`Nodes::exclude_rule`] for the opposite.
+    pub fn filter_rule(mut self, rule: R) -> Self {
+        let kept: Vec<_> = self.pairs.filter(|pair| pair.as_rule() == rule).collect();
+        self.original = NodesIter::Filtered(kept.clone().into_iter());
+        self.pairs = NodesIter::Filtered(kept.into_iter());
+        self
+    }
+
+    /// Drop the remaining nodes matching `rule`, preserving order of the rest. The inverse of
+    /// [`Nodes::filter_rule`].
+    pub fn exclude_rule(mut self, rule: R) -> Self {
+        let kept: Vec<_> = self.pairs.filter(|pair| pair.as_rule() != rule).collect();
+        self.original = NodesIter::Filtered(kept.clone().into_iter());
+        self.pairs = NodesIter::Filtered(kept.into_iter());
+        self
+    }
+
+    /// Split the remaining nodes into groups at each occurrence of `rule`, preserving order both
+    /// within and across groups. Every marker node is dropped rather than starting the group after
+    /// it, so a marker-separated sequence of `n` markers always splits into `n + 1` groups (some
+    /// possibly empty, e.g. two adjacent markers or one at either end). Unlike
+    /// [`Nodes::filter_rule`], which discards the distinction between sections entirely, this keeps
+    /// each section as its own [`Nodes`] so a different consuming method can process each one. See
+    /// [`advanced_features::node_grouping`].
+    pub fn split_at_rule(mut self, rule: R) -> Vec<Self> {
+        let mut groups = Vec::new();
+        let mut current = Vec::new();
+        for pair in self.pairs.by_ref() {
+            if pair.as_rule() == rule {
+                groups.push(std::mem::take(&mut current));
+            } else {
+                current.push(pair);
+            }
+        }
+        groups.push(current);
+        groups
+            .into_iter()
+            .map(|group| {
+                let mut fork = self.fork();
+                fork.original = NodesIter::Filtered(group.clone().into_iter());
+                fork.pairs = NodesIter::Filtered(group.into_iter());
+                fork
+            })
+            .collect()
+    }
+
+    /// An independent copy of this sequence, sharing the same remaining nodes and context.
+    /// Consuming the fork doesn't advance `self`, and vice versa. Used by [`match_nodes!`] to try
+    /// a guarded arm's body without committing to it: if the guard rejects the arm, `self` is
+    /// left untouched for the next arm to try from the same position. The same trick works for
+    /// hand-rolled speculative parsing outside `match_nodes!`: fork before a tentative
+    /// [`Nodes::next_node`]/[`Nodes::peek`]-driven lookahead, and either replace `self` with the
+    /// fork once it pans out, or drop the fork and keep consuming `self` from where it was -
+    /// there's no separate checkpoint/restore pair, since a fork already is the checkpoint.
+    pub fn fork(&self) -> Self {
+        Nodes {
+            pairs: self.pairs.clone(),
+            original: self.original.clone(),
+            user_data: self.user_data.clone(),
+            context: self.context,
+            context_lock: Rc::clone(&self.context_lock),
+            errors: self.errors,
+            warnings: self.warnings,
+            parent_span: self.parent_span,
+            parent_link: self.parent_link.clone(),
+            depth: self.depth,
+            max_depth: self.max_depth,
+            max_nodes: self.max_nodes,
+            node_count: self.node_count.clone(),
+            skip_rules: self.skip_rules.clone(),
+            next_sibling_index: self.next_sibling_index,
+            cancel_token: self.cancel_token,
+            coverage: self.coverage,
+            path: self.path.clone(),
+            trivia: self.trivia,
+        }
+    }
+
+    /// An independent copy of this sequence, reset to the start of whatever nodes it currently
+    /// holds - unlike [`Nodes::fork`], which picks up from wherever `self` currently is, the
+    /// returned copy sees every node again from the first one, even if `self` has already
+    /// consumed some of them. Cheap: the pairs underneath are `Rc`-shared tokens plus an index
+    /// (see [`advanced_features::node_cloning`]), so resetting is just handing back an earlier
+    /// index rather than re-walking anything. Meant for "try one consuming strategy, and on a
+    /// recoverable error fall back to another over the same children" - unlike guarded
+    /// [`match_nodes!`] arms, which only back off on a *rejected* guard, this also covers the case
+    /// where the arm's own body is what fails.
+    pub fn clone_reset(&self) -> Self {
+        let mut reset = self.fork();
+        reset.pairs = self.original.clone();
+        reset.next_sibling_index = 0;
+        reset
+    }
+
+    /// Used by [`match_nodes!`] to check and consume an arm that may contain optional (`?`)
+    /// slots. `pattern` lists, for each slot in order, the expected rule and whether it's
+    /// optional. Matching is greedy: a slot whose rule is present is always taken, even if it's
+    /// optional.
+    ///
+    /// Returns `None` without consuming anything if the whole sequence doesn't fit this shape -
+    /// i.e. some required slot's rule isn't next, or nodes are left over once every slot has been
+    /// tried. Otherwise consumes exactly the matched sequence and returns one entry per slot:
+    /// `Some(node)` where the slot's rule was present, `None` where an optional slot was skipped.
+    pub fn match_optional_seq(
+        &mut self,
+        pattern: &[(R, bool)],
+    ) -> Option<Vec<Option<Node<'i, R, D, Ctx>>>> {
+        let actual = self.peek_rules(pattern.len() + 1);
+        let mut next = 0;
+        let mut present = Vec::with_capacity(pattern.len());
+        for &(rule, optional) in pattern {
+            if actual.get(next) == Some(&rule) {
+                present.push(true);
+                next += 1;
+            } else if optional {
+                present.push(false);
+            } else {
+                return None;
+            }
+        }
+        if next != actual.len() {
+            // Either a required slot was missing, or nodes are left over past the last slot.
+            return None;
+        }
+        Some(
+            present
+                .into_iter()
+                .map(|was_present| was_present.then(|| self.next_node().unwrap()))
+                .collect(),
+        )
+    }
+
+    /// Used by [`match_nodes!`] to check and consume an arm with a leading and/or trailing `..`
+    /// wildcard, e.g. `[.., ident(name), ..]`. `rules` lists the expected rule of each required
+    /// slot in between, in order; `leading`/`trailing` say whether a wildcard was written before/
+    /// after them, i.e. whether this sequence is allowed to have nodes there at all. Matching looks
+    /// for the leftmost contiguous run of nodes whose rules match `rules` exactly, anywhere a
+    /// wildcard allows it to start/end - `leading: false` pins the run to the very first node,
+    /// `trailing: false` pins it to the very last.
+    ///
+    /// Returns `None` without consuming anything if no such run exists - too few nodes, no run
+    /// matching `rules` at all, or one only reachable by skipping nodes at an end with no wildcard.
+    /// Otherwise consumes the whole sequence, including whatever either wildcard skipped, and
+    /// returns one `Node` per entry in `rules`.
+    pub fn match_wildcard_seq(
+        &mut self,
+        rules: &[R],
+        leading: bool,
+        trailing: bool,
+    ) -> Option<Vec<Node<'i, R, D, Ctx>>> {
+        let actual = self.rules();
+        if actual.len() < rules.len() {
+            return None;
+        }
+        let last_start = actual.len() - rules.len();
+        for start in 0..=last_start {
+            if !leading && start != 0 {
+                continue;
+            }
+            if !trailing && start != last_start {
+                continue;
+            }
+            if &actual[start..start + rules.len()] == rules {
+                for _ in 0..start {
+                    self.next_node();
+                }
+                let matched: Vec<_> = (0..rules.len()).map(|_| self.next_node().unwrap()).collect();
+                while self.next_node().is_some() {}
+                return Some(matched);
+            }
+        }
+        None
+    }
+
+    /// Used by [`match_nodes!`] to check and consume a `name(binding) sep sep_rule ..` arm, e.g. a
+    /// comma-separated list of `expr` built as `expr ~ (comma ~ expr)*`. `rule` is the element's
+    /// rule, `sep` the separator's. Matching alternates: an element, then (if present) a
+    /// separator, repeating until the next node isn't an element - so a sequence with no trailing
+    /// separator, one trailing separator, or no elements at all (an empty list) are all accepted,
+    /// but two separators in a row, or a separator with no element before it, are not.
+    ///
+    /// Returns `None` without consuming anything if the sequence doesn't fit that shape - nodes
+    /// left over once the alternation stops finding another element. Otherwise consumes the whole
+    /// sequence, including any trailing separator, and returns one `Node` per element (the
+    /// separators are discarded, never bound to anything).
+    pub fn match_separated_seq(
+        &mut self,
+        rule: R,
+        sep: R,
+    ) -> Option<Vec<Node<'i, R, D, Ctx>>> {
+        let mut fork = self.fork();
+        let mut matched = Vec::new();
+        loop {
+            match fork.peek_rule() {
+                Some(r) if r == rule => matched.push(fork.next_node().unwrap()),
+                _ => break,
+            }
+            match fork.peek_rule() {
+                Some(r) if r == sep => {
+                    fork.next_node();
+                }
+                _ => break,
+            }
+        }
+        if !fork.is_empty() {
+            return None;
+        }
+        *self = fork;
+        Some(matched)
+    }
+
+    /// Used by [`match_nodes!`] to check and consume a tag-keyed arm, where every node is expected
+    /// to carry one of the [`#tag`](Node::tag)s listed in `pattern`, in any order. `pattern` lists
+    /// one `(tag, rule)` pair per requested binding.
+    ///
+    /// Returns `None` without consuming anything if this sequence doesn't fit that shape exactly -
+    /// a node with no tag, an unlisted tag, a tag used on the wrong rule, a tag repeated, or a tag
+    /// missing - so the next arm gets a fresh look at the same nodes. Otherwise consumes the whole
+    /// sequence and returns one `Node` per entry in `pattern`, in the same order as `pattern`
+    /// (not the order the nodes actually appeared in).
+    pub fn match_tagged_seq(&mut self, pattern: &[(&str, R)]) -> Option<Vec<Node<'i, R, D, Ctx>>> {
+        let mut fork = self.fork();
+        let mut slots: Vec<Option<Node<'i, R, D, Ctx>>> = pattern.iter().map(|_| None).collect();
+        while let Some(node) = fork.next_node() {
+            let tag = node.tag().map(str::to_owned)?;
+            let idx = pattern
+                .iter()
+                .position(|&(t, r)| t == tag && r == node.as_rule())?;
+            if slots[idx].is_some() {
+                return None;
+            }
+            slots[idx] = Some(node);
+        }
+        if slots.iter().any(Option::is_none) {
+            return None;
+        }
+        *self = fork;
+        Some(slots.into_iter().map(Option::unwrap).collect())
+    }
+
+    /// Consume and return the next node, if any.
+    pub fn next_node(&mut self) -> Option<Node<'i, R, D, Ctx>> {
+        let pair = self.pairs.next()?;
+        let sibling_index = self.next_sibling_index;
+        self.next_sibling_index += 1;
+        if let Some(coverage) = self.coverage {
+            // Safety: `coverage` was derived from a `&mut HashSet<R>` that
+            // `Parser::parse_with_coverage` keeps borrowed for the whole consume pass, and is
+            // never touched anywhere else during that pass.
+            unsafe { (*coverage).insert(pair.as_rule()) };
+        }
+        if let Some(node_count) = &self.node_count {
+            node_count.set(node_count.get() + 1);
+        }
+        Some(Node {
+            pair,
+            user_data: self.user_data.clone(),
+            context: self.context,
+            context_lock: Rc::clone(&self.context_lock),
+            errors: self.errors,
+            warnings: self.warnings,
+            parent_link: self.parent_link.clone(),
+            depth: self.depth,
+            max_depth: self.max_depth,
+            max_nodes: self.max_nodes,
+            node_count: self.node_count.clone(),
+            skip_rules: self.skip_rules.clone(),
+            sibling_index: Some(sibling_index),
+            cancel_token: self.cancel_token,
+            coverage: self.coverage,
+            path: self.path.clone(),
+            trivia: self.trivia,
+        })
+    }
+
+    /// Consume and return the next node if it matches `rule`, without advancing otherwise. A
+    /// shorthand for the common hand-rolled pattern of [`Nodes::peek_rule`]-then-[`next_node`](
+    /// Nodes::next_node) for an optional element, e.g. `while let Some(c) =
+    /// nodes.next_if_rule(Rule::comment) { ... }` to consume a run of interleaved comments without
+    /// reaching for [`Nodes::exclude_rule`].
+    pub fn next_if_rule(&mut self, rule: R) -> Option<Node<'i, R, D, Ctx>> {
+        if self.peek_rule() != Some(rule) {
+            return None;
+        }
+        self.next_node()
+    }
+
+    /// The span covered by every node still left in this sequence, from the start of the next
+    /// unconsumed node to the end of the last one - `None` if nothing remains. Useful for an
+    /// "unexpected trailing content" error once a consuming method has taken as many nodes as it
+    /// understands and found more left over, pointing precisely at the leftover span rather than
+    /// only being able to say that something was left.
+    pub fn remaining_span(&self) -> Option<pest::Span<'i>> {
+        let mut iter = self.pairs.clone();
+        let first = iter.next()?;
+        let last = iter.last().unwrap_or_else(|| first.clone());
+        Some(first.as_span().start_pos().span(&last.as_span().end_pos()))
+    }
+
+    /// Build an [`Error`] pointing at the next node, or at the parent node's span if this
+    /// sequence is empty. If this parse was started with [`Parser::parse_named`], the error's
+    /// `Display` also names the source it came from.
+    pub fn error(&self, message: impl ToString) -> Error<R> {
+        let variant = ErrorVariant::CustomError {
+            message: message.to_string(),
+        };
+        let error = match self.pairs.clone().peek() {
+            Some(pair) => Error::new_from_span(variant, pair.as_span()),
+            None => Error::new_from_span(variant, self.parent_span),
+        };
+        match &self.path {
+            Some(path) => error.with_path(path),
+            None => error,
+        }
+    }
+
+    /// Consume and return the first node, requiring that there is one but not caring how many
+    /// others remain. A safe alternative to `nodes.next().unwrap()` in a hand-written consuming
+    /// method that only needs the first child - see [`Nodes::exactly`]/[`Nodes::two`] when every
+    /// remaining node matters too.
+    pub fn first(&mut self) -> Result<Node<'i, R, D, Ctx>, Error<R>> {
+        self.next_node()
+            .ok_or_else(|| self.error("expected at least one node, found none"))
+    }
+
+    /// Build the [`Error`] for an arity mismatch ([`single`](Self::single)/
+    /// [`exactly`](Self::exactly)/...): `message_prefix`, followed by the actual count and the
+    /// rule of every remaining node, e.g. `"expected exactly 1 node, found 3: [ident, comma,
+    /// ident]"`. Points at the span covering every remaining node - see
+    /// [`Nodes::remaining_span`] - or at the parent node's span if nothing remains.
+    fn arity_error(&self, message_prefix: impl std::fmt::Display) -> Error<R> {
+        let rules: Vec<String> = self.pairs.clone().map(|pair| format!("{:?}", pair.as_rule())).collect();
+        let message = format!("{message_prefix}, found {}: [{}]", rules.len(), rules.join(", "));
+        let variant = ErrorVariant::CustomError { message };
+        let error = match self.remaining_span() {
+            Some(span) => Error::new_from_span(variant, span),
+            None => Error::new_from_span(variant, self.parent_span),
+        };
+        match &self.path {
+            Some(path) => error.with_path(path),
+            None => error,
+        }
+    }
+
+    /// Consume this sequence, requiring that it contains exactly one node, and return it.
+    pub fn single(mut self) -> Result<Node<'i, R, D, Ctx>, Error<R>> {
+        if self.len() != 1 {
+            return Err(self.arity_error("expected exactly 1 node"));
+        }
+        Ok(self.next_node().unwrap_or_else(|| unreachable!("length was just checked to be 1")))
+    }
+
+    /// Like [`Nodes::single`], but an empty sequence isn't an error - only more than one node is.
+    pub fn single_or_none(mut self) -> Result<Option<Node<'i, R, D, Ctx>>, Error<R>> {
+        match self.len() {
+            0 => Ok(None),
+            1 => Ok(self.next_node()),
+            _ => Err(self.arity_error("expected at most 1 node")),
+        }
+    }
+
+    /// Consume this sequence, requiring that it contains exactly `N` nodes, and return them as a
+    /// fixed-size array in order. Errors with the actual count and the rule of every node present
+    /// if it doesn't match `N`. See [`Nodes::two`] for the common `N = 2` case as a tuple instead
+    /// of a one-element array.
+    pub fn exactly<const N: usize>(self) -> Result<[Node<'i, R, D, Ctx>; N], Error<R>> {
+        if self.len() != N {
+            return Err(self.arity_error(format!("expected exactly {N} node(s)")));
+        }
+        let nodes: Vec<_> = self.collect();
+        Ok(nodes
+            .try_into()
+            .unwrap_or_else(|_| unreachable!("length was just checked to be exactly N")))
+    }
+
+    /// Consume this sequence, requiring that it contains exactly two nodes, and return them as a
+    /// tuple. A shorthand for [`Nodes::exactly`]`::<2>()` that avoids indexing into the array.
+    #[allow(clippy::type_complexity)]
+    pub fn two(self) -> Result<(Node<'i, R, D, Ctx>, Node<'i, R, D, Ctx>), Error<R>> {
+        let [a, b] = self.exactly::<2>()?;
+        Ok((a, b))
+    }
+
+    /// Consume every remaining node, mapping each one with `f`, and collect the results into a
+    /// `Vec`. Used to implement the trailing-repetition (`..`) capture in [`match_nodes!`].
+    pub fn map_to_vec<T>(
+        mut self,
+        mut f: impl FnMut(Node<'i, R, D, Ctx>) -> Result<T, Error<R>>,
+    ) -> Result<Vec<T>, Error<R>> {
+        let mut out = Vec::new();
+        while let Some(node) = self.next_node() {
+            out.push(f(node)?);
+        }
+        Ok(out)
+    }
+
+    /// Like [`Nodes::map_to_vec`], but doesn't collect into a `Vec` up front - each remaining node
+    /// is consumed and mapped through `f` only as the returned iterator is actually advanced, so a
+    /// caller that processes and discards each `T` in turn (e.g. writing it straight out to a
+    /// stream) never holds more than one mapped value in memory at once, no matter how large the
+    /// sequence is. Iteration stops for good after the first `Err`, the same as a `?` inside
+    /// [`Nodes::map_to_vec`]'s loop would.
+    pub fn into_consuming_iter<T>(
+        mut self,
+        mut f: impl FnMut(Node<'i, R, D, Ctx>) -> Result<T, Error<R>> + 'i,
+    ) -> impl Iterator<Item = Result<T, Error<R>>> + 'i
+    where
+        T: 'i,
+        R: 'i,
+        D: 'i,
+        Ctx: 'i,
+    {
+        let mut done = false;
+        std::iter::from_fn(move || {
+            if done {
+                return None;
+            }
+            let node = self.next_node()?;
+            let result = f(node);
+            if result.is_err() {
+                done = true;
+            }
+            Some(result)
+        })
+    }
+
+    /// Consume every remaining node, mapping each one with `f`, but unlike [`Nodes::map_to_vec`],
+    /// never stop at the first `Err`. Every successfully-mapped value is returned alongside every
+    /// error, so a caller validating a whole sequence - a config file, a CSV record - can report
+    /// every malformed child at once instead of just the first, as described in
+    /// [`advanced_features::error_recovery`].
+    pub fn consume_all<T>(
+        mut self,
+        mut f: impl FnMut(Node<'i, R, D, Ctx>) -> Result<T, Error<R>>,
+    ) -> (Vec<T>, Vec<Error<R>>) {
+        let mut values = Vec::new();
+        let mut errors = Vec::new();
+        while let Some(node) = self.next_node() {
+            match f(node) {
+                Ok(value) => values.push(value),
+                Err(error) => errors.push(error),
+            }
+        }
+        (values, errors)
+    }
+
+    /// Like [`Nodes::consume_all`], but for a sequence made of several independent groups
+    /// separated by a `sync` rule - e.g. a block of statements separated by `;` - rather than a
+    /// flat run of same-shaped nodes. Each group (the nodes between one `sync` node and the next,
+    /// with every `sync` node itself discarded) is handed to `f` as its own [`Nodes`]; a group
+    /// that fails doesn't touch any other group, since group boundaries are already fixed by
+    /// where the `sync` rules matched, independent of how far `f` got before erroring. Returns
+    /// every successfully-produced value alongside every group's error, the same shape as
+    /// `consume_all`, so a caller can show partial results plus every diagnostic at once instead
+    /// of aborting at the first bad statement. See
+    /// [`advanced_features::error_recovery`].
+    pub fn consume_with_recovery<T>(
+        mut self,
+        sync: R,
+        mut f: impl FnMut(Nodes<'i, R, D, Ctx>) -> Result<T, Error<R>>,
+    ) -> (Vec<T>, Vec<Error<R>>) {
+        let mut values = Vec::new();
+        let mut errors = Vec::new();
+        while !self.is_empty() {
+            let group_start = self.next_sibling_index;
+            let mut group = Vec::new();
+            while let Some(pair) = self.pairs.peek() {
+                self.pairs.next();
+                self.next_sibling_index += 1;
+                if pair.as_rule() == sync {
+                    break;
+                }
+                group.push(pair);
+            }
+            if group.is_empty() {
+                continue;
+            }
+            let group_nodes = Nodes {
+                pairs: NodesIter::Filtered(group.clone().into_iter()),
+                original: NodesIter::Filtered(group.into_iter()),
+                user_data: self.user_data.clone(),
+                context: self.context,
+                context_lock: Rc::clone(&self.context_lock),
+                errors: self.errors,
+                warnings: self.warnings,
+                parent_span: self.parent_span,
+                parent_link: self.parent_link.clone(),
+                depth: self.depth,
+                max_depth: self.max_depth,
+                max_nodes: self.max_nodes,
+                node_count: self.node_count.clone(),
+                skip_rules: self.skip_rules.clone(),
+                next_sibling_index: group_start,
+                cancel_token: self.cancel_token,
+                coverage: self.coverage,
+                path: self.path.clone(),
+                trivia: self.trivia,
+            };
+            match f(group_nodes) {
+                Ok(value) => values.push(value),
+                Err(error) => errors.push(error),
+            }
+        }
+        (values, errors)
+    }
+
+    /// Gather every remaining node into a `Vec`, mapping each one with `f`. Meant to be collected
+    /// further into a map type of the caller's choice, e.g. with `.into_iter().collect()`. See
+    /// [`collect_map_no_dup`](Self::collect_map_no_dup) for the duplicate-rejecting variant.
+    pub fn collect_map<K, V>(
+        self,
+        f: impl FnMut(Node<'i, R, D, Ctx>) -> Result<(K, V), Error<R>>,
+    ) -> Result<Vec<(K, V)>, Error<R>> {
+        self.map_to_vec(f)
+    }
+
+    /// Like [`collect_map`](Self::collect_map), but rejects a repeated key: the error is built
+    /// from the [`Node`] of the second occurrence, via [`Node::error`].
+    pub fn collect_map_no_dup<K, V>(
+        mut self,
+        mut f: impl FnMut(Node<'i, R, D, Ctx>) -> Result<(K, V), Error<R>>,
+    ) -> Result<Vec<(K, V)>, Error<R>>
+    where
+        K: Eq + std::hash::Hash + Clone,
+    {
+        let mut seen = std::collections::HashSet::new();
+        let mut out = Vec::new();
+        while let Some(node) = self.next_node() {
+            let dup_error = node.error("duplicate key");
+            let (k, v) = f(node)?;
+            if !seen.insert(k.clone()) {
+                return Err(dup_error);
+            }
+            out.push((k, v));
+        }
+        Ok(out)
+    }
+
+    /// Gather every remaining node two at a time - a key then its value - straight into a
+    /// `HashMap`, mapping each with `kf`/`vf` respectively. For the "one `entry` node with two
+    /// grandchildren" shape instead, give `entry` its own consuming method returning `(K, V)` and
+    /// write `[entry(e)..] => collect_map_no_dup` in a function returning `Result<HashMap<K, V>,
+    /// Error<R>>` - the trailing-capture sugar collects into whatever the caller's return type
+    /// asks for. Rejects a repeated key: the error is built from the key [`Node`] of the second
+    /// occurrence, via [`Node::error`].
+    pub fn collect_map_pairs<K, V>(
+        mut self,
+        mut kf: impl FnMut(Node<'i, R, D, Ctx>) -> Result<K, Error<R>>,
+        mut vf: impl FnMut(Node<'i, R, D, Ctx>) -> Result<V, Error<R>>,
+    ) -> Result<std::collections::HashMap<K, V>, Error<R>>
+    where
+        K: Eq + std::hash::Hash,
+    {
+        let mut out = std::collections::HashMap::new();
+        while let Some(key_node) = self.next_node() {
+            let dup_error = key_node.error("duplicate key");
+            let key = kf(key_node)?;
+            let value_node = self
+                .next_node()
+                .ok_or_else(|| self.error("key with no matching value"))?;
+            let value = vf(value_node)?;
+            if out.insert(key, value).is_some() {
+                return Err(dup_error);
+            }
+        }
+        Ok(out)
+    }
+
+    /// Gather every remaining node into a `Vec`, mapping each one with `f`. Meant to be collected
+    /// further into a set type of the caller's choice. See
+    /// [`collect_set_no_dup`](Self::collect_set_no_dup) for the duplicate-rejecting variant.
+    pub fn collect_set<V>(
+        self,
+        f: impl FnMut(Node<'i, R, D, Ctx>) -> Result<V, Error<R>>,
+    ) -> Result<Vec<V>, Error<R>> {
+        self.map_to_vec(f)
+    }
+
+    /// Like [`collect_set`](Self::collect_set), but rejects a repeated value: the error is built
+    /// from the [`Node`] of the second occurrence, via [`Node::error`].
+    pub fn collect_set_no_dup<V>(
+        mut self,
+        mut f: impl FnMut(Node<'i, R, D, Ctx>) -> Result<V, Error<R>>,
+    ) -> Result<Vec<V>, Error<R>>
+    where
+        V: Eq + std::hash::Hash + Clone,
+    {
+        let mut seen = std::collections::HashSet::new();
+        let mut out = Vec::new();
+        while let Some(node) = self.next_node() {
+            let dup_error = node.error("duplicate value");
+            let v = f(node)?;
+            if !seen.insert(v.clone()) {
+                return Err(dup_error);
+            }
+            out.push(v);
+        }
+        Ok(out)
+    }
+
+    /// Gather every remaining node into a `Vec`, in source order - see the [`Iterator`] impl.
+    /// Shorthand for `.collect()` that doesn't need a turbofish or a type annotation at the call
+    /// site.
+    pub fn collect_vec(self) -> Vec<Node<'i, R, D, Ctx>> {
+        self.collect()
+    }
+}
+
+/// Yields children in the same order they appear in the source text - the same guarantee
+/// [`Node::sibling_index`] relies on, and the order [`match_nodes!`](crate::match_nodes) pattern
+/// matches against.
+impl<'i, R: RuleType, D: Clone, Ctx> Iterator for Nodes<'i, R, D, Ctx> {
+    type Item = Node<'i, R, D, Ctx>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_node()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<'i, R: RuleType, D: Clone, Ctx> ExactSizeIterator for Nodes<'i, R, D, Ctx> {
+    fn len(&self) -> usize {
+        Nodes::len(self)
+    }
+}
+
+/// Bounds how many candidate prefixes [`Parser::parse_partial`]'s backoff search tries before
+/// giving up on recovery, so a late syntax error in a large input can't force a quadratic number
+/// of reparses.
+const PARSE_PARTIAL_MAX_BACKOFF_ATTEMPTS: usize = 4096;
+
+/// Implement this trait for your grammar's `pest_derive`-generated parser to get access to
+/// [`Node`]-based parsing.
+///
+/// Your parser must also implement `pest::Parser<Self::Rule>` - this is what `pest_derive`
+/// generates for you.
+pub trait Parser {
+    /// The rule type generated by `pest_derive` for this grammar.
+    type Rule: RuleType;
+
+    /// Parse `input_str` starting from `rule`, with no user data.
+    fn parse(rule: Self::Rule, input_str: &str) -> Result<Nodes<'_, Self::Rule>, Error<Self::Rule>>
+    where
+        Self: pest::Parser<Self::Rule>,
+    {
+        Self::parse_with_userdata(rule, input_str, ())
+    }
+
+    /// Parse `input_str` starting from `rule`, threading `data` through every [`Node`] as
+    /// described in [`advanced_features::user_data`].
+    fn parse_with_userdata<D: Clone>(
+        rule: Self::Rule,
+        input_str: &str,
+        data: D,
+    ) -> Result<Nodes<'_, Self::Rule, D>, Error<Self::Rule>>
+    where
+        Self: pest::Parser<Self::Rule>,
+    {
+        let pairs = <Self as pest::Parser<Self::Rule>>::parse(rule, input_str)?;
+        Ok(Nodes::new(pairs, data, input_str))
+    }
+
+    /// Parse `input_str` starting from `rule`, with no user data, then call `dispatch` on the
+    /// single resulting top-level [`Node`] - the `parse` then [`single`](Nodes::single) then call
+    /// the root consuming method sequence every entry point otherwise has to spell out by hand,
+    /// as described in [`advanced_features::entry_point`].
+    fn parse_entry<'i, T>(
+        rule: Self::Rule,
+        input_str: &'i str,
+        dispatch: impl FnOnce(Node<'i, Self::Rule>) -> Result<T, Error<Self::Rule>>,
+    ) -> Result<T, Error<Self::Rule>>
+    where
+        Self: pest::Parser<Self::Rule>,
+    {
+        dispatch(<Self as Parser>::parse(rule, input_str)?.single()?)
+    }
+
+    /// Like [`parse_entry`](Self::parse_entry), but threading `data` through every [`Node`] as
+    /// described in [`advanced_features::user_data`].
+    fn parse_entry_with_userdata<'i, D: Clone, T>(
+        rule: Self::Rule,
+        input_str: &'i str,
+        data: D,
+        dispatch: impl FnOnce(Node<'i, Self::Rule, D>) -> Result<T, Error<Self::Rule>>,
+    ) -> Result<T, Error<Self::Rule>>
+    where
+        Self: pest::Parser<Self::Rule>,
+    {
+        dispatch(Self::parse_with_userdata(rule, input_str, data)?.single()?)
+    }
+
+    /// Read all of `reader` into `buf`, then parse it starting from `rule`, with no user data.
+    /// `buf` is cleared first, so any leftover content from a previous call is discarded.
+    ///
+    /// This reads the whole source into memory before parsing - the same as calling
+    /// [`std::io::Read::read_to_string`] yourself and passing the result to
+    /// [`parse`](Self::parse) - rather than parsing incrementally with bounded memory. See
+    /// [`advanced_features::streaming_reads`] for why true streaming isn't a fit for this crate's
+    /// zero-copy [`Node`]s, and what to do instead for inputs too large to buffer whole.
+    ///
+    /// Requires the `std` feature, since [`std::io::Read`] isn't available otherwise. See
+    /// [`advanced_features::no_std_support`].
+    #[cfg(feature = "std")]
+    fn parse_from_reader<'i>(
+        rule: Self::Rule,
+        mut reader: impl std::io::Read,
+        buf: &'i mut String,
+    ) -> Result<Nodes<'i, Self::Rule>, ReadError<Self::Rule>>
+    where
+        Self: pest::Parser<Self::Rule>,
+    {
+        buf.clear();
+        reader.read_to_string(buf).map_err(ReadError::Io)?;
+        <Self as Parser>::parse(rule, buf).map_err(ReadError::Parse)
+    }
+
+    /// Parse `input_str` starting from `rule`, with no user data, building a parent chain so that
+    /// every [`Node`] produced while descending the tree can walk back up via [`Node::parent`], as
+    /// described in [`advanced_features::parent_navigation`]. Costs one [`Rc`] allocation per
+    /// level of descent; use plain [`parse`](Self::parse) when that isn't needed.
+    fn parse_parented(
+        rule: Self::Rule,
+        input_str: &str,
+    ) -> Result<Nodes<'_, Self::Rule>, Error<Self::Rule>>
+    where
+        Self: pest::Parser<Self::Rule>,
+    {
+        let pairs = <Self as pest::Parser<Self::Rule>>::parse(rule, input_str)?;
+        Ok(Nodes::new_parented(pairs, input_str))
+    }
+
+    /// Parse `input_str` starting from `rule`, with no user data, building the same parent chain
+    /// as [`parse_parented`](Self::parse_parented) and additionally recording `comment_rule`/
+    /// `whitespace_rule`, so that [`Node::leading_trivia`]/[`Node::trailing_trivia`] can recover
+    /// the comments immediately surrounding each node - reconstructed from the gap of source text
+    /// between sibling spans, since pest silently inserts `comment_rule`/`whitespace_rule` between
+    /// sequenced items in a non-atomic rule without ever giving them a pair of their own. See
+    /// [`advanced_features::comment_trivia`].
+    fn parse_with_trivia(
+        rule: Self::Rule,
+        input_str: &str,
+        comment_rule: Self::Rule,
+        whitespace_rule: Self::Rule,
+    ) -> Result<Nodes<'_, Self::Rule>, Error<Self::Rule>>
+    where
+        Self: pest::Parser<Self::Rule>,
+    {
+        let pairs = <Self as pest::Parser<Self::Rule>>::parse(rule, input_str)?;
+        let parse_fn: TriviaParseFn<Self::Rule> = <Self as pest::Parser<Self::Rule>>::parse;
+        Ok(Nodes::new_with_trivia(pairs, input_str, comment_rule, whitespace_rule, parse_fn))
+    }
+
+    /// Parse `input_str` starting from `rule`, with no user data, bounding how many
+    /// [`into_children`](Node::into_children)/[`children_ref`](Node::children_ref) calls deep the
+    /// consuming pass may go: once [`Node::depth`] would exceed `max_depth`, [`match_nodes!`]
+    /// returns a clean `Err` instead of letting the consuming pass's own recursive descent overflow
+    /// the stack on adversarial input like deeply nested parentheses. Unbounded (the behavior of
+    /// every other entry point) unless this is used. See [`advanced_features::recursion_limit`].
+    fn parse_with_depth_limit(
+        rule: Self::Rule,
+        input_str: &str,
+        max_depth: usize,
+    ) -> Result<Nodes<'_, Self::Rule>, Error<Self::Rule>>
+    where
+        Self: pest::Parser<Self::Rule>,
+    {
+        let pairs = <Self as pest::Parser<Self::Rule>>::parse(rule, input_str)?;
+        Ok(Nodes::new_with_depth_limit(pairs, input_str, max_depth))
+    }
+
+    /// Parse `input_str` starting from `rule`, with no user data, enforcing whichever of
+    /// `limits`'s [`max_input_bytes`](ParseLimits::max_input_bytes)/
+    /// [`max_depth`](ParseLimits::max_depth)/[`max_nodes`](ParseLimits::max_nodes) are set. A
+    /// `max_input_bytes` violation is rejected outright, before pest ever runs; `max_depth`/
+    /// `max_nodes` are enforced the same way [`parse_with_depth_limit`](Self::parse_with_depth_limit)
+    /// enforces a depth limit alone, returning a clean `Err` from [`match_nodes!`] instead of
+    /// letting the consuming pass run past either budget. Combines the three so a service facing
+    /// untrusted input can reject a pathological payload - too large, too deep, or simply too
+    /// big a tree - before it ever threatens the stack or the heap. See
+    /// [`advanced_features::parse_limits`].
+    fn parse_with_limits(
+        rule: Self::Rule,
+        input_str: &str,
+        limits: ParseLimits,
+    ) -> Result<Nodes<'_, Self::Rule>, Error<Self::Rule>>
+    where
+        Self: pest::Parser<Self::Rule>,
+    {
+        if let Some(max_input_bytes) = limits.max_input_bytes {
+            if input_str.len() > max_input_bytes {
+                return Err(Error::new_from_pos(
+                    ErrorVariant::CustomError {
+                        message: format!(
+                            "input of {} bytes exceeds the {max_input_bytes} byte limit",
+                            input_str.len(),
+                        ),
+                    },
+                    pest::Position::from_start(input_str),
+                ));
+            }
+        }
+        let pairs = <Self as pest::Parser<Self::Rule>>::parse(rule, input_str)?;
+        Ok(Nodes::new_with_limits(pairs, input_str, limits))
+    }
+
+    /// Parse `input_str` starting from `rule`, with no user data, checking `cancel_token` before
+    /// every [`match_nodes!`] dispatch - at least once per node visited during the consuming
+    /// pass. Once `cancel_token` is set, [`match_nodes!`] returns a clean `Err` instead of letting
+    /// the pass run any further, as described in [`advanced_features::cancellation`]. Lets a
+    /// caller enforce a deadline on a pathological input by setting `cancel_token` from another
+    /// thread (e.g. a timer), rather than having to kill the parsing thread outright.
+    fn parse_with_cancel<'i>(
+        rule: Self::Rule,
+        input_str: &'i str,
+        cancel_token: &'i AtomicBool,
+    ) -> Result<Nodes<'i, Self::Rule>, Error<Self::Rule>>
+    where
+        Self: pest::Parser<Self::Rule>,
+    {
+        let pairs = <Self as pest::Parser<Self::Rule>>::parse(rule, input_str)?;
+        Ok(Nodes::new_with_cancel_token(pairs, input_str, cancel_token))
+    }
+
+    /// Parse `input_str` starting from `rule`, with no user data, recording the rule of every
+    /// node consumed - via [`Nodes::next_node`] or [`match_nodes!`], which is built on top of it -
+    /// into `coverage` as the consuming pass visits it. Once the pass finishes, `coverage` holds
+    /// every grammar rule the input actually exercised, so comparing it against `Rule::COUNT` (or
+    /// simply diffing it against the full set of rules by hand) surfaces the ones a test corpus
+    /// never reached, as described in [`advanced_features::grammar_coverage`].
+    fn parse_with_coverage<'i>(
+        rule: Self::Rule,
+        input_str: &'i str,
+        coverage: &'i mut std::collections::HashSet<Self::Rule>,
+    ) -> Result<Nodes<'i, Self::Rule>, Error<Self::Rule>>
+    where
+        Self: pest::Parser<Self::Rule>,
+    {
+        let pairs = <Self as pest::Parser<Self::Rule>>::parse(rule, input_str)?;
+        Ok(Nodes::new_with_coverage(pairs, input_str, coverage))
+    }
+
+    /// Parse `input_str` starting from `rule`, with no user data, attaching `path` to every
+    /// [`Error`] built from a [`Node`]/[`Nodes`] produced while descending from it, so its
+    /// `Display` names the source it came from without any post-processing on the caller's part,
+    /// as described in [`advanced_features::named_sources`].
+    fn parse_named<'i>(
+        rule: Self::Rule,
+        input_str: &'i str,
+        path: &str,
+    ) -> Result<Nodes<'i, Self::Rule>, Error<Self::Rule>>
+    where
+        Self: pest::Parser<Self::Rule>,
+    {
+        let pairs = <Self as pest::Parser<Self::Rule>>::parse(rule, input_str)?;
+        Ok(Nodes::new_with_path(pairs, input_str, path))
+    }
+
+    /// Parse every `(name, input_str)` pair in `inputs` starting from `rule`, cloning `data` for
+    /// each one, as described in [`advanced_features::batch_parsing`]. Standardizes the
+    /// boilerplate a multi-file tool (a linter or bundler walking a whole project, say) would
+    /// otherwise repeat per file: each result's `Err`, like [`parse_named`](Self::parse_named),
+    /// has `name` attached so its `Display` names the file it came from, and on success `name` is
+    /// likewise attached to every [`Node`]/[`Nodes`] so errors built from it later do too. Returns
+    /// one `Result` per input rather than stopping at the first failure, so a single malformed
+    /// file in a large batch doesn't hide every other file's result.
+    fn parse_batch<'a, D: Clone>(
+        rule: Self::Rule,
+        inputs: &[(&str, &'a str)],
+        data: D,
+    ) -> Vec<BatchResult<'a, Self::Rule, D>>
+    where
+        Self: pest::Parser<Self::Rule>,
+    {
+        inputs
+            .iter()
+            .map(|&(name, input_str)| {
+                let result = Self::parse_with_userdata(rule, input_str, data.clone())
+                    .map(|mut nodes| {
+                        nodes.path = Some(Rc::from(name));
+                        nodes
+                    })
+                    .map_err(|e| e.with_path(name));
+                (name.to_owned(), result)
+            })
+            .collect()
+    }
+
+    /// Parse `input_str` starting from `rule`, with no user data, dropping every rule listed in
+    /// `options` from `pairs` whenever a child sequence is built - at any depth of descent, not
+    /// just the top level - so neither [`match_nodes!`] nor manual iteration ever sees them. Use
+    /// this for a rule that has to stay non-silent (no leading `_`) in the grammar for one
+    /// consuming method, but gets in the way of every other one, as described in
+    /// [`advanced_features::node_filtering`].
+    fn parse_with_options(
+        rule: Self::Rule,
+        input_str: &str,
+        options: ParseOptions<Self::Rule>,
+    ) -> Result<Nodes<'_, Self::Rule>, Error<Self::Rule>>
+    where
+        Self: pest::Parser<Self::Rule>,
+    {
+        let pairs = <Self as pest::Parser<Self::Rule>>::parse(rule, input_str)?;
+        Ok(Nodes::new_with_options(pairs, input_str, options))
+    }
+
+    /// Parse an owned `input` starting from `rule`, with no user data, returning an
+    /// [`OwnedNodes`] that keeps `input` alive alongside the [`Nodes`] borrowed from it - so the
+    /// result can be returned from a function, stored in a struct, or sent elsewhere without the
+    /// caller having to keep a separate `String` alive and threading its lifetime through every
+    /// signature that touches the parse result. See [`advanced_features::owned_parsing`] for the
+    /// trade-off this makes in exchange: the [`Nodes`] can only be taken out and consumed once.
+    ///
+    /// Requires the `owned_parsing` feature.
+    #[cfg(feature = "owned_parsing")]
+    fn parse_owned(
+        rule: Self::Rule,
+        input: String,
+    ) -> Result<OwnedNodes<Self::Rule>, Error<Self::Rule>>
+    where
+        Self: pest::Parser<Self::Rule>,
+        Self::Rule: 'static,
+    {
+        OwnedNodes::try_new(input, |input: &String| {
+            let pairs = <Self as pest::Parser<Self::Rule>>::parse(rule, input)?;
+            Ok(Some(Nodes::new(pairs, (), input)))
+        })
+    }
+
+    /// Parse `substring` starting from `rule` alone, with no user data - the same as [`parse`]
+    /// against just that slice, given its own name for the common caller of this method: something
+    /// re-parsing a single edited subtree (e.g. a language server re-parsing the one top-level item
+    /// the cursor is in) rather than the whole document. See
+    /// [`advanced_features::incremental_reparse`] for why the result can't be spliced back into a
+    /// `Node` tree parsed from the original, larger document - the caller has to manage that caching
+    /// layer itself.
+    ///
+    /// [`parse`]: Self::parse
+    fn reparse(
+        rule: Self::Rule,
+        substring: &str,
+    ) -> Result<Nodes<'_, Self::Rule>, Error<Self::Rule>>
+    where
+        Self: pest::Parser<Self::Rule>,
+    {
+        <Self as Parser>::parse(rule, substring)
+    }
+
+    /// Parse `input_str` starting from `rule`, matching it once against the start of the input
+    /// without requiring the rest to be consumed - unlike every other entry point, which expects
+    /// `rule` (almost always via a top rule ending in `EOI`) to account for the whole input.
+    /// Returns the matched [`Nodes`] alongside whatever of `input_str` was left over, byte-for-
+    /// byte - useful for a REPL or any other reader that parses one top-level item at a time off a
+    /// stream, rather than having the whole input available up front. Errors if `rule` matched
+    /// zero bytes, since a caller looping on the remainder would otherwise spin forever without
+    /// ever making progress.
+    fn parse_prefix(
+        rule: Self::Rule,
+        input_str: &str,
+    ) -> Result<PrefixMatch<'_, Self::Rule>, Error<Self::Rule>>
+    where
+        Self: pest::Parser<Self::Rule>,
+    {
+        let pairs = <Self as pest::Parser<Self::Rule>>::parse(rule, input_str)?;
+        let consumed = pairs.clone().map(|pair| pair.as_span().end()).max().unwrap_or(0);
+        if consumed == 0 {
+            return Err(Error::new_from_pos(
+                ErrorVariant::CustomError {
+                    message: format!("{rule:?} matched zero bytes at the start of the input"),
+                },
+                pest::Position::from_start(input_str),
+            ));
+        }
+        Ok((Nodes::new(pairs, (), input_str), &input_str[consumed..]))
+    }
+
+    /// Parse `input_str` starting from `rule`, recovering a best-effort tree when pest's own
+    /// grammar match fails partway through, rather than only ever handing back an `Err` with
+    /// nothing else. On success this is the same as [`parse`](Self::parse), wrapped in `Some`
+    /// alongside `None`. On failure, backs off from the furthest position pest's error reports
+    /// reaching - one `char` boundary at a time - looking for the longest prefix of `input_str`
+    /// that is itself a complete match for `rule`, and returns that alongside the original error.
+    ///
+    /// Whether backing off ever finds such a prefix depends entirely on how `rule` is written;
+    /// see [`advanced_features::error_recovery`] for how to structure a grammar so it usually
+    /// does. Many grammars never will, in which case the first element is always `None` and this
+    /// degrades to reporting the same error as [`parse`](Self::parse) with no partial tree - never
+    /// worse, but also never better without a grammar change.
+    ///
+    /// # Complexity
+    ///
+    /// Each candidate prefix is a fresh [`pest::Parser::parse`] of `rule` from the start, so
+    /// backing off one `char` boundary at a time from a late error position would re-parse the
+    /// whole prefix up to once per boundary - quadratic in the distance pest got into the input
+    /// before failing. To keep a syntax error near the end of a large, untrusted input from
+    /// turning recovery into the expensive part of the parse, the search tries at most a fixed
+    /// number of boundaries (4096), working backward from the error. Past that many attempts,
+    /// recovery gives up the same way it would for a grammar that never recovers at all - the
+    /// first element of the returned tuple is `None` - rather than keep paying for reparses that
+    /// never found a match nearby.
+    ///
+    /// [`parse`]: Self::parse
+    fn parse_partial(rule: Self::Rule, input_str: &str) -> PartialParse<'_, Self::Rule>
+    where
+        Self: pest::Parser<Self::Rule>,
+    {
+        let err = match <Self as pest::Parser<Self::Rule>>::parse(rule, input_str) {
+            Ok(pairs) => return (Some(Nodes::new(pairs, (), input_str)), None),
+            Err(err) => err,
+        };
+        let furthest = match err.location {
+            pest::error::InputLocation::Pos(pos) => pos,
+            pest::error::InputLocation::Span((_, end)) => end,
+        };
+        let partial = (0..=furthest)
+            .rev()
+            .filter(|&i| input_str.is_char_boundary(i))
+            .take(PARSE_PARTIAL_MAX_BACKOFF_ATTEMPTS)
+            .find_map(|i| {
+                let prefix = &input_str[..i];
+                let pairs = <Self as pest::Parser<Self::Rule>>::parse(rule, prefix).ok()?;
+                Some(Nodes::new(pairs, (), prefix))
+            });
+        (partial, Some(err))
+    }
+
+    /// Parse `input_str` starting from `rule`, threading a mutable `context` through every
+    /// [`Node`] by reference rather than cloning it, as described in
+    /// [`advanced_features::context`]. Use this instead of
+    /// [`parse_with_userdata`](Self::parse_with_userdata) when the data a consuming method needs
+    /// (an interner, an arena, a symbol table) should be mutated in place as the pass descends the
+    /// tree, rather than cloned at every node.
+    fn parse_with_context<'i, Ctx>(
+        rule: Self::Rule,
+        input_str: &'i str,
+        context: &'i mut Ctx,
+    ) -> Result<Nodes<'i, Self::Rule, (), Ctx>, Error<Self::Rule>>
+    where
+        Self: pest::Parser<Self::Rule>,
+    {
+        let pairs = <Self as pest::Parser<Self::Rule>>::parse(rule, input_str)?;
+        Ok(Nodes::new_with_context(pairs, (), input_str, context))
+    }
+
+    /// Parse `input_str` starting from `rule`, then run `consume` to produce a `T`, collecting
+    /// every error [`Node::emit_error`] records along the way instead of stopping at the first,
+    /// as described in [`advanced_features::error_recovery`]. Unlike [`parse`](Self::parse), this
+    /// takes the final step of calling a top-level consuming method itself, since the error buffer
+    /// has to exist before anything - including picking the root node out of `Nodes` - runs.
+    ///
+    /// Returns `Some(T)` alongside every collected error if `consume` (and the initial parse)
+    /// succeeded, or `None` alongside them if a fatal error - an ordinary `Err` that was allowed to
+    /// propagate, or a failure from pest itself - cut the pass short instead.
+    fn parse_collecting_errors<T>(
+        rule: Self::Rule,
+        input_str: &str,
+        consume: impl FnOnce(Nodes<'_, Self::Rule>) -> Result<T, Error<Self::Rule>>,
+    ) -> (Option<T>, Vec<Error<Self::Rule>>)
+    where
+        Self: pest::Parser<Self::Rule>,
+    {
+        let mut errors = Vec::new();
+        let pairs = match <Self as pest::Parser<Self::Rule>>::parse(rule, input_str) {
+            Ok(pairs) => pairs,
+            Err(err) => {
+                errors.push(err);
+                return (None, errors);
+            }
+        };
+        let nodes = Nodes::new_collecting_errors(pairs, input_str, &mut errors);
+        match consume(nodes) {
+            Ok(value) => (Some(value), errors),
+            Err(err) => {
+                errors.push(err);
+                (None, errors)
+            }
+        }
+    }
+
+    /// Parse `input_str` starting from `rule`, then run `consume`, collecting every non-fatal
+    /// warning [`Node::warn`] records along the way into a buffer returned alongside the value,
+    /// as described in [`advanced_features::error_recovery`]. Unlike
+    /// [`Parser::parse_collecting_errors`], a warning never turns a successful parse into a
+    /// failure - an `Err` from `consume`, or from the initial parse, still propagates as `Err` the
+    /// usual way, with whatever warnings were recorded before it occurred discarded.
+    #[allow(clippy::type_complexity)]
+    fn parse_collecting_warnings<T>(
+        rule: Self::Rule,
+        input_str: &str,
+        consume: impl FnOnce(Nodes<'_, Self::Rule>) -> Result<T, Error<Self::Rule>>,
+    ) -> Result<(T, Vec<Error<Self::Rule>>), Error<Self::Rule>>
+    where
+        Self: pest::Parser<Self::Rule>,
+    {
+        let mut warnings = Vec::new();
+        let pairs = <Self as pest::Parser<Self::Rule>>::parse(rule, input_str)?;
+        let nodes = Nodes::new_collecting_warnings(pairs, input_str, &mut warnings);
+        let value = consume(nodes)?;
+        Ok((value, warnings))
+    }
+}
+
+/// Expects a [`Nodes`] value and one or more `[pattern] => expr` arms, tried in order against the
+/// actual rules of the sequence. Each pattern is a comma-separated list of `rule_name(binding)`,
+/// optionally ending in `rule_name(binding)..` to capture every remaining node: `binding` is then
+/// a `Vec<T>` of every node's result, built with an early return on the first one that errors.
+/// `rule_name` must name both a variant of the grammar's `Rule` enum and an associated function
+/// (typically `Self::rule_name`) to call on the matching node. There's no attribute to give the
+/// two different names - this crate has no `#[pest_consume::parser]` macro to carry one, as noted
+/// in [`advanced_features::custom_errors`], so `match_nodes!`'s item syntax has no indirection to
+/// hang a rename off either. A grammar with unwieldy rule names can still dispatch by hand instead
+/// of through `match_nodes!`, using [`Nodes::peek_rule`] and [`Nodes::next_node`] directly, which
+/// only ever look at the `Rule` enum and don't care what the calling method is named. The same
+/// goes for a hypothetical case-insensitive dispatch mode: `$name` above is one identifier token
+/// compared for exact equality by the compiler, not a string compared at runtime, so there's
+/// nowhere to splice in an ASCII-case-insensitive comparison short of bypassing `match_nodes!` the
+/// same way - e.g. matching on `format!("{:?}", nodes.peek_rule())` lowercased.
+///
+/// Any `rule_name(binding)` slot may instead be written `rule_name(binding)?` to make it
+/// optional: `binding` is then an `Option<T>`, bound to `None` when no node of that rule is next.
+/// An optional slot may appear anywhere in the pattern, not just at the end, e.g.
+/// `[ident(name), type_annotation(ty)?, block(b)]`.
+///
+/// A required slot may instead be written `rule_name("literal")`, with a string literal in place
+/// of a binding: this matches only when the node's [`as_str`](Node::as_str) equals `literal`
+/// exactly, and produces no binding at all - handy for a tiny keyword-like rule a caller would
+/// otherwise dispatch on by hand, e.g. `[keyword("if"), expr(c), block(b)] => ...` as one arm and
+/// `[keyword("while"), expr(c), block(b)] => ...` as another. A mismatch falls through to the next
+/// arm exactly as a shape mismatch would, rather than erroring. See
+/// [`advanced_features::keyword_dispatch`]. Not supported together with `?`, a trailing `..`
+/// capture, a `collect_*` body, a guard, tags, or groups.
+///
+/// A trailing-capture arm may also write one of `collect_map`, `collect_map_no_dup`,
+/// `collect_set`, `collect_set_no_dup` in place of the expression, e.g.
+/// `[entry(e)..] => collect_map_no_dup`. This gathers every remaining node with the rule's
+/// associated function and collects the result into whatever container the surrounding code
+/// expects, rejecting duplicate keys/values for the `_no_dup` variants. See
+/// [`advanced_features::collections`].
+///
+/// An arm without a trailing capture may also carry a `[pattern] if guard => expr` clause, like a
+/// Rust `match` guard: `guard` can refer to the pattern's bindings, and a falsy guard makes the
+/// whole arm fall through to the next one rather than erroring, exactly as if the pattern itself
+/// hadn't matched. This isn't supported together with a trailing `..` capture or a `collect_*`
+/// body.
+///
+/// A pattern may instead be written entirely in terms of tags, `[#tag_a => name_a(binding_a),
+/// #tag_b => name_b(binding_b)]`, to match against each node's [`#tag`](Node::tag) rather than its
+/// position in the sequence. This is for grammars where the same rule can show up through more
+/// than one alternative at different positions - tagging each occurrence (`#lhs = expr`) lets the
+/// pattern identify them without caring which one came first. Every node in the sequence must
+/// carry exactly one of the listed tags; a node with no tag, an unlisted tag, or a tag repeated
+/// makes the whole arm not match. This form can't be mixed with positional slots, `?`, `..`, a
+/// `collect_*` body, or a guard.
+///
+/// A pattern may also be written as one or more parenthesized groups separated by `|`, e.g.
+/// `[(expr(x), op(o)) | (literal(l))] => body`, when a rule has more than one production and
+/// handling them with separate arms would otherwise duplicate a large shared `body`. Each group is
+/// tried in turn as its own fixed sequence of required slots; the first one that fits wins. Every
+/// binding from every group is visible in `body`, typed as `Option<T>` - `Some` from whichever
+/// group matched, `None` from the rest - so `body` typically starts by matching on which bindings
+/// came back `Some`. Bindings must be plain identifiers here, since the same name is declared
+/// across every group. This form can't be mixed with positional items outside the groups, `?`,
+/// `..`, a `collect_*` body, a guard, or tags.
+///
+/// A pattern may also start and/or end with a bare `..`, e.g. `[.., ident(name), ..]`, to skip any
+/// number of unwanted nodes at that end rather than enumerate them: `[.., footer(f)]` allows (and
+/// ignores) any nodes before `footer`, `[header(h), ..]` allows any after `header`, and
+/// `[.., body(b), ..]` allows both. The wildcard only ever anchors an end of the pattern, never
+/// sits between two slots, so a match is still unambiguous; the slots between the wildcard(s) are
+/// matched as a contiguous run and bound exactly as usual. This is distinct from the trailing
+/// `name(binding)..` capture above: that one binds every skipped node into a `Vec`, while `..` here
+/// discards them. Not supported together with `?`, a `collect_*` body, a guard, tags, or groups.
+///
+/// A whole pattern may also be written `[rule_name(binding) sep sep_rule_name ..]` to match a run
+/// of `rule_name` nodes separated by `sep_rule_name` ones, e.g. a comma-separated list built in
+/// the grammar as `expr ~ (comma ~ expr)*`: `binding` is then a `Vec<T>` of every element's
+/// result, with the separators discarded rather than bound. An optional trailing separator is
+/// tolerated (`a, b, c,` matches the same as `a, b, c`), and an empty sequence matches as an empty
+/// `Vec`. This form can't be mixed with other items, `?`, a `collect_*` body, a guard, tags, or
+/// groups - see [`Nodes::match_separated_seq`].
+///
+/// If no arm matches, the resulting [`Error`] names the actual sequence of rules encountered
+/// (via [`Nodes::rules`]) and points at the sequence's parent span, rather than an opaque
+/// "nothing matched" - e.g. `no arm of match_nodes! matched this sequence of rules:
+/// [ident, ident]`.
+///
+/// A `rule_name(binding)` slot whose rule has no corresponding `Self::rule_name` function - a
+/// grammar rule added without ever writing its handler - is already an ordinary Rust compile
+/// error (`no function or associated item named \`rule_name\` found`) rather than anything
+/// `match_nodes!` has to detect itself: `Self::$name` is resolved like any other call, at the
+/// call site this expands to, so the error names the exact missing method. A rule dispatched
+/// dynamically instead of through `match_nodes!` - see
+/// [`advanced_features::extensible_dispatch`] - has no such compile-time check available, since
+/// there's no set of arms to check against; [`Node::error_no_consuming_method`] gives that case a
+/// consistent runtime error to fall back on.
+///
+/// If an earlier arm is a plain, unguarded sequence of required slots - `[a(x), b(y)] => ...`,
+/// with none of the forms above - and a later arm writes out that exact same rule sequence, the
+/// earlier arm always wins and the later one can never run. This is caught at compile time with a
+/// `compile_error!` naming the duplicated sequence, the same way Rust's own `match` flags an
+/// unreachable pattern. The other arm forms (`collect_*`, a guard, tags, groups, a wildcard, a
+/// separated run, or a `?`-optional slot) aren't compared this way, since "the same pattern" isn't
+/// as clear-cut for them.
+#[macro_export]
+macro_rules! match_nodes {
+    ($nodes:expr; $($arms:tt)*) => {
+        'match_nodes: {
+            #[allow(unused_mut)]
+            let mut __nodes = $nodes;
+            if let ::std::result::Result::Err(__depth_err) = __nodes.check_depth_limit() {
+                break 'match_nodes ::std::result::Result::Err(__depth_err);
+            }
+            if let ::std::result::Result::Err(__cancel_err) = __nodes.check_cancelled() {
+                break 'match_nodes ::std::result::Result::Err(__cancel_err);
+            }
+            if let ::std::result::Result::Err(__budget_err) = __nodes.check_node_budget() {
+                break 'match_nodes ::std::result::Result::Err(__budget_err);
+            }
+            $crate::match_nodes!(@check_dup []; $($arms)*);
+            $crate::match_nodes!(@arm 'match_nodes, __nodes; $($arms)*);
+            break 'match_nodes ::std::result::Result::Err(__nodes.error(format!(
+                "no arm of match_nodes! matched this sequence of rules: {:?}",
+                __nodes.rules(),
+            )));
+        }
+    };
+
+    // Walked once per invocation, independently of the `@arm` codegen below: for each arm written
+    // as a plain sequence of required slots with no guard, flag it if an *earlier* arm already
+    // covers the exact same rule sequence, since that earlier arm always matches first and this
+    // one could never run. Every other arm shape is skipped over untouched rather than compared -
+    // see the doc comment above for why. Mirrors `@arm`'s own arm-shape ordering below, since the
+    // same ambiguity between a `collect_*` keyword and a bare `$body:expr` applies here too.
+    (@check_dup $seen:tt; [$($items:tt)*] => collect_map $(, $($rest:tt)*)?) => {
+        $crate::match_nodes!(@check_dup $seen; $($($rest)*)?);
+    };
+    (@check_dup $seen:tt; [$($items:tt)*] => collect_map_no_dup $(, $($rest:tt)*)?) => {
+        $crate::match_nodes!(@check_dup $seen; $($($rest)*)?);
+    };
+    (@check_dup $seen:tt; [$($items:tt)*] => collect_set $(, $($rest:tt)*)?) => {
+        $crate::match_nodes!(@check_dup $seen; $($($rest)*)?);
+    };
+    (@check_dup $seen:tt; [$($items:tt)*] => collect_set_no_dup $(, $($rest:tt)*)?) => {
+        $crate::match_nodes!(@check_dup $seen; $($($rest)*)?);
+    };
+    (@check_dup $seen:tt; [$($items:tt)*] if $guard:expr => $body:expr $(, $($rest:tt)*)?) => {
+        $crate::match_nodes!(@check_dup $seen; $($($rest)*)?);
+    };
+    (@check_dup $seen:tt; [$(# $tag:ident => $name:ident ( $bind:pat )),+ $(,)?] => $body:expr $(, $($rest:tt)*)?) => {
+        $crate::match_nodes!(@check_dup $seen; $($($rest)*)?);
+    };
+    (@check_dup $seen:tt; [$(( $($name:ident ( $bind:ident )),+ $(,)? ))|+] => $body:expr $(, $($rest:tt)*)?) => {
+        $crate::match_nodes!(@check_dup $seen; $($($rest)*)?);
+    };
+    (@check_dup $seen:tt; [.., $($name:ident ( $bind:pat )),+ , ..] => $body:expr $(, $($rest:tt)*)?) => {
+        $crate::match_nodes!(@check_dup $seen; $($($rest)*)?);
+    };
+    (@check_dup $seen:tt; [.., $($name:ident ( $bind:pat )),+] => $body:expr $(, $($rest:tt)*)?) => {
+        $crate::match_nodes!(@check_dup $seen; $($($rest)*)?);
+    };
+    (@check_dup $seen:tt; [$($name:ident ( $bind:pat )),+ , ..] => $body:expr $(, $($rest:tt)*)?) => {
+        $crate::match_nodes!(@check_dup $seen; $($($rest)*)?);
+    };
+    (@check_dup $seen:tt; [$name:ident ( $bind:pat ) sep $sep:ident ..] => $body:expr $(, $($rest:tt)*)?) => {
+        $crate::match_nodes!(@check_dup $seen; $($($rest)*)?);
+    };
+    // Everything else reaching here is some arrangement of plain required/optional/literal slots
+    // with no trailing capture handled above (that's checked separately, since a trailing capture
+    // changes what "the same sequence" even means). Rather than re-deriving which slots are which
+    // kind, this hands the items off to `@items` itself with a `check_dup` final tag - the same
+    // item-by-item muncher the real codegen below uses - and lets `@items_final` pick the result
+    // back up once the kind of each slot is known; see there for why this is the right point to
+    // hook in rather than re-matching `$bind` against some fragment specifier by hand.
+    (@check_dup $seen:tt; [$($items:tt)*] => $body:expr $(, $($rest:tt)*)?) => {
+        $crate::match_nodes!(@items 'check_dup, __check_dup_unused; []; (check_dup $seen [$($($rest)*)?]); $($items)*);
+    };
+    (@check_dup $seen:tt;) => {};
+
+    // Checks the new signature against every one already in `$seen`, then recurses into
+    // `@check_dup` with `$seen` plus the new signature appended - `[$($old)*]` is spliced
+    // directly into the new bracketed list rather than computed by a helper macro, since a
+    // `macro_rules!` expansion can't be used as a value fed into another macro's arguments.
+    (@check_dup_append [$($old:tt)*]; [$($name:ident),+]; $($rest:tt)*) => {
+        $crate::match_nodes!(@check_dup_against [$($name),+]; [$($old)*]);
+        $crate::match_nodes!(@check_dup [$($old)* [$($name),+]]; $($rest)*);
+    };
+
+    (@check_dup_against $new:tt; [$([$($old:ident),+])*]) => {
+        $(
+            $crate::match_nodes!(@check_dup_cmp ($($old),+) $new);
+        )*
+    };
+    // `macro_rules!` has no built-in way to test whether two already-captured token sequences are
+    // equal, so this defines a throwaway macro whose first arm's pattern *is* the earlier
+    // sequence's literal tokens - spliced in at definition time, not matched as a fragment - then
+    // immediately invokes it with the later sequence. The fallback arm's pattern is just `$new`
+    // spliced in again, which trivially matches the very tokens it's invoked with whenever the
+    // first (exact-match) arm didn't already catch them - no fresh placeholder name needed.
+    (@check_dup_cmp ($($old:ident),+) [$($new:ident),+]) => {
+        macro_rules! __match_nodes_dup_check {
+            ($($old),+) => {
+                compile_error!(concat!(
+                    "match_nodes!: an earlier arm already matches the rule sequence `",
+                    stringify!($($old),+),
+                    "`; this arm can never be reached - merge the two bodies or remove the duplicate",
+                ));
+            };
+            ($($new),+) => {};
+        }
+        __match_nodes_dup_check!($($new),+);
+    };
+
+    // One arm's pattern is munged one item at a time (rather than with a single
+    // `$($fixed:ident(pat)),*`-style repetition) into a `(name, binding, kind)` accumulator,
+    // where `kind` is `req` or `opt` (a trailing `?`), plus a separate trailing slot for a
+    // final `..` capture if present. Item-at-a-time munging is what lets `?` and `..` slots
+    // live in the same pattern without the macro having to guess, from a bare repetition, how
+    // many items belong to a fixed prefix versus a final special one.
+    //
+    // The `collect_*` keywords are matched literally here, before the item list is captured as
+    // a `$body:expr`, exactly as a bareword like `collect_map` would otherwise also parse (and
+    // shadow) as an ordinary expression body. See `advanced_features::collections`.
+    (@arm $label:lifetime, $nodes:ident; [$($items:tt)*] => collect_map $(, $($rest:tt)*)?) => {
+        $crate::match_nodes!(@items $label, $nodes; []; (collect_map); $($items)*);
+        $($crate::match_nodes!(@arm $label, $nodes; $($rest)*);)?
+    };
+    (@arm $label:lifetime, $nodes:ident; [$($items:tt)*] => collect_map_no_dup $(, $($rest:tt)*)?) => {
+        $crate::match_nodes!(@items $label, $nodes; []; (collect_map_no_dup); $($items)*);
+        $($crate::match_nodes!(@arm $label, $nodes; $($rest)*);)?
+    };
+    (@arm $label:lifetime, $nodes:ident; [$($items:tt)*] => collect_set $(, $($rest:tt)*)?) => {
+        $crate::match_nodes!(@items $label, $nodes; []; (collect_set); $($items)*);
+        $($crate::match_nodes!(@arm $label, $nodes; $($rest)*);)?
+    };
+    (@arm $label:lifetime, $nodes:ident; [$($items:tt)*] => collect_set_no_dup $(, $($rest:tt)*)?) => {
+        $crate::match_nodes!(@items $label, $nodes; []; (collect_set_no_dup); $($items)*);
+        $($crate::match_nodes!(@arm $label, $nodes; $($rest)*);)?
+    };
+    // A guarded arm: `[pattern] if guard => expr`. The bind happens against a `fork()` of
+    // `$nodes` so that a failing guard leaves `$nodes` untouched for the next arm to try from
+    // the same position; only a passing guard commits the fork back into `$nodes`. Not supported
+    // together with a trailing `..` capture or a `collect_*` body - see `@items_final` below.
+    (@arm $label:lifetime, $nodes:ident; [$($items:tt)*] if $guard:expr => $body:expr $(, $($rest:tt)*)?) => {
+        $crate::match_nodes!(@items $label, $nodes; []; (guard_expr $guard, $body); $($items)*);
+        $($crate::match_nodes!(@arm $label, $nodes; $($rest)*);)?
+    };
+    // A tag-keyed arm: every item is `#tag => rule_name(binding)` instead of the usual positional
+    // `rule_name(binding)`, matched against [`Node::tag`] rather than sequence order - see
+    // `Nodes::match_tagged_seq`. Not supported mixed with positional items, `..`, `?`, or a
+    // `collect_*`/guard body; those still go through the ordinary arms below.
+    (@arm $label:lifetime, $nodes:ident; [$(# $tag:ident => $name:ident ( $bind:pat )),+ $(,)?] => $body:expr $(, $($rest:tt)*)?) => {
+        if let ::std::option::Option::Some(mut __matched) = $nodes.match_tagged_seq(&[
+            $((stringify!($tag), Rule::$name)),+
+        ]) {
+            let mut __matched = __matched.drain(..);
+            $(let $bind = Self::$name(__matched.next().unwrap())?;)+
+            break $label $body;
+        }
+        $($crate::match_nodes!(@arm $label, $nodes; $($rest)*);)?
+    };
+    // A whole-pattern alternation of parenthesized groups: `[(a(x), b(y)) | (c(z))] => body`. Each
+    // group is a fixed sequence of required slots, tried in turn against a fork of `$nodes` (so a
+    // group that doesn't fit leaves `$nodes` untouched for the next group, or the next arm if none
+    // fit); the first group whose shape fits consumes exactly those nodes. Every binding from
+    // every group is in scope in `$body` as an `Option<T>` - `Some` from whichever group actually
+    // matched, `None` from the rest - since bindings must be plain identifiers rather than
+    // arbitrary patterns (the same name has to exist in every group's branch). Not supported
+    // together with positional items outside the groups, `?`, `..`, a `collect_*` body, a guard,
+    // or tags.
+    (@arm $label:lifetime, $nodes:ident; [$(( $($name:ident ( $bind:ident )),+ $(,)? ))|+] => $body:expr $(, $($rest:tt)*)?) => {
+        $(
+            $(
+                #[allow(unused_mut)]
+                let mut $bind: ::std::option::Option<_> = ::std::option::Option::None;
+            )+
+        )+
+        $(
+            {
+                let mut __fork = $nodes.fork();
+                if let ::std::option::Option::Some(mut __matched) = __fork.match_optional_seq(&[
+                    $((Rule::$name, false)),+
+                ]) {
+                    let mut __matched = __matched.drain(..);
+                    $($bind = ::std::option::Option::Some(Self::$name(__matched.next().unwrap().expect("match_optional_seq: a required slot was reported as matched"))?);)+
+                    $nodes = __fork;
+                    break $label $body;
+                }
+            }
+        )+
+        $($crate::match_nodes!(@arm $label, $nodes; $($rest)*);)?
+    };
+    // A pattern with a leading and/or trailing bare `..` wildcard - `[.., ident(name), ..]` -
+    // matching a contiguous run of required slots anywhere the wildcard(s) allow, while discarding
+    // whatever else surrounds them. Unlike the trailing-capture `name(binding)..` above, the nodes
+    // a wildcard skips are never bound to anything, and a wildcard is only allowed at an end of the
+    // pattern (never between two slots), so which nodes it consumes stays unambiguous - see
+    // `Nodes::match_wildcard_seq`. Not supported together with `?`, a `collect_*`/guard body, tags,
+    // or groups.
+    (@arm $label:lifetime, $nodes:ident; [.., $($name:ident ( $bind:pat )),+ , ..] => $body:expr $(, $($rest:tt)*)?) => {
+        $crate::match_nodes!(@wildcard $label, $nodes; true, true; $($name ( $bind )),+; $body);
+        $($crate::match_nodes!(@arm $label, $nodes; $($rest)*);)?
+    };
+    (@arm $label:lifetime, $nodes:ident; [.., $($name:ident ( $bind:pat )),+] => $body:expr $(, $($rest:tt)*)?) => {
+        $crate::match_nodes!(@wildcard $label, $nodes; true, false; $($name ( $bind )),+; $body);
+        $($crate::match_nodes!(@arm $label, $nodes; $($rest)*);)?
+    };
+    (@arm $label:lifetime, $nodes:ident; [$($name:ident ( $bind:pat )),+ , ..] => $body:expr $(, $($rest:tt)*)?) => {
+        $crate::match_nodes!(@wildcard $label, $nodes; false, true; $($name ( $bind )),+; $body);
+        $($crate::match_nodes!(@arm $label, $nodes; $($rest)*);)?
+    };
+    (@wildcard $label:lifetime, $nodes:ident; $leading:expr, $trailing:expr; $($name:ident ( $bind:pat )),+; $body:expr) => {
+        if let ::std::option::Option::Some(mut __matched) = $nodes.match_wildcard_seq(
+            &[$(Rule::$name),+],
+            $leading,
+            $trailing,
+        ) {
+            let mut __matched = __matched.drain(..);
+            $(let $bind = Self::$name(__matched.next().unwrap())?;)+
+            break $label $body;
+        }
+    };
+    // A whole-pattern separated-run capture: `[expr(e) sep comma ..]`, matching a `sep`-separated
+    // run of `rule`, with an optional trailing `sep` tolerated. See `Nodes::match_separated_seq`.
+    // Not supported mixed with other items, `?`, a `collect_*`/guard body, tags, or groups.
+    (@arm $label:lifetime, $nodes:ident; [$name:ident ( $bind:pat ) sep $sep:ident ..] => $body:expr $(, $($rest:tt)*)?) => {
+        if let ::std::option::Option::Some(mut __matched) = $nodes.match_separated_seq(Rule::$name, Rule::$sep) {
+            let $bind = __matched
+                .drain(..)
+                .map(Self::$name)
+                .collect::<::std::result::Result<::std::vec::Vec<_>, _>>()?;
+            break $label $body;
+        }
+        $($crate::match_nodes!(@arm $label, $nodes; $($rest)*);)?
+    };
+    (@arm $label:lifetime, $nodes:ident; [$($items:tt)*] => $body:expr $(, $($rest:tt)*)?) => {
+        $crate::match_nodes!(@items $label, $nodes; []; (expr $body); $($items)*);
+        $($crate::match_nodes!(@arm $label, $nodes; $($rest)*);)?
+    };
+    (@arm $label:lifetime, $nodes:ident;) => {};
+
+    // Munch one `name(binding)`, `name(binding)?` or `name(binding)..` item off the front of the
+    // pattern. `..` is only accepted with nothing after it, since a trailing capture must be the
+    // last slot. Each pushed item is preceded by its own comma so the accumulator ends up a
+    // properly comma-separated list (with one leading comma to strip) rather than bare
+    // concatenated tuples, which `@items_final` below parses as a `$(,)? $(...),+` repetition.
+    (@items $label:lifetime, $nodes:ident; [$($acc:tt)*]; $final:tt; $name:ident ( $bind:pat ) ..) => {
+        $crate::match_nodes!(@dispatch_final $label, $nodes; [$($acc)*]; [($name, $bind)]; $final);
+    };
+    (@items $label:lifetime, $nodes:ident; [$($acc:tt)*]; $final:tt; $name:ident ( $bind:pat ) ? , $($rest:tt)*) => {
+        $crate::match_nodes!(@items $label, $nodes; [$($acc)* , ($name, $bind, opt)]; $final; $($rest)*);
+    };
+    (@items $label:lifetime, $nodes:ident; [$($acc:tt)*]; $final:tt; $name:ident ( $bind:pat ) ?) => {
+        $crate::match_nodes!(@dispatch_final $label, $nodes; [$($acc)* , ($name, $bind, opt)]; []; $final);
+    };
+    // A required slot written `name("literal")` instead of `name(binding)`: matched below by the
+    // `:literal` fragment specifier, which is tried before the generic `:pat` arms below it can
+    // claim the same tokens (a string literal also parses as an irrefutable-looking `:pat`, which
+    // would otherwise produce an uncompilable `let "literal" = ...`). See `@lit_check`/
+    // `@bind_stmt`, which are what actually give this `lit` kind different treatment from `req`.
+    (@items $label:lifetime, $nodes:ident; [$($acc:tt)*]; $final:tt; $name:ident ( $lit:literal ) , $($rest:tt)*) => {
+        $crate::match_nodes!(@items $label, $nodes; [$($acc)* , ($name, $lit, lit)]; $final; $($rest)*);
+    };
+    (@items $label:lifetime, $nodes:ident; [$($acc:tt)*]; $final:tt; $name:ident ( $lit:literal )) => {
+        $crate::match_nodes!(@dispatch_final $label, $nodes; [$($acc)* , ($name, $lit, lit)]; []; $final);
+    };
+    (@items $label:lifetime, $nodes:ident; [$($acc:tt)*]; $final:tt; $name:ident ( $bind:pat ) , $($rest:tt)*) => {
+        $crate::match_nodes!(@items $label, $nodes; [$($acc)* , ($name, $bind, req)]; $final; $($rest)*);
+    };
+    (@items $label:lifetime, $nodes:ident; [$($acc:tt)*]; $final:tt; $name:ident ( $bind:pat )) => {
+        $crate::match_nodes!(@dispatch_final $label, $nodes; [$($acc)* , ($name, $bind, req)]; []; $final);
+    };
+    (@items $label:lifetime, $nodes:ident; [$($acc:tt)*]; $final:tt;) => {
+        $crate::match_nodes!(@dispatch_final $label, $nodes; [$($acc)*]; []; $final);
+    };
+
+    // Looks for a literal-kind slot (see `@items` above) in the finished accumulator and, only
+    // if one is there, reroutes to `@items_final_lit` instead of the plain `@items_final`. Every
+    // other arm keeps going straight to `@items_final`, with the exact same code it always
+    // generated - deep recursive grammars (see `tests/depth_limit.rs`'s `unbounded_by_default`)
+    // depend on the common req/opt-only path staying as frame-cheap as it was before literal
+    // slots existed, which is why that path isn't routed through the fork `@items_final_lit` uses.
+    (@dispatch_final $label:lifetime, $nodes:ident; [$($acc:tt)*]; $trailing:tt; (expr $body:expr)) => {
+        $crate::match_nodes!(@scan_lit $label, $nodes; [$($acc)*]; $trailing; (expr $body); $($acc)*);
+    };
+    (@dispatch_final $label:lifetime, $nodes:ident; [$($acc:tt)*]; $trailing:tt; $final:tt) => {
+        $crate::match_nodes!(@items_final $label, $nodes; [$($acc)*]; $trailing; $final);
+    };
+    (@scan_lit $label:lifetime, $nodes:ident; [$($acc:tt)*]; $trailing:tt; $final:tt; , ($name:ident, $bind:pat, lit) $($rest:tt)*) => {
+        $crate::match_nodes!(@items_final_lit $label, $nodes; [$($acc)*]; $trailing; $final);
+    };
+    (@scan_lit $label:lifetime, $nodes:ident; [$($acc:tt)*]; $trailing:tt; $final:tt; , ($name:ident, $bind:pat, $kind:ident) $($rest:tt)*) => {
+        $crate::match_nodes!(@scan_lit $label, $nodes; [$($acc)*]; $trailing; $final; $($rest)*);
+    };
+    (@scan_lit $label:lifetime, $nodes:ident; [$($acc:tt)*]; $trailing:tt; $final:tt;) => {
+        $crate::match_nodes!(@items_final $label, $nodes; [$($acc)*]; $trailing; $final);
+    };
+
+    // `@check_dup`'s arm-shape check, continued: one or more slots, every one of them a plain
+    // required binding (the literal `req` here, rather than a `$kind:ident` capture, is what
+    // requires *every* slot to be `req` - an `opt` or `lit` slot anywhere makes this whole arm
+    // fail to match, falling through to the catch-all just below), and no trailing capture. This
+    // is the only shape actually compared for duplicates; see the doc comment on `match_nodes!`.
+    (@items_final $label:lifetime, $nodes:ident; [$(,)? $(($name:ident, $bind:pat, req)),+]; []; (check_dup $seen:tt [$($rest:tt)*])) => {
+        $crate::match_nodes!(@check_dup_append $seen; [$($name),+]; $($rest)*);
+    };
+    // Anything else reaching `@check_dup` by way of `@items` - an optional or literal slot, a
+    // trailing capture, or an empty pattern - isn't compared; placed ahead of the pre-existing
+    // trailing-capture arms below so a `check_dup` tag never reaches `@trailing`, which has no arm
+    // for it.
+    (@items_final $label:lifetime, $nodes:ident; [$($acc:tt)*]; $trailing:tt; (check_dup $seen:tt [$($rest:tt)*])) => {
+        $crate::match_nodes!(@check_dup $seen; $($rest)*);
+    };
+
+    // Base case: an empty pattern, `[] => ...`, matches only an empty sequence.
+    (@items_final $label:lifetime, $nodes:ident; []; []; (expr $body:expr)) => {
+        if $nodes.is_empty() {
+            break $label $body;
+        }
+    };
+    // One or more required/optional slots, with no trailing capture and no literal slot among
+    // them (see `@dispatch_final`/`@items_final_lit` otherwise).
+    (@items_final $label:lifetime, $nodes:ident; [$(,)? $(($name:ident, $bind:pat, $kind:ident)),+]; []; (expr $body:expr)) => {
+        if let ::std::option::Option::Some(mut __matched) = $nodes.match_optional_seq(&[
+            $((Rule::$name, $crate::match_nodes!(@is_opt $kind))),+
+        ]) {
+            let mut __matched = __matched.drain(..);
+            $(let $bind = $crate::match_nodes!(@bind $kind, $name, __matched.next().unwrap())?;)+
+            break $label $body;
+        }
+    };
+    // Same shape as the arm just above, but for a pattern containing at least one literal slot
+    // (`name("literal")`): matched on a fork, like the guarded arm below, so that a literal slot
+    // whose content doesn't match (see `@lit_check`) falls through to the next arm instead of
+    // erroring - a shape match alone isn't enough to commit here.
+    (@items_final_lit $label:lifetime, $nodes:ident; [$(,)? $(($name:ident, $bind:pat, $kind:ident)),+]; []; (expr $body:expr)) => {
+        let mut __fork = $nodes.fork();
+        if let ::std::option::Option::Some(__matched) = __fork.match_optional_seq(&[
+            $((Rule::$name, $crate::match_nodes!(@is_opt $kind))),+
+        ]) {
+            let mut __lit_check = __matched.iter();
+            #[allow(unused_mut)]
+            let __lits_match = true
+                $(&& $crate::match_nodes!(@lit_check $kind, $bind, __lit_check.next().unwrap()))+;
+            if __lits_match {
+                $nodes = __fork;
+                let mut __matched = __matched.into_iter();
+                $($crate::match_nodes!(@bind_stmt $kind, $bind, $name, __matched.next().unwrap());)+
+                break $label $body;
+            }
+        }
+    };
+    // Only a trailing capture, with no required/optional slots before it. Wrapped in `if true`
+    // (rather than breaking unconditionally) purely so the compiler doesn't flag whatever
+    // follows this arm - the final fallback `Err`, or a later arm - as unreachable; there's no
+    // prefix to check here.
+    (@items_final $label:lifetime, $nodes:ident; []; [($tname:ident, $tbind:pat)]; $final:tt) => {
+        if true {
+            $crate::match_nodes!(@trailing $label, $nodes, $tname, $tbind, $final);
+        }
+    };
+    // A non-empty required/optional prefix, followed by a trailing capture.
+    (@items_final $label:lifetime, $nodes:ident; [$(,)? $(($name:ident, $bind:pat, $kind:ident)),+]; [($tname:ident, $tbind:pat)]; $final:tt) => {
+        if let ::std::option::Option::Some(mut __matched) = $nodes.match_optional_seq(&[
+            $((Rule::$name, $crate::match_nodes!(@is_opt $kind))),+
+        ]) {
+            let mut __matched = __matched.drain(..);
+            $(let $bind = $crate::match_nodes!(@bind $kind, $name, __matched.next().unwrap())?;)+
+            $crate::match_nodes!(@trailing $label, $nodes, $tname, $tbind, $final);
+        }
+    };
+
+    // Guarded base case: an empty pattern has nothing to fork, since checking `is_empty` doesn't
+    // consume anything.
+    (@items_final $label:lifetime, $nodes:ident; []; []; (guard_expr $guard:expr, $body:expr)) => {
+        if $nodes.is_empty() && ($guard) {
+            break $label $body;
+        }
+    };
+    // Guarded prefix, no trailing capture: bind against a fork, and only commit the fork back
+    // into `$nodes` - advancing past the matched nodes - once the guard passes. A failing guard
+    // (or a shape mismatch) leaves `$nodes` exactly as the next arm will see it.
+    (@items_final $label:lifetime, $nodes:ident; [$(,)? $(($name:ident, $bind:pat, $kind:ident)),+]; []; (guard_expr $guard:expr, $body:expr)) => {
+        let mut __fork = $nodes.fork();
+        if let ::std::option::Option::Some(mut __matched) = __fork.match_optional_seq(&[
+            $((Rule::$name, $crate::match_nodes!(@is_opt $kind))),+
+        ]) {
+            let mut __matched = __matched.drain(..);
+            $(let $bind = $crate::match_nodes!(@bind $kind, $name, __matched.next().unwrap())?;)+
+            if $guard {
+                $nodes = __fork;
+                break $label $body;
+            }
+        }
+    };
+
+    (@trailing $label:lifetime, $nodes:ident, $tname:ident, $tbind:pat, (expr $body:expr)) => {
+        let $tbind = $nodes.map_to_vec(Self::$tname)?;
+        break $label $body;
+    };
+    (@trailing $label:lifetime, $nodes:ident, $tname:ident, $tbind:pat, (collect_map)) => {
+        break $label $nodes.collect_map(Self::$tname).map(|__v| __v.into_iter().collect());
+    };
+    (@trailing $label:lifetime, $nodes:ident, $tname:ident, $tbind:pat, (collect_map_no_dup)) => {
+        break $label $nodes.collect_map_no_dup(Self::$tname).map(|__v| __v.into_iter().collect());
+    };
+    (@trailing $label:lifetime, $nodes:ident, $tname:ident, $tbind:pat, (collect_set)) => {
+        break $label $nodes.collect_set(Self::$tname).map(|__v| __v.into_iter().collect());
+    };
+    (@trailing $label:lifetime, $nodes:ident, $tname:ident, $tbind:pat, (collect_set_no_dup)) => {
+        break $label $nodes.collect_set_no_dup(Self::$tname).map(|__v| __v.into_iter().collect());
+    };
+
+    (@is_opt req) => { false };
+    (@is_opt opt) => { true };
+    (@is_opt lit) => { false };
+
+    (@bind opt, $name:ident, $node:expr) => {
+        match $node {
+            ::std::option::Option::Some(__n) => ::std::result::Result::Ok(::std::option::Option::Some(Self::$name(__n)?)),
+            ::std::option::Option::None => ::std::result::Result::Ok(::std::option::Option::None),
+        }
+    };
+    (@bind req, $name:ident, $node:expr) => {
+        Self::$name($node.expect("match_optional_seq: a required slot was reported as matched"))
+    };
+
+    // Whether a slot's matched node (an `&Option<Node>`, not yet consumed) satisfies its literal
+    // requirement, if it has one - `req`/`opt` slots have nothing to check. `$node` is always
+    // evaluated (even when the kind doesn't care) so that the shared `__lit_check` iterator stays
+    // in lockstep across slots of every kind.
+    (@lit_check req, $bind:pat, $node:expr) => {{ let _ = $node; true }};
+    (@lit_check opt, $bind:pat, $node:expr) => {{ let _ = $node; true }};
+    (@lit_check lit, $lit:pat, $node:expr) => {{
+        match $node {
+            ::std::option::Option::Some(__n) => ::std::matches!(__n.as_str(), $lit),
+            ::std::option::Option::None => false,
+        }
+    }};
+
+    // The statement that turns a slot's matched node into its binding, once every literal check
+    // in the arm has already passed - a `lit` slot has no binding to produce, just the node to
+    // discard (its content was already checked by `@lit_check`).
+    (@bind_stmt req, $bind:pat, $name:ident, $node:expr) => {
+        let $bind = $crate::match_nodes!(@bind req, $name, $node)?;
+    };
+    (@bind_stmt opt, $bind:pat, $name:ident, $node:expr) => {
+        let $bind = $crate::match_nodes!(@bind opt, $name, $node)?;
+    };
+    (@bind_stmt lit, $lit:pat, $name:ident, $node:expr) => {
+        let _ = $node;
+    };
+}
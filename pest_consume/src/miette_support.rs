@@ -0,0 +1,61 @@
+//! The [`MietteError`] type described in [`crate::advanced_features::miette_diagnostics`].
+
+use std::fmt;
+
+use pest::error::InputLocation;
+use pest::RuleType;
+
+use crate::Error;
+
+/// An [`Error`] together with the source text it was parsed from, so it can be reported as a
+/// [`miette::Diagnostic`] with an underlined, in-context span. Build one with
+/// [`IntoMietteError::with_source`].
+#[derive(Debug)]
+pub struct MietteError<R: RuleType> {
+    error: Error<R>,
+    source: String,
+}
+
+impl<R: RuleType> fmt::Display for MietteError<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.error, f)
+    }
+}
+
+impl<R: RuleType> std::error::Error for MietteError<R> {}
+
+impl<R: RuleType> miette::Diagnostic for MietteError<R> {
+    fn source_code(&self) -> Option<&dyn miette::SourceCode> {
+        Some(&self.source)
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = miette::LabeledSpan> + '_>> {
+        let message = self.error.variant.message().into_owned();
+        let (offset, len) = match self.error.location {
+            InputLocation::Pos(pos) => (pos, 0),
+            InputLocation::Span((start, end)) => (start, end - start),
+        };
+        Some(Box::new(std::iter::once(miette::LabeledSpan::new(
+            Some(message),
+            offset,
+            len,
+        ))))
+    }
+}
+
+/// Extension trait attaching the source text an [`Error`] came from, so it can be converted into
+/// a [`miette::Diagnostic`]. See [`advanced_features::miette_diagnostics`](crate::advanced_features::miette_diagnostics).
+pub trait IntoMietteError<R: RuleType> {
+    /// Attach `source` - the same string that was passed to [`Parser::parse`](crate::Parser::parse)
+    /// or one of its siblings - producing a value that implements [`miette::Diagnostic`].
+    fn with_source(self, source: impl Into<String>) -> MietteError<R>;
+}
+
+impl<R: RuleType> IntoMietteError<R> for Error<R> {
+    fn with_source(self, source: impl Into<String>) -> MietteError<R> {
+        MietteError {
+            error: self,
+            source: source.into(),
+        }
+    }
+}
@@ -0,0 +1,52 @@
+//! The [`OwnedNodes`] type described in [`crate::advanced_features::owned_parsing`].
+
+use ouroboros::self_referencing;
+use pest::RuleType;
+
+use crate::Nodes;
+
+/// Bundles an owned `String` together with the [`Nodes`] parsed from it, so the two can be passed
+/// and stored as a single value instead of the caller having to keep the original `String` alive
+/// itself everywhere a borrowed [`Nodes`] is needed. Build one with
+/// [`Parser::parse_owned`](crate::Parser::parse_owned).
+///
+/// The [`Nodes`] can only be taken out and consumed once, via [`OwnedNodes::consume`] - see its
+/// docs for why.
+#[self_referencing(pub_extras)]
+pub struct OwnedNodes<R: RuleType + 'static> {
+    input: String,
+    #[borrows(input)]
+    #[not_covariant]
+    nodes: Option<Nodes<'this, R>>,
+}
+
+impl<R: RuleType + 'static> OwnedNodes<R> {
+    /// The input this was parsed from.
+    pub fn input(&self) -> &str {
+        self.borrow_input()
+    }
+
+    /// Run `f` on the [`Nodes`] parsed from [`input`](Self::input), returning whatever owned value
+    /// it produces.
+    ///
+    /// This takes the [`Nodes`] out of `self` to hand to `f` by value - the same way a plain
+    /// [`Parser::parse`](crate::Parser::parse) call's result would be consumed - rather than only
+    /// offering `&Nodes`/`&mut Nodes`, since most of this crate's consuming methods (starting with
+    /// [`match_nodes!`](crate::match_nodes), and including [`Nodes::single`],
+    /// [`Node::into_children`](crate::Node::into_children), and friends) take `self` by value.
+    /// That's also why this can only be called once per `OwnedNodes`: the `Nodes` borrows
+    /// [`input`](Self::input), so it can't be handed back afterwards without giving `f` a way to
+    /// smuggle a reference into it back out past `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called more than once on the same `OwnedNodes`.
+    pub fn consume<T>(&mut self, f: impl FnOnce(Nodes<'_, R>) -> T) -> T {
+        self.with_nodes_mut(|nodes| {
+            let nodes = nodes
+                .take()
+                .expect("OwnedNodes::consume can only be called once");
+            f(nodes)
+        })
+    }
+}
@@ -0,0 +1,109 @@
+//! The `Nodes::pratt_climb` method described in [`crate::advanced_features::pratt_parsing`].
+
+use pest::iterators::Pair;
+use pest::pratt_parser::PrattParser;
+use pest::RuleType;
+use std::rc::Rc;
+
+use crate::{Error, Node, Nodes};
+
+impl<'i, R: RuleType, D: Clone, Ctx> Nodes<'i, R, D, Ctx> {
+    /// Runs [pest]'s Pratt-parsing algorithm over this child sequence, which must be shaped as
+    /// `prefix* ~ primary ~ postfix* ~ (infix ~ prefix* ~ primary ~ postfix*)*`, as described by
+    /// [`pest::pratt_parser::PrattParser`]. Unlike [`Nodes::prec_climb`], this also handles prefix
+    /// and postfix operators, not just infix ones.
+    ///
+    /// `primary` converts a primary [`Node`] into `T`. `prefix`/`postfix`/`infix` each fold an
+    /// operator [`Node`] together with its already-converted operand(s); pass `None` for any
+    /// affix `pratt`'s table doesn't define. A fold only runs once every sub-expression beneath it
+    /// has already been converted, so an `Err` from deep inside the tree is simply threaded
+    /// outward by the closures above it rather than triggering more work.
+    ///
+    /// # Panics
+    ///
+    /// Panics under the same conditions as [`pest::pratt_parser::PrattParserMap::parse`] - most
+    /// notably, if this sequence is empty.
+    ///
+    /// [pest]: https://pest.rs
+    pub fn pratt_climb<T>(
+        self,
+        pratt: &PrattParser<R>,
+        mut primary: impl FnMut(Node<'i, R, D, Ctx>) -> Result<T, Error<R>>,
+        mut prefix: Option<impl FnMut(Node<'i, R, D, Ctx>, Result<T, Error<R>>) -> Result<T, Error<R>>>,
+        mut postfix: Option<impl FnMut(Result<T, Error<R>>, Node<'i, R, D, Ctx>) -> Result<T, Error<R>>>,
+        mut infix: Option<
+            impl FnMut(Result<T, Error<R>>, Node<'i, R, D, Ctx>, Result<T, Error<R>>) -> Result<T, Error<R>>,
+        >,
+    ) -> Result<T, Error<R>> {
+        let Nodes {
+            pairs,
+            user_data,
+            context,
+            context_lock,
+            errors,
+            warnings,
+            parent_link,
+            depth,
+            max_depth,
+            max_nodes,
+            node_count,
+            skip_rules,
+            cancel_token,
+            coverage,
+            path,
+            trivia,
+            ..
+        } = self;
+        let to_node = move |pair: Pair<'i, R>| {
+            if let Some(coverage) = coverage {
+                // Safety: see `Nodes::next_node` - the same reasoning applies here, since
+                // `pratt_climb` is the only other place that turns a `Pair` into a `Node`.
+                unsafe { (*coverage).insert(pair.as_rule()) };
+            }
+            if let Some(node_count) = &node_count {
+                node_count.set(node_count.get() + 1);
+            }
+            Node {
+                pair,
+                user_data: user_data.clone(),
+                context,
+                context_lock: Rc::clone(&context_lock),
+                errors,
+                warnings,
+                parent_link: parent_link.clone(),
+                depth,
+                max_depth,
+                max_nodes,
+                node_count: node_count.clone(),
+                skip_rules: skip_rules.clone(),
+                // `pratt_climb` reshuffles nodes into a new expression tree rather than handing
+                // them out in the original sequence's order, so there's no sensible sibling index
+                // to give them - see `Node::sibling_index`.
+                sibling_index: None,
+                cancel_token,
+                coverage,
+                path: path.clone(),
+                trivia,
+            }
+        };
+        let result = pratt
+            .map_primary(|pair| primary(to_node(pair)))
+            .map_prefix(|pair, rhs| match prefix.as_mut() {
+                Some(prefix) => prefix(to_node(pair), rhs),
+                None => Err(to_node(pair)
+                    .error("pratt_climb: grammar uses a prefix operator, but no `prefix` closure was given")),
+            })
+            .map_postfix(|lhs, pair| match postfix.as_mut() {
+                Some(postfix) => postfix(lhs, to_node(pair)),
+                None => Err(to_node(pair)
+                    .error("pratt_climb: grammar uses a postfix operator, but no `postfix` closure was given")),
+            })
+            .map_infix(|lhs, pair, rhs| match infix.as_mut() {
+                Some(infix) => infix(lhs, to_node(pair), rhs),
+                None => Err(to_node(pair)
+                    .error("pratt_climb: grammar uses an infix operator, but no `infix` closure was given")),
+            })
+            .parse(pairs);
+        result
+    }
+}
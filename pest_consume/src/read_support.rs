@@ -0,0 +1,29 @@
+//! The [`ReadError`] type described in [`crate::advanced_features::streaming_reads`].
+
+use std::fmt;
+use std::io;
+
+use pest::RuleType;
+
+use crate::Error;
+
+/// Either the read from a [`Read`](std::io::Read) source failed, or the buffered text it produced
+/// failed to parse. Returned by [`Parser::parse_from_reader`](crate::Parser::parse_from_reader).
+#[derive(Debug)]
+pub enum ReadError<R: RuleType> {
+    /// Reading from the source failed before a full parse was attempted.
+    Io(io::Error),
+    /// The text read from the source failed to parse.
+    Parse(Error<R>),
+}
+
+impl<R: RuleType> fmt::Display for ReadError<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReadError::Io(e) => write!(f, "{e}"),
+            ReadError::Parse(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl<R: RuleType> std::error::Error for ReadError<R> {}
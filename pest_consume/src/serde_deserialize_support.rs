@@ -0,0 +1,376 @@
+//! [`serde::Deserializer`] for [`Node`], as described in
+//! [`crate::advanced_features::serde_deserialization`].
+
+use std::collections::HashMap;
+use std::fmt;
+
+use pest::RuleType;
+use serde::de::{self, DeserializeSeed, Deserializer, IntoDeserializer, MapAccess, SeqAccess, Visitor};
+
+use crate::{Node, Nodes};
+
+/// What [`Node`]'s [`Deserializer`] impl reports for a node shape the target type can't be built
+/// from - more than one matching child for a scalar/struct field, text that doesn't parse as the
+/// requested number, or an enum (not supported - see
+/// [`crate::advanced_features::serde_deserialization`]).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeserializeError(String);
+
+impl fmt::Display for DeserializeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for DeserializeError {}
+
+impl de::Error for DeserializeError {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        DeserializeError(msg.to_string())
+    }
+}
+
+/// Drives a [`Visitor`]'s `visit_seq`/`visit_map` from any iterator of [`Node`]s - the children
+/// of one [`Node`] for [`Node::deserialize_seq`]/[`Node::deserialize_map`], or the matches
+/// collected for one field for [`NodeGroup::deserialize_seq`].
+struct NodeSeqAccess<I> {
+    nodes: I,
+}
+
+impl<'i, R: RuleType, D: Clone, Ctx, I> SeqAccess<'i> for NodeSeqAccess<I>
+where
+    I: Iterator<Item = Node<'i, R, D, Ctx>>,
+{
+    type Error = DeserializeError;
+
+    fn next_element_seed<T: DeserializeSeed<'i>>(&mut self, seed: T) -> Result<Option<T::Value>, Self::Error> {
+        match self.nodes.next() {
+            Some(node) => seed.deserialize(node).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        match self.nodes.size_hint() {
+            (lower, Some(upper)) if lower == upper => Some(upper),
+            _ => None,
+        }
+    }
+}
+
+/// [`MapAccess`] for [`Node::deserialize_map`]: every child becomes one entry, keyed by its rule
+/// name (the same `{:?}`-formatted name [`advanced_features::serde_serialization`] uses), valued
+/// by the child itself. Unlike [`NodeFieldMapAccess`], nothing is grouped - a repeated rule just
+/// produces repeated keys, left to the target map type to handle (most overwrite on duplicate
+/// keys, the same as any other source with repeated keys).
+///
+/// [`advanced_features::serde_serialization`]: crate::advanced_features::serde_serialization
+struct NodeMapAccess<'i, R, D, Ctx> {
+    nodes: Nodes<'i, R, D, Ctx>,
+    next_value: Option<Node<'i, R, D, Ctx>>,
+}
+
+impl<'i, R: RuleType, D: Clone, Ctx> MapAccess<'i> for NodeMapAccess<'i, R, D, Ctx> {
+    type Error = DeserializeError;
+
+    fn next_key_seed<K: DeserializeSeed<'i>>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error> {
+        let Some(node) = self.nodes.next_node() else {
+            return Ok(None);
+        };
+        let rule_name = format!("{:?}", node.as_rule());
+        self.next_value = Some(node);
+        seed.deserialize(rule_name.into_deserializer()).map(Some)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'i>>(&mut self, seed: V) -> Result<V::Value, Self::Error> {
+        let node = self
+            .next_value
+            .take()
+            .expect("serde always calls next_value_seed right after next_key_seed");
+        seed.deserialize(node)
+    }
+}
+
+/// [`MapAccess`] for [`Node::deserialize_struct`]: walks `fields` in the order the target struct
+/// declared them, with every field present regardless of how many children matched it - zero,
+/// one, or more than one are each meaningful to some field shape, and [`NodeFieldMapAccess`]
+/// doesn't know which shape a field's type asks for until [`NodeGroup`]'s `deserialize_*` is
+/// actually called, so it always hands one back rather than guessing from the count alone. A
+/// field present more than once is the same variadic-children idea [`match_nodes!`]'s trailing
+/// `name(bind)..` captures, reached here through [`serde`] instead of the macro.
+///
+/// [`match_nodes!`]: crate::match_nodes
+struct NodeFieldMapAccess<'i, R, D, Ctx> {
+    fields: std::slice::Iter<'static, &'static str>,
+    groups: HashMap<String, Vec<Node<'i, R, D, Ctx>>>,
+    current_field: Option<&'static str>,
+}
+
+impl<'i, R: RuleType, D: Clone, Ctx> MapAccess<'i> for NodeFieldMapAccess<'i, R, D, Ctx> {
+    type Error = DeserializeError;
+
+    fn next_key_seed<K: DeserializeSeed<'i>>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error> {
+        let Some(field) = self.fields.next() else {
+            return Ok(None);
+        };
+        self.current_field = Some(field);
+        seed.deserialize((*field).into_deserializer()).map(Some)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'i>>(&mut self, seed: V) -> Result<V::Value, Self::Error> {
+        let field = self
+            .current_field
+            .take()
+            .expect("serde always calls next_value_seed right after next_key_seed");
+        let nodes = self.groups.remove(field).unwrap_or_default();
+        seed.deserialize(NodeGroup { field, nodes })
+    }
+}
+
+/// Every child [`NodeFieldMapAccess`] found for one field, deserialized as the value behind that
+/// field's key. Exactly one match deserializes exactly like that child [`Node`] would on its
+/// own, so a plain (non-`Vec`, non-`Option`) field backed by one matching child works with no
+/// extra ceremony. Zero or more than one is only meaningful for
+/// [`deserialize_seq`](Self::deserialize_seq) (a `Vec<T>` field, collecting however many there
+/// are, including none) and [`deserialize_option`](Self::deserialize_option) (`None` for zero,
+/// `Some` for exactly one, an error for more than one) - every other method needs exactly one
+/// match to delegate to, and errors naming `field` otherwise.
+struct NodeGroup<'i, R, D, Ctx> {
+    field: &'static str,
+    nodes: Vec<Node<'i, R, D, Ctx>>,
+}
+
+impl<'i, R: RuleType, D: Clone, Ctx> NodeGroup<'i, R, D, Ctx> {
+    /// The group's one matching child, or an error naming `field` and how many actually matched -
+    /// every method but `deserialize_seq`/`deserialize_option` needs exactly one to delegate to.
+    fn single(self) -> Result<Node<'i, R, D, Ctx>, DeserializeError> {
+        match <[_; 1]>::try_from(self.nodes) {
+            Ok([node]) => Ok(node),
+            Err(nodes) => Err(DeserializeError(format!(
+                "expected exactly one node for field `{}`, found {} - for anything other than \
+                 exactly one, the field needs to be an Option<_> or a Vec<_>",
+                self.field,
+                nodes.len()
+            ))),
+        }
+    }
+}
+
+/// Delegates `$method` straight to this group's one matching child, via [`NodeGroup::single`].
+macro_rules! delegate_to_single {
+    ($($method:ident ( $($arg:ident : $argty:ty),* )),+ $(,)?) => {
+        $(
+            fn $method<V: Visitor<'i>>(self, $($arg: $argty,)* visitor: V) -> Result<V::Value, Self::Error> {
+                self.single()?.$method($($arg,)* visitor)
+            }
+        )+
+    };
+}
+
+impl<'i, R: RuleType, D: Clone, Ctx> Deserializer<'i> for NodeGroup<'i, R, D, Ctx> {
+    type Error = DeserializeError;
+
+    delegate_to_single! {
+        deserialize_any(),
+        deserialize_bool(),
+        deserialize_i8(), deserialize_i16(), deserialize_i32(), deserialize_i64(), deserialize_i128(),
+        deserialize_u8(), deserialize_u16(), deserialize_u32(), deserialize_u64(), deserialize_u128(),
+        deserialize_f32(), deserialize_f64(),
+        deserialize_char(), deserialize_str(), deserialize_string(),
+        deserialize_bytes(), deserialize_byte_buf(),
+        deserialize_unit(),
+        deserialize_unit_struct(name: &'static str),
+        deserialize_newtype_struct(name: &'static str),
+        deserialize_tuple(len: usize),
+        deserialize_tuple_struct(name: &'static str, len: usize),
+        deserialize_map(),
+        deserialize_struct(name: &'static str, fields: &'static [&'static str]),
+        deserialize_enum(name: &'static str, variants: &'static [&'static str]),
+        deserialize_identifier(),
+        deserialize_ignored_any(),
+    }
+
+    fn deserialize_option<V: Visitor<'i>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        if self.nodes.is_empty() {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self.single()?)
+        }
+    }
+
+    fn deserialize_seq<V: Visitor<'i>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_seq(NodeSeqAccess { nodes: self.nodes.into_iter() })
+    }
+}
+
+macro_rules! deserialize_scalar {
+    ($($method:ident => $visit:ident : $ty:ty),+ $(,)?) => {
+        $(
+            fn $method<V: Visitor<'i>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+                let text = self.as_str();
+                let value: $ty = text.parse().map_err(|e| {
+                    de::Error::custom(format!("couldn't parse {text:?} as {}: {e}", stringify!($ty)))
+                })?;
+                visitor.$visit(value)
+            }
+        )+
+    };
+}
+
+impl<'i, R: RuleType, D: Clone, Ctx> Deserializer<'i> for Node<'i, R, D, Ctx> {
+    type Error = DeserializeError;
+
+    /// No hint to go on beyond the node's own shape: a node with children is deserialized as a
+    /// [`seq`](Self::deserialize_seq), one with none as a [`str`](Self::deserialize_str) - the two
+    /// cases [`advanced_features::serde_deserialization`] documents as working with no type hint
+    /// at all, e.g. deserializing straight into `serde_json::Value`.
+    ///
+    /// [`advanced_features::serde_deserialization`]: crate::advanced_features::serde_deserialization
+    fn deserialize_any<V: Visitor<'i>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        if self.children_ref().next_node().is_some() {
+            self.deserialize_seq(visitor)
+        } else {
+            self.deserialize_str(visitor)
+        }
+    }
+
+    deserialize_scalar! {
+        deserialize_bool => visit_bool: bool,
+        deserialize_i8 => visit_i8: i8,
+        deserialize_i16 => visit_i16: i16,
+        deserialize_i32 => visit_i32: i32,
+        deserialize_i64 => visit_i64: i64,
+        deserialize_i128 => visit_i128: i128,
+        deserialize_u8 => visit_u8: u8,
+        deserialize_u16 => visit_u16: u16,
+        deserialize_u32 => visit_u32: u32,
+        deserialize_u64 => visit_u64: u64,
+        deserialize_u128 => visit_u128: u128,
+        deserialize_f32 => visit_f32: f32,
+        deserialize_f64 => visit_f64: f64,
+    }
+
+    fn deserialize_char<V: Visitor<'i>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        let mut chars = self.as_str().chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => visitor.visit_char(c),
+            _ => Err(de::Error::custom(format!(
+                "expected exactly one character, found {:?}",
+                self.as_str()
+            ))),
+        }
+    }
+
+    fn deserialize_str<V: Visitor<'i>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_borrowed_str(self.as_str())
+    }
+
+    fn deserialize_string<V: Visitor<'i>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_bytes<V: Visitor<'i>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_borrowed_bytes(self.as_str().as_bytes())
+    }
+
+    fn deserialize_byte_buf<V: Visitor<'i>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        self.deserialize_bytes(visitor)
+    }
+
+    /// A node always exists, so it's always `Some` - whether a field counts as absent (zero
+    /// matching children) is decided one level up, by `NodeGroup::deserialize_option`, which never
+    /// reaches this impl in that case.
+    fn deserialize_option<V: Visitor<'i>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_unit<V: Visitor<'i>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'i>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.deserialize_unit(visitor)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'i>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    /// Every child becomes one sequence element, in order - see
+    /// [`advanced_features::serde_deserialization`] for how this composes with a `NodeGroup`'s own
+    /// `deserialize_seq` for a `Vec<T>`-shaped struct field.
+    ///
+    /// [`advanced_features::serde_deserialization`]: crate::advanced_features::serde_deserialization
+    fn deserialize_seq<V: Visitor<'i>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_seq(NodeSeqAccess { nodes: self.into_children() })
+    }
+
+    fn deserialize_tuple<V: Visitor<'i>>(self, _len: usize, visitor: V) -> Result<V::Value, Self::Error> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'i>>(
+        self,
+        _name: &'static str,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_map<V: Visitor<'i>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_map(NodeMapAccess { nodes: self.into_children(), next_value: None })
+    }
+
+    /// Groups this node's children by rule name, then walks `fields` in the order the target
+    /// struct declared them, emitting every field's key regardless of how many children matched
+    /// it - zero, one, or more than one are each meaningful to some field shape, so the zero/one/
+    /// many distinction is left to the value side (`NodeGroup`'s own `Deserializer` impl) rather
+    /// than decided here.
+    fn deserialize_struct<V: Visitor<'i>>(
+        self,
+        _name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        let mut groups: HashMap<String, Vec<Node<'i, R, D, Ctx>>> = HashMap::new();
+        for child in self.into_children() {
+            groups.entry(format!("{:?}", child.as_rule())).or_default().push(child);
+        }
+        visitor.visit_map(NodeFieldMapAccess { fields: fields.iter(), groups, current_field: None })
+    }
+
+    /// Not supported: picking a variant from a node's shape alone is unambiguous only for the
+    /// narrowest grammars (a rule that's a bare alternation with exactly one child either way),
+    /// and silently guessing wrong for anything broader is worse than refusing outright. See
+    /// [`advanced_features::serde_deserialization`].
+    ///
+    /// [`advanced_features::serde_deserialization`]: crate::advanced_features::serde_deserialization
+    fn deserialize_enum<V: Visitor<'i>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        _visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        Err(de::Error::custom(
+            "Node's Deserializer doesn't support enums - see \
+             advanced_features::serde_deserialization",
+        ))
+    }
+
+    fn deserialize_identifier<V: Visitor<'i>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'i>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_unit()
+    }
+}
@@ -0,0 +1,24 @@
+//! [`Serialize`] for [`Node`], as described in
+//! [`crate::advanced_features::serde_serialization`].
+
+use pest::RuleType;
+use serde::ser::{Serialize, SerializeStruct, Serializer};
+
+use crate::Node;
+
+impl<'i, R: RuleType, D: Clone, Ctx> Serialize for Node<'i, R, D, Ctx> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let span = self.as_span();
+        let mut state = serializer.serialize_struct("Node", 5)?;
+        state.serialize_field("rule", &format!("{:?}", self.as_rule()))?;
+        state.serialize_field("str", self.as_str())?;
+        state.serialize_field("start", &span.start())?;
+        state.serialize_field("end", &span.end())?;
+        let children: Vec<_> = {
+            let mut children = self.children_ref();
+            std::iter::from_fn(move || children.next_node()).collect()
+        };
+        state.serialize_field("children", &children)?;
+        state.end()
+    }
+}
@@ -0,0 +1,43 @@
+//! [`assert_parses_as`], described in
+//! [`advanced_features::tree_shape_assertions`](crate::advanced_features::tree_shape_assertions).
+
+use pest::RuleType;
+
+use crate::{Error, Node, Nodes};
+
+/// Asserts that a parse produced a tree matching `expected`'s shape - a bare rule name for a
+/// leaf, `rule_name(child, child, ...)` for a node with children, comma-separated siblings at
+/// every level - ignoring every span and matched string along the way. Whitespace in `expected`
+/// is insignificant, so it can be laid out however is most readable. Panics, printing both
+/// shapes, on a mismatch; also panics, printing the [`Error`], if `parsed` itself is `Err`.
+///
+/// ```ignore
+/// assert_parses_as(
+///     MyParser::parse(Rule::func, "fn f(x) { return x; }"),
+///     "func(ident, block(stmt))",
+/// );
+/// ```
+pub fn assert_parses_as<R: RuleType, D: Clone, Ctx>(parsed: Result<Nodes<'_, R, D, Ctx>, Error<R>>, expected: &str) {
+    let nodes = parsed.unwrap_or_else(|error| panic!("parse failed:\n{error}"));
+    let actual = shape_of_nodes(nodes);
+    if canonicalize(&actual) != canonicalize(expected) {
+        panic!("tree shape mismatch:\n  expected: {expected}\n  actual:   {actual}");
+    }
+}
+
+fn shape_of_nodes<R: RuleType, D: Clone, Ctx>(nodes: Nodes<'_, R, D, Ctx>) -> String {
+    nodes.map(|node| shape_of(&node)).collect::<Vec<_>>().join(", ")
+}
+
+fn shape_of<R: RuleType, D: Clone, Ctx>(node: &Node<'_, R, D, Ctx>) -> String {
+    let mut children = node.children_ref().peekable();
+    if children.peek().is_none() {
+        return node.rule_name();
+    }
+    let inner = children.map(|child| shape_of(&child)).collect::<Vec<_>>().join(", ");
+    format!("{}({})", node.rule_name(), inner)
+}
+
+fn canonicalize(shape: &str) -> String {
+    shape.chars().filter(|c| !c.is_whitespace()).collect()
+}
@@ -0,0 +1,50 @@
+#![cfg(feature = "ariadne")]
+
+use pest_consume::{Error, IntoAriadneReport, Parser as _};
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+name = @{ ASCII_ALPHA+ }
+reference = { SOI ~ name ~ EOI }
+"#]
+struct RefParser;
+
+impl pest_consume::Parser for RefParser {
+    type Rule = Rule;
+}
+
+fn build_error(input: &str) -> Error<Rule> {
+    match RefParser::parse(Rule::reference, input) {
+        Err(err) => err,
+        Ok(_) => panic!("expected a parse failure"),
+    }
+}
+
+#[test]
+fn report_builder_builds_a_report_with_no_extra_labels() {
+    let err = build_error("12x");
+    let report = err.report_builder().build();
+    let mut out = Vec::new();
+    report
+        .write(ariadne::Source::from("12x"), &mut out)
+        .unwrap();
+    assert!(!out.is_empty());
+}
+
+#[test]
+fn with_label_attaches_a_secondary_label_alongside_the_primary_one() {
+    let err = build_error("12x");
+    let report = err
+        .report_builder()
+        .with_label(
+            pest::Span::new("12x", 0, 2).unwrap(),
+            "first defined here",
+        )
+        .build();
+    let mut out = Vec::new();
+    report
+        .write(ariadne::Source::from("12x"), &mut out)
+        .unwrap();
+    let rendered = String::from_utf8(out).unwrap();
+    assert!(rendered.contains("first defined here"));
+}
@@ -0,0 +1,44 @@
+use pest_consume::Parser as _;
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+padded = @{ (" " | "\t")* ~ ASCII_ALPHA* ~ (" " | "\t")* }
+line = { SOI ~ padded ~ EOI }
+"#]
+struct PaddedParser;
+
+type Node<'i> = pest_consume::Node<'i, Rule>;
+
+impl pest_consume::Parser for PaddedParser {
+    type Rule = Rule;
+}
+
+fn padded_node(input: &str) -> Node<'_> {
+    let inputs = PaddedParser::parse(Rule::line, input).unwrap();
+    let line = inputs.single().unwrap();
+    line.into_children().next_node().unwrap()
+}
+
+#[test]
+fn as_str_trimmed_strips_leading_and_trailing_whitespace() {
+    let node = padded_node("  hello  ");
+    assert_eq!(node.as_str(), "  hello  ");
+    assert_eq!(node.as_str_trimmed(), "hello");
+}
+
+#[test]
+fn trim_span_reports_the_byte_offsets_of_just_the_content() {
+    let node = padded_node("  hello  ");
+    let span = node.trim_span();
+    assert_eq!(span.as_str(), "hello");
+    assert_eq!(span.start(), 2);
+    assert_eq!(span.end(), 7);
+}
+
+#[test]
+fn an_all_whitespace_match_trims_to_an_empty_slice_without_panicking() {
+    let node = padded_node("   ");
+    assert_eq!(node.as_str_trimmed(), "");
+    let span = node.trim_span();
+    assert_eq!(span.as_str(), "");
+}
@@ -0,0 +1,50 @@
+#![cfg(feature = "testing")]
+
+use pest_consume::{assert_parses_as, Parser as _};
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+ident = @{ ASCII_ALPHA+ }
+stmt = { ident ~ ";" }
+block = { "{" ~ stmt+ ~ "}" }
+func = { SOI ~ ident ~ block ~ EOI }
+"#]
+struct FuncParser;
+
+impl pest_consume::Parser for FuncParser {
+    type Rule = Rule;
+}
+
+#[test]
+fn matches_a_tree_of_the_expected_shape() {
+    assert_parses_as(
+        FuncParser::parse(Rule::func, "f{x;y;}"),
+        "func(ident, block(stmt(ident), stmt(ident)), EOI)",
+    );
+}
+
+#[test]
+fn whitespace_in_the_expected_shape_is_insignificant() {
+    assert_parses_as(
+        FuncParser::parse(Rule::func, "f{x;y;}"),
+        "
+        func(
+            ident,
+            block(stmt(ident), stmt(ident)),
+            EOI
+        )
+        ",
+    );
+}
+
+#[test]
+#[should_panic(expected = "tree shape mismatch")]
+fn panics_with_both_shapes_on_a_mismatch() {
+    assert_parses_as(FuncParser::parse(Rule::func, "f{x;y;}"), "func(ident, block(stmt))");
+}
+
+#[test]
+#[should_panic(expected = "parse failed")]
+fn panics_with_the_error_on_a_failed_parse() {
+    assert_parses_as(FuncParser::parse(Rule::func, "not valid"), "func(ident, block(stmt))");
+}
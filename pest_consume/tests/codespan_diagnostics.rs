@@ -0,0 +1,37 @@
+#![cfg(feature = "codespan")]
+
+use codespan_reporting::diagnostic::Severity;
+use codespan_reporting::files::SimpleFiles;
+use pest_consume::{Error, IntoCodespanDiagnostic, Parser as _};
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+name = @{ ASCII_ALPHA+ }
+reference = { SOI ~ name ~ EOI }
+"#]
+struct RefParser;
+
+impl pest_consume::Parser for RefParser {
+    type Rule = Rule;
+}
+
+fn build_error(input: &str) -> Error<Rule> {
+    match RefParser::parse(Rule::reference, input) {
+        Err(err) => err,
+        Ok(_) => panic!("expected a parse failure"),
+    }
+}
+
+#[test]
+fn into_diagnostic_carries_the_error_message_and_a_primary_label_at_its_span() {
+    let mut files = SimpleFiles::new();
+    let file_id = files.add("input", "12x");
+
+    let diagnostic = build_error("12x").into_diagnostic(file_id);
+
+    assert_eq!(diagnostic.severity, Severity::Error);
+    assert_eq!(diagnostic.labels.len(), 1);
+    let label = &diagnostic.labels[0];
+    assert_eq!(label.file_id, file_id);
+    assert_eq!(diagnostic.message, label.message);
+}
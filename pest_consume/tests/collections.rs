@@ -0,0 +1,155 @@
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
+use pest::error::LineColLocation;
+use pest_consume::{match_nodes, Error, Parser as _};
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+key = @{ ASCII_ALPHA+ }
+num = @{ ASCII_DIGIT+ }
+entry = { key ~ "=" ~ num }
+entries = { entry ~ ("," ~ entry)* }
+record = { SOI ~ entries ~ EOI }
+tag_list = { key ~ ("," ~ key)* }
+tags = { SOI ~ tag_list ~ EOI }
+flat_entries = { (key ~ num)* }
+flat_record = { SOI ~ flat_entries ~ EOI }
+WHITESPACE = _{ " " }
+"#]
+struct RecordParser;
+
+type Node<'i> = pest_consume::Node<'i, Rule>;
+type PestResult<T> = Result<T, Error<Rule>>;
+
+impl pest_consume::Parser for RecordParser {
+    type Rule = Rule;
+}
+
+impl RecordParser {
+    #[allow(non_snake_case)]
+    fn EOI(_input: Node) -> PestResult<()> {
+        Ok(())
+    }
+
+    fn key(input: Node) -> PestResult<String> {
+        Ok(input.as_str().to_owned())
+    }
+
+    fn num(input: Node) -> PestResult<u32> {
+        input.as_str().parse().map_err(|_| input.error("not a number"))
+    }
+
+    fn entry(input: Node) -> PestResult<(String, u32)> {
+        match_nodes!(input.into_children();
+            [key(k), num(n)] => Ok((k, n)),
+        )
+    }
+
+    fn entries(input: Node) -> PestResult<BTreeMap<String, u32>> {
+        match_nodes!(input.into_children();
+            [entry(_e)..] => collect_map_no_dup,
+        )
+    }
+
+    fn record(input: Node) -> PestResult<BTreeMap<String, u32>> {
+        match_nodes!(input.into_children();
+            [entries(m), EOI(_)] => Ok(m),
+        )
+    }
+
+    fn tag_list(input: Node) -> PestResult<BTreeSet<String>> {
+        match_nodes!(input.into_children();
+            [key(_k)..] => collect_set_no_dup,
+        )
+    }
+
+    fn tags(input: Node) -> PestResult<BTreeSet<String>> {
+        match_nodes!(input.into_children();
+            [tag_list(s), EOI(_)] => Ok(s),
+        )
+    }
+
+    fn flat_entries(input: Node) -> PestResult<std::collections::HashMap<String, u32>> {
+        input.into_children().collect_map_pairs(Self::key, Self::num)
+    }
+
+    fn flat_record(input: Node) -> PestResult<std::collections::HashMap<String, u32>> {
+        match_nodes!(input.into_children();
+            [flat_entries(m), EOI(_)] => Ok(m),
+        )
+    }
+}
+
+fn eval_record(input: &str) -> PestResult<BTreeMap<String, u32>> {
+    let inputs = RecordParser::parse(Rule::record, input)?;
+    let input = inputs.single()?;
+    RecordParser::record(input)
+}
+
+fn eval_tags(input: &str) -> PestResult<BTreeSet<String>> {
+    let inputs = RecordParser::parse(Rule::tags, input)?;
+    let input = inputs.single()?;
+    RecordParser::tags(input)
+}
+
+#[test]
+fn collects_unique_keys() {
+    let map = eval_record("a=1, b=2, c=3").unwrap();
+    assert_eq!(map.get("a"), Some(&1));
+    assert_eq!(map.get("b"), Some(&2));
+    assert_eq!(map.get("c"), Some(&3));
+}
+
+#[test]
+fn rejects_duplicate_key_at_second_occurrence() {
+    let err = eval_record("a=1, b=2, a=3").unwrap_err();
+    // The error should point at the second `a=3` entry, not the first `a=1`.
+    assert_eq!(err.line_col, LineColLocation::Span((1, 11), (1, 14)));
+}
+
+#[test]
+fn collects_unique_tags() {
+    let tags = eval_tags("a, b, c").unwrap();
+    assert!(tags.contains("a") && tags.contains("b") && tags.contains("c"));
+}
+
+#[test]
+fn rejects_duplicate_tag_at_second_occurrence() {
+    let err = eval_tags("a, b, a").unwrap_err();
+    assert_eq!(err.line_col, LineColLocation::Span((1, 7), (1, 8)));
+}
+
+fn eval_flat_record(input: &str) -> PestResult<std::collections::HashMap<String, u32>> {
+    let inputs = RecordParser::parse(Rule::flat_record, input)?;
+    let input = inputs.single()?;
+    RecordParser::flat_record(input)
+}
+
+#[test]
+fn collect_map_pairs_reads_alternating_key_and_value_children() {
+    let map = eval_flat_record("a 1 b 2 c 3").unwrap();
+    assert_eq!(map.get("a"), Some(&1));
+    assert_eq!(map.get("b"), Some(&2));
+    assert_eq!(map.get("c"), Some(&3));
+}
+
+#[test]
+fn collect_map_pairs_rejects_duplicate_key_at_second_occurrence() {
+    let err = eval_flat_record("a 1 b 2 a 3").unwrap_err();
+    assert_eq!(err.line_col, LineColLocation::Span((1, 9), (1, 10)));
+}
+
+#[test]
+fn collect_vec_preserves_source_order() {
+    let inputs = RecordParser::parse(Rule::tags, "a, b, c").unwrap();
+    let input = inputs.single().unwrap();
+    let tag_list = input.into_children().find(|c| c.as_rule() == Rule::tag_list).unwrap();
+    let keys: Vec<String> = tag_list
+        .into_children()
+        .collect_vec()
+        .into_iter()
+        .map(|node| RecordParser::key(node).unwrap())
+        .collect();
+    assert_eq!(keys, vec!["a", "b", "c"]);
+}
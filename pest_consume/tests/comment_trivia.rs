@@ -0,0 +1,60 @@
+use pest_consume::Parser as _;
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+field = @{ ASCII_ALPHA+ }
+record = { SOI ~ field ~ ("," ~ field)* ~ EOI }
+WHITESPACE = _{ " " | "\n" }
+COMMENT = _{ "//" ~ (!"\n" ~ ANY)* }
+"#]
+struct RecordParser;
+
+type Node<'i> = pest_consume::Node<'i, Rule>;
+
+impl pest_consume::Parser for RecordParser {
+    type Rule = Rule;
+}
+
+fn fields(input: &str) -> Vec<Node<'_>> {
+    let inputs =
+        RecordParser::parse_with_trivia(Rule::record, input, Rule::COMMENT, Rule::WHITESPACE).unwrap();
+    let record = inputs.single().unwrap();
+    record.children_ref().filter(|c| c.as_rule() == Rule::field).collect()
+}
+
+#[test]
+fn leading_trivia_is_recovered_for_the_first_field() {
+    let fields = fields("// leading\na, b");
+    assert_eq!(fields[0].leading_trivia(), vec!["// leading"]);
+}
+
+#[test]
+fn trailing_trivia_is_recovered_for_the_last_field() {
+    let fields = fields("a, b // trailing\n");
+    assert_eq!(fields[1].trailing_trivia(), vec!["// trailing"]);
+}
+
+#[test]
+fn a_comment_past_a_bare_literal_separator_is_not_recovered() {
+    // The "," between fields is a bare literal with no pair of its own, so it blocks trivia
+    // reconstruction from reaching across it in either direction.
+    let fields = fields("a, // past the comma\nb");
+    assert_eq!(fields[0].trailing_trivia(), Vec::<&str>::new());
+    assert_eq!(fields[1].leading_trivia(), Vec::<&str>::new());
+}
+
+#[test]
+fn a_node_with_no_siblings_on_either_side_has_no_trivia() {
+    let fields = fields("a");
+    assert_eq!(fields[0].leading_trivia(), Vec::<&str>::new());
+    assert_eq!(fields[0].trailing_trivia(), Vec::<&str>::new());
+}
+
+#[test]
+fn without_parse_with_trivia_both_are_always_empty() {
+    let inputs = RecordParser::parse(Rule::record, "a, b").unwrap();
+    let record = inputs.single().unwrap();
+    let field = record.children_ref().find(|c| c.as_rule() == Rule::field).unwrap();
+    assert_eq!(field.leading_trivia(), Vec::<&str>::new());
+    assert_eq!(field.trailing_trivia(), Vec::<&str>::new());
+}
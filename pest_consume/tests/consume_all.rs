@@ -0,0 +1,60 @@
+use pest_consume::{match_nodes, Error, Parser as _};
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+field = @{ (!"," ~ ANY)* }
+fields = { field ~ ("," ~ field)* }
+record = { SOI ~ fields ~ EOI }
+"#]
+struct RecordParser;
+
+type Node<'i> = pest_consume::Node<'i, Rule>;
+type PestResult<T> = Result<T, Error<Rule>>;
+
+impl pest_consume::Parser for RecordParser {
+    type Rule = Rule;
+}
+
+impl RecordParser {
+    #[allow(non_snake_case)]
+    fn EOI(_input: Node) -> PestResult<()> {
+        Ok(())
+    }
+
+    fn field(input: Node) -> PestResult<f64> {
+        input
+            .as_str()
+            .parse()
+            .map_err(|_| input.error("not a number"))
+    }
+
+    fn fields(input: Node) -> PestResult<(Vec<f64>, Vec<Error<Rule>>)> {
+        Ok(input.into_children().consume_all(Self::field))
+    }
+
+    fn record(input: Node) -> PestResult<(Vec<f64>, Vec<Error<Rule>>)> {
+        match_nodes!(input.into_children();
+            [fields(result), EOI(_)] => Ok(result),
+        )
+    }
+}
+
+fn eval(input: &str) -> (Vec<f64>, Vec<Error<Rule>>) {
+    let inputs = RecordParser::parse(Rule::record, input).unwrap();
+    let input = inputs.single().unwrap();
+    RecordParser::record(input).unwrap()
+}
+
+#[test]
+fn all_fields_succeed() {
+    let (values, errors) = eval("1,2,3");
+    assert_eq!(values, vec![1.0, 2.0, 3.0]);
+    assert!(errors.is_empty());
+}
+
+#[test]
+fn malformed_fields_are_all_reported_alongside_the_good_ones() {
+    let (values, errors) = eval("1,x,3,y");
+    assert_eq!(values, vec![1.0, 3.0]);
+    assert_eq!(errors.len(), 2);
+}
@@ -0,0 +1,73 @@
+use pest_consume::{match_nodes, Error, Parser as _};
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+ident = @{ ASCII_ALPHA+ }
+num = @{ ASCII_DIGIT+ }
+assign = { ident ~ "=" ~ num }
+semi = { ";" }
+block = { SOI ~ assign ~ (semi ~ assign)* ~ semi? ~ EOI }
+"#]
+struct BlockParser;
+
+type Node<'i> = pest_consume::Node<'i, Rule>;
+type PestResult<T> = Result<T, Error<Rule>>;
+
+impl pest_consume::Parser for BlockParser {
+    type Rule = Rule;
+}
+
+impl BlockParser {
+    fn ident(input: Node) -> PestResult<String> {
+        Ok(input.as_str().to_owned())
+    }
+
+    fn num(input: Node) -> PestResult<i64> {
+        input.as_str().parse().map_err(|_| input.error("not a number"))
+    }
+
+    fn assign(input: Node) -> PestResult<(String, i64)> {
+        match_nodes!(input.into_children();
+            [ident(name), num(value)] => Ok((name, value)),
+        )
+    }
+
+    fn block(input: Node) -> (Vec<(String, i64)>, Vec<Error<Rule>>) {
+        input
+            .into_children()
+            .exclude_rule(Rule::EOI)
+            .consume_with_recovery(Rule::semi, |group| Self::assign(group.single()?))
+    }
+}
+
+fn eval(input: &str) -> (Vec<(String, i64)>, Vec<Error<Rule>>) {
+    let inputs = BlockParser::parse(Rule::block, input).unwrap();
+    let input = inputs.single().unwrap();
+    BlockParser::block(input)
+}
+
+#[test]
+fn every_statement_succeeds() {
+    let (values, errors) = eval("a=1;b=2;c=3");
+    assert_eq!(
+        values,
+        vec![("a".to_owned(), 1), ("b".to_owned(), 2), ("c".to_owned(), 3)]
+    );
+    assert!(errors.is_empty());
+}
+
+#[test]
+fn a_failed_statement_is_skipped_without_losing_the_ones_around_it() {
+    // "99999999999999999999" matches the grammar's `num` rule but overflows `i64::parse`, so
+    // `assign` fails on that one statement only - the other two should still come back.
+    let (values, errors) = eval("a=1;b=99999999999999999999;c=3");
+    assert_eq!(values, vec![("a".to_owned(), 1), ("c".to_owned(), 3)]);
+    assert_eq!(errors.len(), 1);
+}
+
+#[test]
+fn a_trailing_separator_does_not_produce_a_phantom_empty_group() {
+    let (values, errors) = eval("a=1;b=2;");
+    assert_eq!(values, vec![("a".to_owned(), 1), ("b".to_owned(), 2)]);
+    assert!(errors.is_empty());
+}
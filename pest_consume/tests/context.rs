@@ -0,0 +1,95 @@
+use pest_consume::{match_nodes, Error, Parser as _};
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+word = @{ ASCII_ALPHA+ }
+word_list = { word ~ (" " ~ word)* }
+words = { SOI ~ word_list ~ EOI }
+"#]
+struct WordsParser;
+
+#[derive(Default)]
+struct Interner {
+    strings: Vec<String>,
+}
+
+impl Interner {
+    fn intern(&mut self, s: &str) -> usize {
+        match self.strings.iter().position(|existing| existing == s) {
+            Some(id) => id,
+            None => {
+                self.strings.push(s.to_owned());
+                self.strings.len() - 1
+            }
+        }
+    }
+}
+
+type Node<'i> = pest_consume::Node<'i, Rule, (), Interner>;
+type PestResult<T> = Result<T, Error<Rule>>;
+
+impl pest_consume::Parser for WordsParser {
+    type Rule = Rule;
+}
+
+impl WordsParser {
+    #[allow(non_snake_case)]
+    fn EOI(_input: Node) -> PestResult<()> {
+        Ok(())
+    }
+
+    fn word(input: Node) -> PestResult<usize> {
+        Ok(input.context_mut().intern(input.as_str()))
+    }
+
+    fn word_list(input: Node) -> PestResult<Vec<usize>> {
+        match_nodes!(input.into_children();
+            [word(w)..] => Ok(w),
+        )
+    }
+
+    fn words(input: Node) -> PestResult<Vec<usize>> {
+        match_nodes!(input.into_children();
+            [word_list(w), EOI(_)] => Ok(w),
+        )
+    }
+}
+
+fn intern_words(input_str: &str, interner: &mut Interner) -> PestResult<Vec<usize>> {
+    let inputs = WordsParser::parse_with_context(Rule::words, input_str, interner)?;
+    let input = inputs.single()?;
+    WordsParser::words(input)
+}
+
+#[test]
+fn interns_repeated_words_to_the_same_id() {
+    let mut interner = Interner::default();
+    let ids = intern_words("the quick fox jumps the fox", &mut interner).unwrap();
+    assert_eq!(ids, vec![0, 1, 2, 3, 0, 2]);
+    assert_eq!(interner.strings, vec!["the", "quick", "fox", "jumps"]);
+}
+
+#[test]
+fn context_survives_across_separate_parses() {
+    let mut interner = Interner::default();
+    intern_words("alpha beta", &mut interner).unwrap();
+    let ids = intern_words("beta gamma", &mut interner).unwrap();
+    // `beta` was already interned by the first parse, `gamma` is new.
+    assert_eq!(ids, vec![1, 2]);
+    assert_eq!(interner.strings, vec!["alpha", "beta", "gamma"]);
+}
+
+#[test]
+#[should_panic(expected = "already borrowed")]
+fn two_sibling_nodes_holding_context_mut_at_once_panics_instead_of_aliasing() {
+    let mut interner = Interner::default();
+    let inputs = WordsParser::parse_with_context(Rule::words, "alpha beta", &mut interner).unwrap();
+    let words = inputs.single().unwrap();
+    let mut top_level = words.into_children();
+    let word_list = top_level.next_node().unwrap();
+    let mut words_in_list = word_list.into_children();
+    let first = words_in_list.next_node().unwrap();
+    let second = words_in_list.next_node().unwrap();
+    let _first_guard = first.context_mut();
+    let _second_guard = second.context_mut();
+}
@@ -0,0 +1,67 @@
+use pest_consume::{Error, Parser as _};
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+name = @{ ASCII_ALPHA+ }
+reference = { SOI ~ name ~ EOI }
+"#]
+struct RefParser;
+
+type Node<'i> = pest_consume::Node<'i, Rule>;
+
+impl pest_consume::Parser for RefParser {
+    type Rule = Rule;
+}
+
+#[derive(Debug, PartialEq)]
+enum MyError {
+    Grammar(Error<Rule>),
+}
+
+impl From<Error<Rule>> for MyError {
+    fn from(e: Error<Rule>) -> Self {
+        MyError::Grammar(e)
+    }
+}
+
+impl RefParser {
+    fn name(input: Node) -> Result<String, MyError> {
+        let name = input.as_str();
+        if name == "defined" {
+            Ok(name.to_owned())
+        } else {
+            Err(input.error_as(format!("undefined variable: {name}")))
+        }
+    }
+
+    fn reference(input: Node) -> Result<String, MyError> {
+        let name = input.into_children().next_node().unwrap();
+        RefParser::name(name)
+    }
+}
+
+fn eval(input: &str) -> Result<String, MyError> {
+    let inputs = RefParser::parse(Rule::reference, input)?;
+    let input = inputs.single()?;
+    RefParser::reference(input)
+}
+
+#[test]
+fn a_defined_name_resolves() {
+    assert_eq!(eval("defined"), Ok("defined".to_owned()));
+}
+
+#[test]
+fn an_undefined_name_reports_a_custom_error_built_from_the_node() {
+    match eval("nope") {
+        Err(MyError::Grammar(err)) => {
+            assert!(err.to_string().contains("undefined variable: nope"));
+        }
+        Ok(_) => panic!("expected an error"),
+    }
+}
+
+#[test]
+fn a_grammar_failure_converts_into_the_custom_error_type_too() {
+    assert!(matches!(eval(""), Err(MyError::Grammar(_))));
+}
@@ -0,0 +1,68 @@
+use pest_consume::{match_nodes, Error, Parser as _};
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+nested = { "(" ~ nested ~ ")" | num }
+num = @{ ASCII_DIGIT+ }
+expr = { SOI ~ nested ~ EOI }
+"#]
+struct NestedParser;
+
+type Node<'i> = pest_consume::Node<'i, Rule>;
+type PestResult<T> = Result<T, Error<Rule>>;
+
+impl pest_consume::Parser for NestedParser {
+    type Rule = Rule;
+}
+
+impl NestedParser {
+    #[allow(non_snake_case)]
+    fn EOI(_input: Node) -> PestResult<()> {
+        Ok(())
+    }
+
+    fn num(input: Node) -> PestResult<u32> {
+        Ok(input.as_str().parse().unwrap())
+    }
+
+    fn nested(input: Node) -> PestResult<u32> {
+        match_nodes!(input.into_children();
+            [nested(n)] => Ok(n),
+            [num(n)] => Ok(n),
+        )
+    }
+
+    fn expr(input: Node) -> PestResult<u32> {
+        match_nodes!(input.into_children();
+            [nested(n), EOI(_)] => Ok(n),
+        )
+    }
+}
+
+fn parens(depth: usize) -> String {
+    format!("{}{}{}", "(".repeat(depth), 1, ")".repeat(depth))
+}
+
+#[test]
+fn within_the_limit_still_parses() {
+    let input = parens(5);
+    let inputs = NestedParser::parse_with_depth_limit(Rule::expr, &input, 10).unwrap();
+    let result = NestedParser::expr(inputs.single().unwrap());
+    assert_eq!(result.unwrap(), 1);
+}
+
+#[test]
+fn exceeding_the_limit_returns_an_error_instead_of_recursing_further() {
+    let input = parens(20);
+    let inputs = NestedParser::parse_with_depth_limit(Rule::expr, &input, 10).unwrap();
+    let result = NestedParser::expr(inputs.single().unwrap());
+    assert!(result.is_err());
+}
+
+#[test]
+fn unbounded_by_default() {
+    let input = parens(500);
+    let inputs = NestedParser::parse(Rule::expr, &input).unwrap();
+    let result = NestedParser::expr(inputs.single().unwrap());
+    assert_eq!(result.unwrap(), 1);
+}
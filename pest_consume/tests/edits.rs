@@ -0,0 +1,86 @@
+use pest_consume::{Edits, Parser as _};
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+word = @{ ASCII_ALPHANUMERIC+ }
+sentence = { SOI ~ word ~ (" " ~ word)* ~ EOI }
+"#]
+struct SentenceParser;
+
+impl pest_consume::Parser for SentenceParser {
+    type Rule = Rule;
+}
+
+fn words(input: &str) -> Vec<pest_consume::Node<'_, Rule>> {
+    let inputs = SentenceParser::parse(Rule::sentence, input).unwrap();
+    let sentence = inputs.single().unwrap();
+    sentence
+        .into_children()
+        .filter_rule(Rule::word)
+        .map_to_vec(Ok::<_, pest_consume::Error<Rule>>)
+        .unwrap()
+}
+
+mod pair_grammar {
+    use pest_consume::Parser as _;
+
+    #[derive(pest_derive::Parser)]
+    #[grammar_inline = r#"
+    half = @{ ASCII_ALPHANUMERIC }
+    pair = { SOI ~ half ~ half ~ EOI }
+    "#]
+    pub struct PairParser;
+
+    impl pest_consume::Parser for PairParser {
+        type Rule = Rule;
+    }
+
+    pub fn halves(input: &str) -> Vec<pest_consume::Node<'_, Rule>> {
+        let inputs = PairParser::parse(Rule::pair, input).unwrap();
+        let pair = inputs.single().unwrap();
+        pair.into_children()
+            .filter_rule(Rule::half)
+            .map_to_vec(Ok::<_, pest_consume::Error<Rule>>)
+            .unwrap()
+    }
+}
+
+#[test]
+fn non_overlapping_edits_apply_in_source_order_regardless_of_recording_order() {
+    let input = "one two three";
+    let nodes = words(input);
+
+    let mut edits = Edits::new(input);
+    edits.add(&nodes[2], "THREE").unwrap();
+    edits.add(&nodes[0], "ONE").unwrap();
+
+    assert_eq!(edits.apply(), "ONE two THREE");
+}
+
+#[test]
+fn an_edit_with_no_recorded_changes_returns_the_source_unchanged() {
+    let input = "one two three";
+    assert_eq!(Edits::new(input).apply(), input);
+}
+
+#[test]
+fn overlapping_edits_are_rejected() {
+    let input = "one two three";
+    let nodes = words(input);
+
+    let mut edits = Edits::new(input);
+    edits.add(&nodes[0], "ONE").unwrap();
+    // The same node's span overlaps the edit just recorded for it.
+    assert!(edits.add(&nodes[0], "one again").is_err());
+}
+
+#[test]
+fn edits_sharing_a_boundary_are_rejected() {
+    let input = "ab";
+    let halves = pair_grammar::halves(input);
+
+    let mut edits = Edits::new(input);
+    edits.add(&halves[0], "A").unwrap();
+    // halves[0] ends exactly where halves[1] starts - no well-defined combined result.
+    assert!(edits.add(&halves[1], "B").is_err());
+}
@@ -0,0 +1,31 @@
+use pest_consume::Parser as _;
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+num = @{ ASCII_DIGIT+ }
+word = @{ ASCII_ALPHA+ }
+item = { SOI ~ (num | word) ~ EOI }
+"#]
+struct ItemParser;
+
+impl pest_consume::Parser for ItemParser {
+    type Rule = Rule;
+}
+
+#[test]
+fn expect_rule_returns_the_node_when_it_matches() {
+    let inputs = ItemParser::parse(Rule::item, "42").unwrap();
+    let item = inputs.single().unwrap();
+    let num = item.into_children().exclude_rule(Rule::EOI).single().unwrap();
+    let num = num.expect_rule(Rule::num).unwrap();
+    assert_eq!(num.as_str(), "42");
+}
+
+#[test]
+fn expect_rule_errors_when_the_rule_does_not_match() {
+    let inputs = ItemParser::parse(Rule::item, "42").unwrap();
+    let item = inputs.single().unwrap();
+    let num = item.into_children().exclude_rule(Rule::EOI).single().unwrap();
+    let err = num.expect_rule(Rule::word).unwrap_err();
+    assert!(err.to_string().contains("expected a `word` node, found `num`"));
+}
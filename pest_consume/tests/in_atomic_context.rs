@@ -0,0 +1,61 @@
+use std::collections::HashSet;
+
+use pest_consume::Parser as _;
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+string_char = { !"\"" ~ ANY }
+string = ${ "\"" ~ string_char* ~ "\"" }
+ident = @{ ASCII_ALPHA+ }
+item = { string | ident }
+file = { SOI ~ item ~ EOI }
+"#]
+struct TextParser;
+
+type Node<'i> = pest_consume::Node<'i, Rule>;
+
+impl pest_consume::Parser for TextParser {
+    type Rule = Rule;
+}
+
+fn atomic_rules() -> HashSet<Rule> {
+    [Rule::string].into_iter().collect()
+}
+
+fn find_child<'i>(node: &Node<'i>, rule: Rule) -> Node<'i> {
+    let mut children = node.children_ref();
+    std::iter::from_fn(|| children.next_node()).find(|n| n.as_rule() == rule).unwrap()
+}
+
+#[test]
+fn a_node_nested_under_an_atomic_rule_reports_it_is_in_atomic_context() {
+    let inputs = TextParser::parse_parented(Rule::file, "\"ab\"").unwrap();
+    let file = inputs.single().unwrap();
+    let item = find_child(&file, Rule::item);
+    let string = find_child(&item, Rule::string);
+    let char_node = find_child(&string, Rule::string_char);
+
+    assert!(char_node.in_atomic_context(&atomic_rules()));
+}
+
+#[test]
+fn a_node_outside_any_atomic_rule_reports_it_is_not_in_atomic_context() {
+    let inputs = TextParser::parse_parented(Rule::file, "ab").unwrap();
+    let file = inputs.single().unwrap();
+    let item = find_child(&file, Rule::item);
+    let ident = find_child(&item, Rule::ident);
+
+    assert!(!ident.in_atomic_context(&atomic_rules()));
+}
+
+#[test]
+fn without_parse_parented_only_the_node_itself_can_be_checked() {
+    let inputs = TextParser::parse(Rule::file, "\"ab\"").unwrap();
+    let file = inputs.single().unwrap();
+    let item = find_child(&file, Rule::item);
+    let string = find_child(&item, Rule::string);
+
+    assert!(string.in_atomic_context(&atomic_rules()));
+    let char_node = find_child(&string, Rule::string_char);
+    assert!(!char_node.in_atomic_context(&atomic_rules()));
+}
@@ -0,0 +1,67 @@
+use pest_consume::{match_nodes, Error, Parser as _};
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+let_stmt = { "let" ~ ASCII_ALPHA+ }
+expr_stmt = { ASCII_DIGIT+ }
+statement = { let_stmt | expr_stmt }
+"#]
+struct StmtParser;
+
+type Node<'i> = pest_consume::Node<'i, Rule>;
+type PestResult<T> = Result<T, Error<Rule>>;
+
+trait Statement {
+    fn describe(&self) -> String;
+}
+
+struct Let(String);
+impl Statement for Let {
+    fn describe(&self) -> String {
+        format!("Let({:?})", self.0)
+    }
+}
+
+struct ExprStmt(String);
+impl Statement for ExprStmt {
+    fn describe(&self) -> String {
+        format!("ExprStmt({:?})", self.0)
+    }
+}
+
+impl pest_consume::Parser for StmtParser {
+    type Rule = Rule;
+}
+
+impl StmtParser {
+    fn let_stmt(input: Node) -> PestResult<String> {
+        Ok(input.as_str().to_owned())
+    }
+
+    fn expr_stmt(input: Node) -> PestResult<String> {
+        Ok(input.as_str().to_owned())
+    }
+
+    // Each arm produces a different concrete type, boxed into the shared `Statement` trait
+    // directly in the arm's expression - `match_nodes!` doesn't need to know about the trait at
+    // all, since an arm's `=> expr` is just an ordinary Rust expression. See
+    // `advanced_features::trait_object_arms`.
+    fn statement(input: Node) -> PestResult<Box<dyn Statement>> {
+        match_nodes!(input.into_children();
+            [let_stmt(s)] => Ok(Box::new(Let(s)) as Box<dyn Statement>),
+            [expr_stmt(s)] => Ok(Box::new(ExprStmt(s)) as Box<dyn Statement>),
+        )
+    }
+}
+
+#[test]
+fn arms_for_different_rules_box_into_a_shared_trait_object() {
+    let stmt =
+        StmtParser::statement(StmtParser::parse(Rule::statement, "lethello").unwrap().single().unwrap())
+            .unwrap();
+    assert_eq!(stmt.describe(), "Let(\"lethello\")");
+
+    let stmt = StmtParser::statement(StmtParser::parse(Rule::statement, "42").unwrap().single().unwrap())
+        .unwrap();
+    assert_eq!(stmt.describe(), "ExprStmt(\"42\")");
+}
@@ -0,0 +1,61 @@
+use pest_consume::{match_nodes, Error, Parser as _};
+
+// `match_nodes!` flags an *unguarded* arm whose rule sequence exactly repeats an earlier arm's -
+// that can't be exercised by a passing test, since it's a `compile_error!`. These tests instead
+// cover the cases that must *not* be flagged, so the check doesn't regress into false positives.
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+ident = @{ ASCII_ALPHA+ }
+number = @{ ASCII_DIGIT+ }
+item = { SOI ~ ident ~ number ~ EOI }
+WHITESPACE = _{ " " }
+"#]
+struct ItemParser;
+
+type Node<'i> = pest_consume::Node<'i, Rule>;
+type PestResult<T> = Result<T, Error<Rule>>;
+
+impl pest_consume::Parser for ItemParser {
+    type Rule = Rule;
+}
+
+impl ItemParser {
+    #[allow(non_snake_case)]
+    fn EOI(_input: Node) -> PestResult<()> {
+        Ok(())
+    }
+
+    fn ident(input: Node) -> PestResult<String> {
+        Ok(input.as_str().to_owned())
+    }
+
+    fn number(input: Node) -> PestResult<i64> {
+        input
+            .as_str()
+            .parse()
+            .map_err(|_| input.error("not a valid number"))
+    }
+
+    // Same rule sequence (`ident`, `number`, `EOI`) twice over, but the first copy carries a
+    // guard - a guarded arm's pattern only sometimes matches, so a later arm with the same
+    // sequence isn't necessarily unreachable, and must not be flagged.
+    fn item(input: Node) -> PestResult<String> {
+        match_nodes!(input.into_children();
+            [ident(i), number(n), EOI(_)] if n > 100 => Ok(format!("big {i} {n}")),
+            [ident(i), number(n), EOI(_)] => Ok(format!("{i} {n}")),
+        )
+    }
+}
+
+fn eval(input: &str) -> PestResult<String> {
+    let inputs = ItemParser::parse(Rule::item, input)?;
+    let input = inputs.single()?;
+    ItemParser::item(input)
+}
+
+#[test]
+fn a_guarded_arm_sharing_its_sequence_with_a_later_arm_is_not_flagged_as_a_duplicate() {
+    assert_eq!(eval("x 200").unwrap(), "big x 200");
+    assert_eq!(eval("x 5").unwrap(), "x 5");
+}
@@ -0,0 +1,57 @@
+use pest_consume::{match_nodes, Error, Parser as _};
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+ident = @{ ASCII_ALPHA+ }
+args = { SOI ~ ident* ~ EOI }
+WHITESPACE = _{ " " }
+"#]
+struct ArgsParser;
+
+type Node<'i> = pest_consume::Node<'i, Rule>;
+type PestResult<T> = Result<T, Error<Rule>>;
+
+impl pest_consume::Parser for ArgsParser {
+    type Rule = Rule;
+}
+
+impl ArgsParser {
+    #[allow(non_snake_case)]
+    fn EOI(_input: Node) -> PestResult<()> {
+        Ok(())
+    }
+
+    fn ident(input: Node) -> PestResult<String> {
+        Ok(input.as_str().to_owned())
+    }
+
+    fn exactly_one(input: Node) -> PestResult<String> {
+        match_nodes!(input.into_children();
+            [ident(a), EOI(_)] => Ok(a),
+        )
+    }
+}
+
+fn classify(input: &str) -> PestResult<String> {
+    let inputs = ArgsParser::parse(Rule::args, input)?;
+    let input = inputs.single()?;
+    ArgsParser::exactly_one(input)
+}
+
+#[test]
+fn fallthrough_error_lists_the_actual_rule_sequence() {
+    let error = classify("foo bar").unwrap_err();
+    let message = error.to_string();
+    assert!(
+        message.contains("[ident, ident, EOI]"),
+        "error didn't list the actual rules: {message}"
+    );
+}
+
+#[test]
+fn fallthrough_error_still_points_at_the_parent_span() {
+    let error = classify("").unwrap_err();
+    // An empty sequence falls through too (EOI alone doesn't match `[ident(a), EOI(_)]`), and
+    // the error should still point somewhere sensible rather than panicking.
+    assert!(error.to_string().contains("[EOI]"));
+}
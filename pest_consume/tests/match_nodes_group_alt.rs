@@ -0,0 +1,81 @@
+use pest_consume::{match_nodes, Error, Parser as _};
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+num = @{ ASCII_DIGIT+ }
+op = { "+" | "-" }
+literal = @{ "lit" }
+body = { (num ~ op ~ num) | literal }
+statement = { SOI ~ body ~ EOI }
+"#]
+struct StatementParser;
+
+type Node<'i> = pest_consume::Node<'i, Rule>;
+type PestResult<T> = Result<T, Error<Rule>>;
+
+impl pest_consume::Parser for StatementParser {
+    type Rule = Rule;
+}
+
+impl StatementParser {
+    #[allow(non_snake_case)]
+    fn EOI(_input: Node) -> PestResult<()> {
+        Ok(())
+    }
+
+    fn num(input: Node) -> PestResult<i64> {
+        input.as_str().parse().map_err(|_| input.error("not a number"))
+    }
+
+    fn op(input: Node) -> PestResult<String> {
+        Ok(input.as_str().to_owned())
+    }
+
+    fn literal(input: Node) -> PestResult<String> {
+        Ok(input.as_str().to_owned())
+    }
+
+    fn body(input: Node) -> PestResult<String> {
+        match_nodes!(input.into_children();
+            [(num(l), op(o), num(r)) | (literal(lit))] => {
+                if let (Some(l), Some(o), Some(r)) = (l, o, r) {
+                    Ok(format!("{l}{o}{r}"))
+                } else if let Some(lit) = lit {
+                    Ok(lit)
+                } else {
+                    unreachable!()
+                }
+            },
+        )
+    }
+
+    fn statement(input: Node) -> PestResult<String> {
+        match_nodes!(input.into_children();
+            [body(b), EOI(_)] => Ok(b),
+        )
+    }
+}
+
+fn eval(input: &str) -> PestResult<String> {
+    let inputs = StatementParser::parse(Rule::statement, input)?;
+    let input = inputs.single()?;
+    StatementParser::statement(input)
+}
+
+#[test]
+fn the_binop_group_binds_its_nodes_and_leaves_the_literal_group_none() {
+    assert_eq!(eval("1+2").unwrap(), "1+2");
+}
+
+#[test]
+fn the_literal_group_binds_its_node_and_leaves_the_binop_group_none() {
+    assert_eq!(eval("lit").unwrap(), "lit");
+}
+
+#[test]
+fn a_shape_matching_neither_group_is_rejected_by_the_grammar_itself() {
+    // The grammar only accepts `num op num` or `literal` as the body, so there's no third shape
+    // that would reach `match_nodes!` at all - confirming the grammar, not the macro, is what
+    // rules this out.
+    assert!(eval("12").is_err());
+}
@@ -0,0 +1,65 @@
+use pest_consume::{match_nodes, Error, Parser as _};
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+number = @{ "-"? ~ ASCII_DIGIT+ }
+value = { SOI ~ number ~ EOI }
+"#]
+struct ValueParser;
+
+type Node<'i> = pest_consume::Node<'i, Rule>;
+type PestResult<T> = Result<T, Error<Rule>>;
+
+impl pest_consume::Parser for ValueParser {
+    type Rule = Rule;
+}
+
+impl ValueParser {
+    #[allow(non_snake_case)]
+    fn EOI(_input: Node) -> PestResult<()> {
+        Ok(())
+    }
+
+    fn number(input: Node) -> PestResult<i64> {
+        input
+            .as_str()
+            .parse()
+            .map_err(|_| input.error("not a valid number"))
+    }
+
+    fn classify(input: Node) -> PestResult<String> {
+        match_nodes!(input.into_children();
+            [number(n), EOI(_)] if n > 0 => Ok("positive".to_owned()),
+            [number(n), EOI(_)] if n < 0 => Ok("negative".to_owned()),
+            [number(_n), EOI(_)] => Ok("zero".to_owned()),
+        )
+    }
+}
+
+fn eval(input: &str) -> PestResult<String> {
+    let inputs = ValueParser::parse(Rule::value, input)?;
+    let input = inputs.single()?;
+    ValueParser::classify(input)
+}
+
+#[test]
+fn passing_guard_picks_its_own_arm() {
+    assert_eq!(eval("5").unwrap(), "positive");
+    assert_eq!(eval("-5").unwrap(), "negative");
+}
+
+#[test]
+fn failing_guard_falls_through_without_erroring() {
+    // `0` fails both guarded arms' conditions, so it must fall through to the final, unguarded
+    // arm rather than erroring out on the first guard that doesn't hold.
+    assert_eq!(eval("0").unwrap(), "zero");
+}
+
+#[test]
+fn falling_through_a_guard_leaves_the_sequence_intact_for_the_next_arm() {
+    // Each guarded arm binds against its own fork of the node sequence; a failing guard must
+    // not leave the real sequence partially consumed, or the later arm's `EOI(_)` slot would
+    // fail to match.
+    assert_eq!(eval("0").unwrap(), "zero");
+    assert_eq!(eval("-1").unwrap(), "negative");
+}
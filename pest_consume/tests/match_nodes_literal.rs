@@ -0,0 +1,116 @@
+use pest_consume::{match_nodes, Error, Parser as _};
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+keyword = @{ "if" | "while" }
+number = @{ ASCII_DIGIT+ }
+stmt = { SOI ~ keyword ~ number ~ EOI }
+WHITESPACE = _{ " " }
+"#]
+struct StmtParser;
+
+type Node<'i> = pest_consume::Node<'i, Rule>;
+type PestResult<T> = Result<T, Error<Rule>>;
+
+impl pest_consume::Parser for StmtParser {
+    type Rule = Rule;
+}
+
+impl StmtParser {
+    #[allow(non_snake_case)]
+    fn EOI(_input: Node) -> PestResult<()> {
+        Ok(())
+    }
+
+    fn number(input: Node) -> PestResult<i64> {
+        input
+            .as_str()
+            .parse()
+            .map_err(|_| input.error("not a valid number"))
+    }
+
+    fn stmt(input: Node) -> PestResult<String> {
+        match_nodes!(input.into_children();
+            [keyword("if"), number(n), EOI(_)] => Ok(format!("if {n}")),
+            [keyword("while"), number(n), EOI(_)] => Ok(format!("while {n}")),
+        )
+    }
+}
+
+fn eval(input: &str) -> PestResult<String> {
+    let inputs = StmtParser::parse(Rule::stmt, input)?;
+    let input = inputs.single()?;
+    StmtParser::stmt(input)
+}
+
+#[test]
+fn a_matching_literal_slot_picks_its_own_arm() {
+    assert_eq!(eval("if 1").unwrap(), "if 1");
+    assert_eq!(eval("while 2").unwrap(), "while 2");
+}
+
+#[test]
+fn a_mismatched_literal_falls_through_without_erroring() {
+    // Both arms have the same rule shape (`keyword`, `number`, `EOI`), so only the literal check
+    // tells them apart; a `while` input must still fall through the `"if"` arm rather than
+    // erroring out on the first literal mismatch.
+    assert_eq!(eval("while 2").unwrap(), "while 2");
+}
+
+#[test]
+fn falling_through_a_literal_mismatch_leaves_the_sequence_intact_for_the_next_arm() {
+    // The first arm forks before checking `"if"` against a `while` node; a mismatch must not
+    // leave the real sequence partially consumed, or the second arm's later slots would fail to
+    // match.
+    assert_eq!(eval("while 3").unwrap(), "while 3");
+}
+
+mod wider {
+    use pest_consume::{match_nodes, Error, Parser as _};
+
+    #[derive(pest_derive::Parser)]
+    #[grammar_inline = r#"
+    keyword = @{ "if" | "while" | "for" }
+    number = @{ ASCII_DIGIT+ }
+    stmt = { SOI ~ keyword ~ number ~ EOI }
+    WHITESPACE = _{ " " }
+    "#]
+    struct WiderParser;
+
+    type Node<'i> = pest_consume::Node<'i, Rule>;
+
+    impl pest_consume::Parser for WiderParser {
+        type Rule = Rule;
+    }
+
+    impl WiderParser {
+        #[allow(non_snake_case)]
+        fn EOI(_input: Node) -> Result<(), Error<Rule>> {
+            Ok(())
+        }
+
+        fn number(input: Node) -> Result<i64, Error<Rule>> {
+            input
+                .as_str()
+                .parse()
+                .map_err(|_| input.error("not a valid number"))
+        }
+
+        fn stmt(input: Node) -> Result<String, Error<Rule>> {
+            match_nodes!(input.into_children();
+                [keyword("if"), number(n), EOI(_)] => Ok(format!("if {n}")),
+                [keyword("while"), number(n), EOI(_)] => Ok(format!("while {n}")),
+            )
+        }
+    }
+
+    #[test]
+    fn an_unhandled_keyword_reports_no_arm_matched() {
+        let inputs = WiderParser::parse(Rule::stmt, "for 1").unwrap();
+        let input = inputs.single().unwrap();
+        let error = WiderParser::stmt(input).unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("no arm of match_nodes! matched"));
+    }
+}
@@ -0,0 +1,70 @@
+use pest_consume::{match_nodes, Error, Parser as _};
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+ident = @{ ASCII_ALPHA+ }
+type_annotation = { ":" ~ ident }
+block = { "{" ~ "}" }
+func = { SOI ~ ident ~ type_annotation? ~ block ~ EOI }
+"#]
+struct FuncParser;
+
+type Node<'i> = pest_consume::Node<'i, Rule>;
+type PestResult<T> = Result<T, Error<Rule>>;
+
+impl pest_consume::Parser for FuncParser {
+    type Rule = Rule;
+}
+
+impl FuncParser {
+    #[allow(non_snake_case)]
+    fn EOI(_input: Node) -> PestResult<()> {
+        Ok(())
+    }
+
+    fn ident(input: Node) -> PestResult<String> {
+        Ok(input.as_str().to_owned())
+    }
+
+    fn type_annotation(input: Node) -> PestResult<String> {
+        match_nodes!(input.into_children();
+            [ident(ty)] => Ok(ty),
+        )
+    }
+
+    fn block(_input: Node) -> PestResult<()> {
+        Ok(())
+    }
+
+    fn func(input: Node) -> PestResult<(String, Option<String>)> {
+        match_nodes!(input.into_children();
+            [ident(name), type_annotation(ty)?, block(_b), EOI(_)] => Ok((name, ty)),
+        )
+    }
+}
+
+fn eval(input: &str) -> PestResult<(String, Option<String>)> {
+    let inputs = FuncParser::parse(Rule::func, input)?;
+    let input = inputs.single()?;
+    FuncParser::func(input)
+}
+
+#[test]
+fn optional_slot_binds_some_when_present() {
+    assert_eq!(
+        eval("f:int{}").unwrap(),
+        ("f".to_owned(), Some("int".to_owned()))
+    );
+}
+
+#[test]
+fn optional_slot_binds_none_when_absent() {
+    assert_eq!(eval("f{}").unwrap(), ("f".to_owned(), None));
+}
+
+#[test]
+fn optional_slot_sandwiched_between_required_slots_still_requires_the_trailing_one() {
+    // Missing the block entirely should still fail to parse - the optional slot being absent
+    // doesn't make the rest of the pattern optional too.
+    assert!(eval("f").is_err());
+}
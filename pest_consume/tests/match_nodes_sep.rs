@@ -0,0 +1,69 @@
+use pest_consume::{match_nodes, Error, Parser as _};
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+number = @{ ASCII_DIGIT+ }
+comma = { "," }
+number_list = { (number ~ (comma ~ number)* ~ comma?)? }
+numbers = { SOI ~ number_list ~ EOI }
+"#]
+struct NumbersParser;
+
+type Node<'i> = pest_consume::Node<'i, Rule>;
+type PestResult<T> = Result<T, Error<Rule>>;
+
+impl pest_consume::Parser for NumbersParser {
+    type Rule = Rule;
+}
+
+impl NumbersParser {
+    #[allow(non_snake_case)]
+    fn EOI(_input: Node) -> PestResult<()> {
+        Ok(())
+    }
+
+    fn number(input: Node) -> PestResult<u32> {
+        input
+            .as_str()
+            .parse()
+            .map_err(|_| input.error("not a valid number"))
+    }
+
+    fn number_list(input: Node) -> PestResult<Vec<u32>> {
+        match_nodes!(input.into_children();
+            [number(ns) sep comma ..] => Ok(ns),
+        )
+    }
+
+    fn numbers(input: Node) -> PestResult<Vec<u32>> {
+        match_nodes!(input.into_children();
+            [number_list(ns), EOI(_)] => Ok(ns),
+        )
+    }
+}
+
+fn eval(input: &str) -> PestResult<Vec<u32>> {
+    let inputs = NumbersParser::parse(Rule::numbers, input)?;
+    let input = inputs.single()?;
+    NumbersParser::numbers(input)
+}
+
+#[test]
+fn elements_with_no_trailing_separator_are_bound_in_order() {
+    assert_eq!(eval("1,2,3").unwrap(), vec![1, 2, 3]);
+}
+
+#[test]
+fn an_optional_trailing_separator_is_tolerated() {
+    assert_eq!(eval("1,2,3,").unwrap(), vec![1, 2, 3]);
+}
+
+#[test]
+fn an_empty_sequence_binds_an_empty_vec() {
+    assert_eq!(eval("").unwrap(), Vec::<u32>::new());
+}
+
+#[test]
+fn a_single_element_with_no_separator_at_all_still_matches() {
+    assert_eq!(eval("42").unwrap(), vec![42]);
+}
@@ -0,0 +1,56 @@
+use pest_consume::{match_nodes, Error, Parser as _};
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+num = @{ ASCII_DIGIT+ }
+binop = { (#lhs = num ~ "+" ~ #rhs = num) | (#rhs = num ~ "-" ~ #lhs = num) }
+calculation = { SOI ~ binop ~ EOI }
+"#]
+struct TagParser;
+
+type Node<'i> = pest_consume::Node<'i, Rule>;
+type PestResult<T> = Result<T, Error<Rule>>;
+
+impl pest_consume::Parser for TagParser {
+    type Rule = Rule;
+}
+
+impl TagParser {
+    #[allow(non_snake_case)]
+    fn EOI(_input: Node) -> PestResult<()> {
+        Ok(())
+    }
+
+    fn num(input: Node) -> PestResult<i64> {
+        input.as_str().parse().map_err(|_| input.error("not a number"))
+    }
+
+    fn binop(input: Node) -> PestResult<(i64, i64)> {
+        match_nodes!(input.into_children();
+            [#lhs => num(l), #rhs => num(r)] => Ok((l, r)),
+        )
+    }
+
+    fn calculation(input: Node) -> PestResult<(i64, i64)> {
+        match_nodes!(input.into_children();
+            [binop(b), EOI(_)] => Ok(b),
+        )
+    }
+}
+
+fn eval(input: &str) -> PestResult<(i64, i64)> {
+    let inputs = TagParser::parse(Rule::calculation, input)?;
+    let input = inputs.single()?;
+    TagParser::calculation(input)
+}
+
+#[test]
+fn tags_identify_nodes_regardless_of_which_alternative_matched() {
+    assert_eq!(eval("1+2").unwrap(), (1, 2));
+}
+
+#[test]
+fn the_reordered_alternative_still_binds_lhs_and_rhs_correctly() {
+    // Here `rhs` is textually first and `lhs` second - tags, not position, decide the binding.
+    assert_eq!(eval("5-3").unwrap(), (3, 5));
+}
@@ -0,0 +1,63 @@
+use pest::error::LineColLocation;
+use pest_consume::{match_nodes, Error, Parser as _};
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+number = @{ ASCII_DIGIT+ }
+number_list = { (number ~ ("," ~ number)*)? }
+numbers = { SOI ~ number_list ~ EOI }
+"#]
+struct NumbersParser;
+
+type Node<'i> = pest_consume::Node<'i, Rule>;
+type PestResult<T> = Result<T, Error<Rule>>;
+
+impl pest_consume::Parser for NumbersParser {
+    type Rule = Rule;
+}
+
+impl NumbersParser {
+    #[allow(non_snake_case)]
+    fn EOI(_input: Node) -> PestResult<()> {
+        Ok(())
+    }
+
+    fn number(input: Node) -> PestResult<u32> {
+        input
+            .as_str()
+            .parse()
+            .map_err(|_| input.error("not a valid number"))
+    }
+
+    fn number_list(input: Node) -> PestResult<Vec<u32>> {
+        match_nodes!(input.into_children();
+            [number(ns)..] => Ok(ns),
+        )
+    }
+
+    fn numbers(input: Node) -> PestResult<Vec<u32>> {
+        match_nodes!(input.into_children();
+            [number_list(ns), EOI(_)] => Ok(ns),
+        )
+    }
+}
+
+fn eval(input: &str) -> PestResult<Vec<u32>> {
+    let inputs = NumbersParser::parse(Rule::numbers, input)?;
+    let input = inputs.single()?;
+    NumbersParser::numbers(input)
+}
+
+#[test]
+fn trailing_capture_binds_directly_to_a_typed_vec() {
+    assert_eq!(eval("1,2,3").unwrap(), vec![1, 2, 3]);
+}
+
+#[test]
+fn trailing_capture_short_circuits_on_the_first_failing_child() {
+    // `99999999999999` overflows `u32`, so `number` errors on the second item; the third item's
+    // `number` must never run since the whole arm bails out via `?` on the first failure.
+    let err = eval("1,99999999999999,3").unwrap_err();
+    // Points at the second (overflowing) number, not the third.
+    assert_eq!(err.line_col, LineColLocation::Span((1, 3), (1, 17)));
+}
@@ -0,0 +1,75 @@
+use pest_consume::{match_nodes, Error, Parser as _};
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+marker = @{ "M" ~ ASCII_DIGIT }
+ident = @{ ASCII_ALPHA+ }
+num = @{ ASCII_DIGIT+ }
+wrapped = { marker* ~ ident ~ num ~ marker* }
+line = { SOI ~ wrapped ~ EOI }
+WHITESPACE = _{ " " }
+"#]
+struct WrappedParser;
+
+type Node<'i> = pest_consume::Node<'i, Rule>;
+type PestResult<T> = Result<T, Error<Rule>>;
+
+impl pest_consume::Parser for WrappedParser {
+    type Rule = Rule;
+}
+
+impl WrappedParser {
+    #[allow(non_snake_case)]
+    fn EOI(_input: Node) -> PestResult<()> {
+        Ok(())
+    }
+
+    fn ident(input: Node) -> PestResult<String> {
+        Ok(input.as_str().to_owned())
+    }
+
+    fn num(input: Node) -> PestResult<i64> {
+        input.as_str().parse().map_err(|_| input.error("not a number"))
+    }
+
+    fn wrapped(input: Node) -> PestResult<(String, i64)> {
+        match_nodes!(input.into_children();
+            [.., ident(i), num(n), ..] => Ok((i, n)),
+        )
+    }
+
+    fn line(input: Node) -> PestResult<(String, i64)> {
+        match_nodes!(input.into_children();
+            [wrapped(w), EOI(_)] => Ok(w),
+        )
+    }
+}
+
+fn eval(input: &str) -> PestResult<(String, i64)> {
+    let inputs = WrappedParser::parse(Rule::line, input)?;
+    let input = inputs.single()?;
+    WrappedParser::line(input)
+}
+
+#[test]
+fn wildcard_ignores_markers_on_both_ends() {
+    assert_eq!(eval("M1 M2 foo 42 M3").unwrap(), ("foo".to_owned(), 42));
+}
+
+#[test]
+fn wildcard_tolerates_no_markers_at_all() {
+    assert_eq!(eval("foo 42").unwrap(), ("foo".to_owned(), 42));
+}
+
+#[test]
+fn wildcard_tolerates_markers_on_only_one_end() {
+    assert_eq!(eval("M1 foo 42").unwrap(), ("foo".to_owned(), 42));
+    assert_eq!(eval("foo 42 M1 M2").unwrap(), ("foo".to_owned(), 42));
+}
+
+#[test]
+fn wildcard_still_binds_the_named_nodes_in_between_exactly() {
+    let (i, n) = eval("M1 bar 7 M2").unwrap();
+    assert_eq!(i, "bar");
+    assert_eq!(n, 7);
+}
@@ -0,0 +1,45 @@
+#![cfg(feature = "miette")]
+
+use miette::Diagnostic as _;
+use pest_consume::{Error, IntoMietteError, Parser as _};
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+number = @{ ASCII_DIGIT+ }
+numbers = { SOI ~ number ~ EOI }
+"#]
+struct NumberParser;
+
+impl pest_consume::Parser for NumberParser {
+    type Rule = Rule;
+}
+
+#[test]
+fn a_parse_failure_carries_the_attached_source_as_a_labeled_span() {
+    let input = "12x";
+    let err: Error<Rule> = match NumberParser::parse(Rule::numbers, input) {
+        Err(err) => err,
+        Ok(_) => panic!("expected a parse failure"),
+    };
+    let diagnostic = err.with_source(input);
+
+    assert!(diagnostic.source_code().is_some());
+
+    let labels: Vec<_> = diagnostic.labels().unwrap().collect();
+    assert_eq!(labels.len(), 1);
+    // `EOI` is expected right after the digits, at byte offset 2.
+    assert_eq!(labels[0].inner().offset(), 2);
+}
+
+#[test]
+fn a_diagnostic_is_displayable_and_convertible_into_a_report() {
+    let input = "12x";
+    let err = match NumberParser::parse(Rule::numbers, input) {
+        Err(err) => err,
+        Ok(_) => panic!("expected a parse failure"),
+    };
+    let diagnostic = err.with_source(input);
+
+    assert!(!diagnostic.to_string().is_empty());
+    let _report: miette::Report = diagnostic.into();
+}
@@ -0,0 +1,34 @@
+use pest_consume::Parser as _;
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+field = @{ ANY* }
+line = { SOI ~ field ~ EOI }
+"#]
+struct FieldParser;
+
+type Node<'i> = pest_consume::Node<'i, Rule>;
+
+impl pest_consume::Parser for FieldParser {
+    type Rule = Rule;
+}
+
+fn field_node(input: &str) -> Node<'_> {
+    let inputs = FieldParser::parse(Rule::line, input).unwrap();
+    let line = inputs.single().unwrap();
+    line.into_children().next_node().unwrap()
+}
+
+#[test]
+fn as_bytes_matches_as_str_as_bytes() {
+    let node = field_node("hello");
+    assert_eq!(node.as_bytes(), b"hello");
+    assert_eq!(node.as_bytes(), node.as_str().as_bytes());
+}
+
+#[test]
+fn as_bytes_covers_a_full_multi_byte_character_without_splitting_it() {
+    let node = field_node("héllo");
+    assert_eq!(node.as_bytes(), "héllo".as_bytes());
+    assert_eq!(node.as_bytes().len(), "héllo".len());
+}
@@ -0,0 +1,45 @@
+use pest_consume::Parser as _;
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+op = @{ "+" | "-" | "é" }
+line = { SOI ~ op ~ EOI }
+word = @{ ASCII_ALPHA+ }
+"#]
+struct OpParser;
+
+type Node<'i> = pest_consume::Node<'i, Rule>;
+
+impl pest_consume::Parser for OpParser {
+    type Rule = Rule;
+}
+
+fn op_node(input: &str) -> Node<'_> {
+    let inputs = OpParser::parse(Rule::line, input).unwrap();
+    inputs
+        .single()
+        .unwrap()
+        .into_children()
+        .next_node()
+        .unwrap()
+}
+
+#[test]
+fn as_char_returns_the_nodes_single_character() {
+    let node = op_node("+");
+    assert_eq!(node.as_char().unwrap(), '+');
+}
+
+#[test]
+fn as_char_counts_a_multi_byte_character_as_one() {
+    let node = op_node("é");
+    assert_eq!(node.as_char().unwrap(), 'é');
+}
+
+#[test]
+fn as_char_reports_a_located_error_on_more_than_one_character() {
+    let inputs = OpParser::parse(Rule::word, "abc").unwrap();
+    let node = inputs.single().unwrap();
+    let error = node.as_char().unwrap_err();
+    assert!(error.to_string().contains("abc"));
+}
@@ -0,0 +1,93 @@
+use pest_consume::{Error, Parser as _};
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+word = @{ ASCII_ALPHA+ }
+word_list = { word ~ (" " ~ word)* }
+words = { SOI ~ word_list ~ EOI }
+"#]
+struct WordsParser;
+
+type Node<'i> = pest_consume::Node<'i, Rule>;
+type PestResult<T> = Result<T, Error<Rule>>;
+
+impl pest_consume::Parser for WordsParser {
+    type Rule = Rule;
+}
+
+impl WordsParser {
+    fn word(input: Node) -> PestResult<String> {
+        Ok(input.as_str().to_owned())
+    }
+}
+
+#[test]
+fn children_ref_can_be_inspected_without_giving_up_the_node() {
+    let inputs = WordsParser::parse(Rule::words, "the quick fox").unwrap();
+    let word_list = inputs
+        .single()
+        .unwrap()
+        .into_children()
+        .next_node()
+        .unwrap();
+
+    // Peek at the shape via `children_ref` first, without consuming `word_list`...
+    let peeked = word_list
+        .children_ref()
+        .map_to_vec(WordsParser::word)
+        .unwrap();
+    assert_eq!(peeked, vec!["the", "quick", "fox"]);
+    assert_eq!(word_list.as_str(), "the quick fox");
+
+    // ...then still consume the same node with `into_children`.
+    let again = word_list
+        .into_children()
+        .map_to_vec(WordsParser::word)
+        .unwrap();
+    assert_eq!(again, vec!["the", "quick", "fox"]);
+}
+
+#[test]
+fn count_children_counts_without_consuming_the_node() {
+    let inputs = WordsParser::parse(Rule::words, "the quick fox").unwrap();
+    let word_list = inputs.single().unwrap().into_children().next_node().unwrap();
+
+    assert_eq!(word_list.count_children(Rule::word), 3);
+    // `word_list` is still usable afterwards.
+    assert_eq!(word_list.as_str(), "the quick fox");
+}
+
+#[test]
+fn count_children_is_zero_for_a_rule_with_no_matching_children() {
+    let inputs = WordsParser::parse(Rule::words, "the quick fox").unwrap();
+    let word_list = inputs.single().unwrap().into_children().next_node().unwrap();
+
+    assert_eq!(word_list.count_children(Rule::word_list), 0);
+}
+
+#[test]
+fn try_into_children_succeeds_on_a_compound_node() {
+    let inputs = WordsParser::parse(Rule::words, "the quick fox").unwrap();
+    let word_list = inputs.single().unwrap().into_children().next_node().unwrap();
+
+    let words = word_list.try_into_children().unwrap().map_to_vec(WordsParser::word).unwrap();
+    assert_eq!(words, vec!["the", "quick", "fox"]);
+}
+
+#[test]
+fn try_into_children_errors_on_a_leaf_node() {
+    let inputs = WordsParser::parse(Rule::words, "the quick fox").unwrap();
+    let word = inputs
+        .single()
+        .unwrap()
+        .into_children()
+        .next_node()
+        .unwrap()
+        .into_children()
+        .next_node()
+        .unwrap();
+    assert_eq!(word.as_rule(), Rule::word);
+
+    let err = word.try_into_children().err().unwrap();
+    assert!(err.to_string().contains("word has no children to consume"));
+}
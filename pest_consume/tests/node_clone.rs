@@ -0,0 +1,56 @@
+use pest_consume::Parser as _;
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+leaf = @{ ASCII_ALPHANUMERIC+ }
+branch = { leaf ~ (" " ~ leaf)* }
+tree = { SOI ~ branch ~ EOI }
+"#]
+struct TreeParser;
+
+impl pest_consume::Parser for TreeParser {
+    type Rule = Rule;
+}
+
+#[test]
+fn a_clone_is_structurally_equal_to_the_original() {
+    let inputs = TreeParser::parse(Rule::tree, "one two three").unwrap();
+    let tree = inputs.single().unwrap();
+    let branch = tree.into_children().filter_rule(Rule::branch).single().unwrap();
+
+    let cloned = branch.clone();
+    assert!(branch.structural_eq(&cloned));
+    assert_eq!(branch.as_str(), cloned.as_str());
+    // Both handles independently walk their own copy of the remaining sequence.
+    assert_eq!(branch.into_children().count(), cloned.into_children().count());
+}
+
+#[test]
+fn a_clone_shares_the_same_error_buffer_rather_than_getting_its_own() {
+    let (_, errors) = TreeParser::parse_collecting_errors(Rule::tree, "one two three", |nodes| {
+        let tree = nodes.single()?;
+        let cloned = tree.clone();
+        // Recorded through the clone, not the original - a deep copy of the node would have taken
+        // its own independent (and therefore invisible here) error buffer along with it.
+        cloned.emit_error(tree.error("reported via the clone"));
+        Ok(())
+    });
+    assert_eq!(errors.len(), 1);
+}
+
+fn bump_via_a_clone(input_str: &str, count: &mut i64) -> Result<(), pest_consume::Error<Rule>> {
+    let inputs = TreeParser::parse_with_context(Rule::tree, input_str, count)?;
+    let tree = inputs.single()?;
+    let cloned = tree.clone();
+    // Mutated through the clone, not the original - a deep copy of the node would have taken its
+    // own independent `Ctx` along with it instead of sharing this one.
+    *cloned.context_mut() += 1;
+    Ok(())
+}
+
+#[test]
+fn a_clone_shares_the_same_context_rather_than_getting_its_own() {
+    let mut count = 0i64;
+    bump_via_a_clone("one two three", &mut count).unwrap();
+    assert_eq!(count, 1);
+}
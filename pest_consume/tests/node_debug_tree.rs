@@ -0,0 +1,54 @@
+use pest_consume::Parser as _;
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+ident = @{ ASCII_ALPHA+ }
+call = { ident ~ "(" ~ (ident ~ ("," ~ ident)*)? ~ ")" }
+block = { "{" ~ call* ~ "}" }
+program = { SOI ~ block ~ EOI }
+WHITESPACE = _{ " " }
+"#]
+struct ProgramParser;
+
+type Node<'i> = pest_consume::Node<'i, Rule>;
+
+impl pest_consume::Parser for ProgramParser {
+    type Rule = Rule;
+}
+
+fn block_node(input: &str) -> Node<'_> {
+    let inputs = ProgramParser::parse(Rule::program, input).unwrap();
+    inputs
+        .single()
+        .unwrap()
+        .into_children()
+        .next_node()
+        .unwrap()
+}
+
+#[test]
+fn debug_tree_renders_an_indented_outline_of_rules_and_text() {
+    let block = block_node("{ foo(a, b) }");
+    let tree = block.debug_tree();
+    assert_eq!(
+        tree,
+        "block \"{ foo(a, b) }\"\n  call \"foo(a, b)\"\n    ident \"foo\"\n    ident \"a\"\n    ident \"b\""
+    );
+}
+
+#[test]
+fn debug_impl_renders_the_same_as_debug_tree() {
+    let block = block_node("{ foo(a) }");
+    assert_eq!(format!("{block:?}"), block.debug_tree());
+}
+
+#[test]
+fn debug_tree_truncates_long_matched_text() {
+    let long_ident = "a".repeat(60);
+    let input = format!("{{ {long_ident}() }}");
+    let block = block_node(&input);
+    let tree = block.debug_tree();
+    let ident_line = tree.lines().nth(2).unwrap();
+    assert!(ident_line.contains("..."));
+    assert!(ident_line.len() < long_ident.len());
+}
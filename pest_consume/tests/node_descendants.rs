@@ -0,0 +1,61 @@
+use pest_consume::Parser as _;
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+ident = @{ ASCII_ALPHA+ }
+call = { ident ~ "(" ~ (ident ~ ("," ~ ident)*)? ~ ")" }
+block = { "{" ~ call* ~ "}" }
+program = { SOI ~ block ~ EOI }
+WHITESPACE = _{ " " }
+"#]
+struct ProgramParser;
+
+type Node<'i> = pest_consume::Node<'i, Rule>;
+
+impl pest_consume::Parser for ProgramParser {
+    type Rule = Rule;
+}
+
+fn block_node(input: &str) -> Node<'_> {
+    let inputs = ProgramParser::parse(Rule::program, input).unwrap();
+    inputs
+        .single()
+        .unwrap()
+        .into_children()
+        .next_node()
+        .unwrap()
+}
+
+#[test]
+fn descendants_walks_the_whole_subtree_in_pre_order() {
+    let block = block_node("{ foo(a, b) bar(c) }");
+    let descendants: Vec<(Rule, &str)> = block
+        .descendants()
+        .map(|n| (n.as_rule(), n.as_str()))
+        .collect();
+    assert_eq!(
+        descendants,
+        vec![
+            (Rule::call, "foo(a, b)"),
+            (Rule::ident, "foo"),
+            (Rule::ident, "a"),
+            (Rule::ident, "b"),
+            (Rule::call, "bar(c)"),
+            (Rule::ident, "bar"),
+            (Rule::ident, "c"),
+        ]
+    );
+}
+
+#[test]
+fn find_all_filters_descendants_down_to_one_rule() {
+    let block = block_node("{ foo(a, b) bar(c) }");
+    let idents: Vec<&str> = block.find_all(Rule::ident).map(|n| n.as_str()).collect();
+    assert_eq!(idents, vec!["foo", "a", "b", "bar", "c"]);
+}
+
+#[test]
+fn an_empty_subtree_has_no_descendants() {
+    let block = block_node("{ }");
+    assert_eq!(block.descendants().count(), 0);
+}
@@ -0,0 +1,86 @@
+use pest_consume::{NodeDiff, Parser as _};
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+number = @{ ASCII_DIGIT+ }
+list = { number ~ ("," ~ number)* }
+line = { SOI ~ list ~ EOI }
+"#]
+struct ListParser;
+
+impl pest_consume::Parser for ListParser {
+    type Rule = Rule;
+}
+
+fn list_node(input: &str) -> pest_consume::Node<'_, Rule> {
+    ListParser::parse(Rule::line, input)
+        .unwrap()
+        .single()
+        .unwrap()
+        .into_children()
+        .next_node()
+        .unwrap()
+}
+
+#[test]
+fn identical_trees_have_no_diffs() {
+    let a = list_node("1,2,3");
+    let b = list_node("1,2,3");
+    assert_eq!(a.diff(&b), vec![]);
+}
+
+#[test]
+fn a_changed_leaf_is_reported_with_its_old_and_new_text() {
+    let a = list_node("1,2,3");
+    let b = list_node("1,9,3");
+    let diffs = a.diff(&b);
+    assert_eq!(diffs.len(), 1);
+    match &diffs[0] {
+        NodeDiff::Changed { old_text, new_text, .. } => {
+            assert_eq!(old_text, "2");
+            assert_eq!(new_text, "9");
+        }
+        other => panic!("expected Changed, got {other:?}"),
+    }
+}
+
+#[test]
+fn an_added_trailing_number_is_reported_as_added() {
+    let a = list_node("1,2");
+    let b = list_node("1,2,3");
+    let diffs = a.diff(&b);
+    assert_eq!(diffs.len(), 1);
+    match &diffs[0] {
+        NodeDiff::Added { rule, text, .. } => {
+            assert_eq!(*rule, Rule::number);
+            assert_eq!(text, "3");
+        }
+        other => panic!("expected Added, got {other:?}"),
+    }
+}
+
+#[test]
+fn a_removed_trailing_number_is_reported_as_removed() {
+    let a = list_node("1,2,3");
+    let b = list_node("1,2");
+    let diffs = a.diff(&b);
+    assert_eq!(diffs.len(), 1);
+    match &diffs[0] {
+        NodeDiff::Removed { rule, text, .. } => {
+            assert_eq!(*rule, Rule::number);
+            assert_eq!(text, "3");
+        }
+        other => panic!("expected Removed, got {other:?}"),
+    }
+}
+
+#[test]
+fn the_path_of_a_changed_leaf_names_its_rule_and_index() {
+    let a = list_node("1,2,3");
+    let b = list_node("1,9,3");
+    let diffs = a.diff(&b);
+    let NodeDiff::Changed { path, .. } = &diffs[0] else {
+        panic!("expected Changed, got {:?}", diffs[0]);
+    };
+    assert_eq!(path.to_string(), "number[1]");
+}
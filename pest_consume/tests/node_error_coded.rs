@@ -0,0 +1,60 @@
+use pest_consume::{CodedError, Parser as _};
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+name = @{ ASCII_ALPHA+ }
+reference = { SOI ~ name ~ EOI }
+"#]
+struct RefParser;
+
+type Node<'i> = pest_consume::Node<'i, Rule>;
+
+impl pest_consume::Parser for RefParser {
+    type Rule = Rule;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ErrorCode {
+    UndefinedVariable,
+}
+
+impl RefParser {
+    fn name(input: Node) -> Result<String, CodedError<Rule, ErrorCode>> {
+        let name = input.as_str();
+        if name == "defined" {
+            Ok(name.to_owned())
+        } else {
+            Err(input.error_coded(ErrorCode::UndefinedVariable, format!("undefined variable: {name}")))
+        }
+    }
+
+    fn reference(input: Node) -> Result<String, CodedError<Rule, ErrorCode>> {
+        let name = input.into_children().next_node().unwrap();
+        RefParser::name(name)
+    }
+}
+
+fn eval(input: &str) -> Result<String, CodedError<Rule, ErrorCode>> {
+    let inputs = RefParser::parse(Rule::reference, input).unwrap();
+    let input = inputs.single().unwrap();
+    RefParser::reference(input)
+}
+
+#[test]
+fn a_defined_name_resolves() {
+    assert_eq!(eval("defined"), Ok("defined".to_owned()));
+}
+
+#[test]
+fn an_undefined_name_carries_its_code_for_programmatic_matching() {
+    let err = eval("nope").unwrap_err();
+    assert_eq!(*err.code(), ErrorCode::UndefinedVariable);
+    assert!(err.to_string().contains("undefined variable: nope"));
+}
+
+#[test]
+fn into_error_discards_the_code_and_keeps_the_plain_error() {
+    let err = eval("nope").unwrap_err();
+    let plain = err.into_error();
+    assert!(plain.to_string().contains("undefined variable: nope"));
+}
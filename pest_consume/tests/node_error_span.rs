@@ -0,0 +1,49 @@
+use pest_consume::Parser as _;
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+call = @{ ident ~ "(" ~ ident ~ ("," ~ ident)* ~ ")" }
+ident = @{ ASCII_ALPHA+ }
+line = { SOI ~ call ~ EOI }
+"#]
+struct CallParser;
+
+type Node<'i> = pest_consume::Node<'i, Rule>;
+
+impl pest_consume::Parser for CallParser {
+    type Rule = Rule;
+}
+
+fn call_node(input: &str) -> Node<'_> {
+    let inputs = CallParser::parse(Rule::line, input).unwrap();
+    inputs
+        .single()
+        .unwrap()
+        .into_children()
+        .next_node()
+        .unwrap()
+}
+
+#[test]
+fn error_with_span_points_at_the_given_span_rather_than_the_whole_node() {
+    let node = call_node("foo(bar,baz)");
+    let arg_span = node.as_span().get(4..7).unwrap();
+    let error = node.error_with_span("unknown argument", arg_span);
+    let message = error.to_string();
+    assert!(message.contains("unknown argument"));
+    assert!(message.contains("bar"));
+}
+
+#[test]
+fn error_at_str_finds_and_points_at_the_first_occurrence() {
+    let node = call_node("foo(bar,baz)");
+    let error = node.error_at_str("unknown argument", "baz");
+    assert!(error.to_string().contains("baz"));
+}
+
+#[test]
+fn error_at_str_falls_back_to_the_whole_node_when_the_needle_is_absent() {
+    let node = call_node("foo(bar,baz)");
+    let error = node.error_at_str("unknown argument", "nope");
+    assert!(error.to_string().contains("foo(bar,baz)"));
+}
@@ -0,0 +1,55 @@
+use pest_consume::Parser as _;
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+number = @{ ASCII_DIGIT+ }
+pair = { "(" ~ number ~ "," ~ number ~ ")" }
+expr = { SOI ~ pair ~ EOI }
+"#]
+struct ExprParser;
+
+impl pest_consume::Parser for ExprParser {
+    type Rule = Rule;
+}
+
+fn pair_node(input: &str) -> pest_consume::Node<'_, Rule> {
+    let inputs = ExprParser::parse(Rule::expr, input).unwrap();
+    inputs.single().unwrap().into_children().next_node().unwrap()
+}
+
+#[test]
+fn clones_of_the_same_node_report_the_same_id() {
+    let node = pair_node("(1,2)");
+    let clone = node.clone();
+    assert_eq!(node.id(), clone.id());
+}
+
+#[test]
+fn repeated_peeks_of_the_same_upcoming_node_report_the_same_id() {
+    let mut children = pair_node("(1,2)").into_children();
+    let first_peek = children.peek().unwrap().id();
+    let second_peek = children.peek().unwrap().id();
+    assert_eq!(first_peek, second_peek);
+    assert_eq!(children.next_node().unwrap().id(), first_peek);
+}
+
+#[test]
+fn a_parent_and_child_report_different_ids() {
+    let parent = pair_node("(1,2)");
+    let child = parent.clone().into_children().next_node().unwrap();
+    assert_ne!(parent.id(), child.id());
+}
+
+#[test]
+fn sibling_nodes_with_different_spans_report_different_ids() {
+    let parent = pair_node("(1,2)");
+    let mut children = parent.into_children();
+    let first = children.next_node().unwrap().id();
+    let second = children.next_node().unwrap().id();
+    assert_ne!(first, second);
+}
+
+#[test]
+fn the_same_input_parsed_twice_assigns_the_same_ids() {
+    assert_eq!(pair_node("(1,2)").id(), pair_node("(1,2)").id());
+}
@@ -0,0 +1,87 @@
+use std::cell::RefCell;
+
+use pest_consume::{Error, Parser as _};
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+number = @{ ASCII_DIGIT+ }
+numbers = { SOI ~ number* ~ EOI }
+WHITESPACE = _{ " " }
+"#]
+struct NumbersParser;
+
+type Node<'i> = pest_consume::Node<'i, Rule>;
+type PestResult<T> = Result<T, Error<Rule>>;
+
+impl pest_consume::Parser for NumbersParser {
+    type Rule = Rule;
+}
+
+impl NumbersParser {
+    fn number(input: Node) -> PestResult<u32> {
+        input.parse_str()
+    }
+
+    fn numbers<'i>(input: Node<'i>) -> impl Iterator<Item = PestResult<u32>> + 'i {
+        input
+            .into_children()
+            .exclude_rule(Rule::EOI)
+            .into_consuming_iter(NumbersParser::number)
+    }
+}
+
+fn parse(input: &str) -> Node<'_> {
+    let inputs = NumbersParser::parse(Rule::numbers, input).unwrap();
+    inputs.single().unwrap()
+}
+
+#[test]
+fn into_consuming_iter_yields_every_mapped_value_in_order() {
+    let root = parse("1 2 3");
+    let values: Vec<_> = NumbersParser::numbers(root).map(Result::unwrap).collect();
+    assert_eq!(values, vec![1, 2, 3]);
+}
+
+#[test]
+fn into_consuming_iter_only_maps_a_node_once_the_iterator_is_advanced() {
+    let root = parse("1 2 3");
+    let mapped = RefCell::new(Vec::new());
+    let mut iter = root
+        .into_children()
+        .exclude_rule(Rule::EOI)
+        .into_consuming_iter(|node| {
+            let value: u32 = node.parse_str()?;
+            mapped.borrow_mut().push(value);
+            Ok(value)
+        });
+
+    assert!(mapped.borrow().is_empty());
+    assert_eq!(iter.next(), Some(Ok(1)));
+    assert_eq!(*mapped.borrow(), vec![1]);
+    assert_eq!(iter.next(), Some(Ok(2)));
+    assert_eq!(iter.next(), Some(Ok(3)));
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn into_consuming_iter_stops_for_good_after_the_first_error() {
+    let root = parse("1 2 3");
+    let mut seen = 0;
+    let mut iter = root
+        .into_children()
+        .exclude_rule(Rule::EOI)
+        .into_consuming_iter(|node| {
+            seen += 1;
+            if seen == 2 {
+                Err(node.error("deliberate failure"))
+            } else {
+                node.parse_str()
+            }
+        });
+
+    assert_eq!(iter.next(), Some(Ok(1)));
+    assert!(iter.next().unwrap().is_err());
+    assert_eq!(iter.next(), None);
+    drop(iter);
+    assert_eq!(seen, 2);
+}
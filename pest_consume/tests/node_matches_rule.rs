@@ -0,0 +1,43 @@
+use pest_consume::Parser as _;
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+ident = @{ ASCII_ALPHA+ }
+number = @{ ASCII_DIGIT+ }
+item = { SOI ~ (ident | number) ~ EOI }
+"#]
+struct ItemParser;
+
+impl pest_consume::Parser for ItemParser {
+    type Rule = Rule;
+}
+
+fn first_child(input: &str) -> pest_consume::Node<'_, Rule> {
+    let inputs = ItemParser::parse(Rule::item, input).unwrap();
+    inputs.single().unwrap().into_children().next_node().unwrap()
+}
+
+#[test]
+fn matches_rule_checks_a_single_rule() {
+    let node = first_child("foo");
+    assert!(node.matches_rule(Rule::ident));
+    assert!(!node.matches_rule(Rule::number));
+}
+
+#[test]
+fn matches_any_checks_a_set_of_rules() {
+    let node = first_child("42");
+    assert!(node.matches_any(&[Rule::ident, Rule::number]));
+    assert!(!node.matches_any(&[Rule::ident, Rule::EOI]));
+}
+
+#[test]
+fn next_if_rule_consumes_only_on_a_match() {
+    let inputs = ItemParser::parse(Rule::item, "foo").unwrap();
+    let mut children = inputs.single().unwrap().into_children();
+
+    assert!(children.next_if_rule(Rule::number).is_none());
+    let node = children.next_if_rule(Rule::ident).unwrap();
+    assert_eq!(node.as_str(), "foo");
+    assert_eq!(children.next_node().unwrap().as_rule(), Rule::EOI);
+}
@@ -0,0 +1,55 @@
+use std::cell::Cell;
+
+use pest_consume::{Error, Memo, Parser as _};
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+number = @{ ASCII_DIGIT+ }
+expr = { SOI ~ number ~ EOI }
+"#]
+struct ExprParser;
+
+type Node<'i> = pest_consume::Node<'i, Rule>;
+type PestResult<T> = Result<T, Error<Rule>>;
+
+impl pest_consume::Parser for ExprParser {
+    type Rule = Rule;
+}
+
+thread_local! {
+    static CALLS: Cell<usize> = const { Cell::new(0) };
+}
+
+impl ExprParser {
+    fn number<'i>(input: Node<'i>, memo: &Memo<'i, Rule, i64>) -> PestResult<i64> {
+        input.memoize(memo, |input| {
+            CALLS.with(|c| c.set(c.get() + 1));
+            input.parse_str()
+        })
+    }
+}
+
+#[test]
+fn a_repeated_call_with_the_same_span_returns_the_cached_value_without_rerunning_the_closure() {
+    CALLS.with(|c| c.set(0));
+    let memo = Memo::new();
+    let inputs = ExprParser::parse(Rule::expr, "42").unwrap();
+    let children = inputs.single().unwrap().into_children();
+
+    assert_eq!(ExprParser::number(children.peek().unwrap(), &memo).unwrap(), 42);
+    assert_eq!(ExprParser::number(children.peek().unwrap(), &memo).unwrap(), 42);
+    assert_eq!(CALLS.with(|c| c.get()), 1);
+}
+
+#[test]
+fn a_fresh_memo_does_not_share_a_cache_with_another() {
+    CALLS.with(|c| c.set(0));
+    let inputs = ExprParser::parse(Rule::expr, "42").unwrap();
+    let children = inputs.single().unwrap().into_children();
+
+    let first_memo = Memo::new();
+    assert_eq!(ExprParser::number(children.peek().unwrap(), &first_memo).unwrap(), 42);
+    let second_memo = Memo::new();
+    assert_eq!(ExprParser::number(children.peek().unwrap(), &second_memo).unwrap(), 42);
+    assert_eq!(CALLS.with(|c| c.get()), 2);
+}
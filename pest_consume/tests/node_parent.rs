@@ -0,0 +1,72 @@
+use pest_consume::Parser as _;
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+ident = @{ ASCII_ALPHA+ }
+stmt = { ident }
+block = { SOI ~ stmt ~ EOI }
+"#]
+struct BlockParser;
+
+type Node<'i> = pest_consume::Node<'i, Rule>;
+
+impl pest_consume::Parser for BlockParser {
+    type Rule = Rule;
+}
+
+fn find_child<'i>(node: &Node<'i>, rule: Rule) -> Node<'i> {
+    let mut children = node.children_ref();
+    std::iter::from_fn(|| children.next_node())
+        .find(|n| n.as_rule() == rule)
+        .unwrap()
+}
+
+#[test]
+fn parent_walks_back_up_the_tree_when_parsed_with_parse_parented() {
+    let inputs = BlockParser::parse_parented(Rule::block, "hello").unwrap();
+    let block = inputs.single().unwrap();
+    let stmt = find_child(&block, Rule::stmt);
+    let ident = find_child(&stmt, Rule::ident);
+
+    assert_eq!(ident.as_str(), "hello");
+    let parent = ident.parent().unwrap();
+    assert_eq!(parent.as_rule(), Rule::stmt);
+    let grandparent = parent.parent().unwrap();
+    assert_eq!(grandparent.as_rule(), Rule::block);
+    assert!(grandparent.parent().is_none());
+}
+
+#[test]
+fn parent_is_always_none_without_parse_parented() {
+    let inputs = BlockParser::parse(Rule::block, "hello").unwrap();
+    let block = inputs.single().unwrap();
+    let stmt = find_child(&block, Rule::stmt);
+    assert!(stmt.parent().is_none());
+}
+
+#[test]
+fn rule_path_lists_ancestors_from_the_root_down_and_prefixes_error_messages() {
+    let inputs = BlockParser::parse_parented(Rule::block, "hello").unwrap();
+    let block = inputs.single().unwrap();
+    let stmt = find_child(&block, Rule::stmt);
+    let ident = find_child(&stmt, Rule::ident);
+
+    assert_eq!(block.rule_path(), Vec::<Rule>::new());
+    assert_eq!(stmt.rule_path(), vec![Rule::block]);
+    assert_eq!(ident.rule_path(), vec![Rule::block, Rule::stmt]);
+
+    let err = ident.error("not allowed here");
+    assert!(err.to_string().contains("block > stmt > ident: not allowed here"));
+}
+
+#[test]
+fn rule_path_is_empty_and_errors_are_unprefixed_without_parse_parented() {
+    let inputs = BlockParser::parse(Rule::block, "hello").unwrap();
+    let block = inputs.single().unwrap();
+    let stmt = find_child(&block, Rule::stmt);
+    let ident = find_child(&stmt, Rule::ident);
+
+    assert_eq!(ident.rule_path(), Vec::<Rule>::new());
+    let err = ident.error("not allowed here");
+    assert!(!err.to_string().contains(" > "));
+}
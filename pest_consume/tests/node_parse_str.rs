@@ -0,0 +1,37 @@
+use pest_consume::Parser as _;
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+num = @{ ASCII_DIGIT+ ~ ("." ~ ASCII_DIGIT+)? }
+line = { SOI ~ num ~ EOI }
+"#]
+struct NumParser;
+
+type Node<'i> = pest_consume::Node<'i, Rule>;
+
+impl pest_consume::Parser for NumParser {
+    type Rule = Rule;
+}
+
+fn num_node(input: &str) -> Node<'_> {
+    let inputs = NumParser::parse(Rule::line, input).unwrap();
+    inputs
+        .single()
+        .unwrap()
+        .into_children()
+        .next_node()
+        .unwrap()
+}
+
+#[test]
+fn parse_str_parses_the_nodes_text_as_the_requested_type() {
+    let node = num_node("3.5");
+    assert_eq!(node.parse_str::<f64>().unwrap(), 3.5);
+}
+
+#[test]
+fn parse_str_reports_a_located_error_on_failure() {
+    let node = num_node("9999999999999999999999999999999999");
+    let error = node.parse_str::<u8>().unwrap_err();
+    assert!(error.to_string().contains("9999999999999999999999999999999999"));
+}
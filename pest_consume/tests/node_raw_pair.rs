@@ -0,0 +1,41 @@
+use pest_consume::Parser as _;
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+word = @{ ASCII_ALPHA+ }
+words = { SOI ~ word ~ EOI }
+"#]
+struct WordsParser;
+
+type Node<'i> = pest_consume::Node<'i, Rule>;
+
+impl pest_consume::Parser for WordsParser {
+    type Rule = Rule;
+}
+
+fn word_node(input: &str) -> Node<'_> {
+    WordsParser::parse(Rule::words, input)
+        .unwrap()
+        .single()
+        .unwrap()
+        .into_children()
+        .next_node()
+        .unwrap()
+}
+
+#[test]
+fn into_pair_hands_back_the_underlying_pest_pair() {
+    let node = word_node("hello");
+    let pair = node.into_pair();
+    assert_eq!(pair.as_rule(), Rule::word);
+    assert_eq!(pair.as_str(), "hello");
+}
+
+#[test]
+fn new_re_enters_pest_consume_from_a_raw_pair() {
+    let pair = word_node("hello").into_pair();
+    let rebuilt: Node = pest_consume::Node::new(pair, ());
+    assert_eq!(rebuilt.as_rule(), Rule::word);
+    assert_eq!(rebuilt.as_str(), "hello");
+    assert!(rebuilt.parent().is_none());
+}
@@ -0,0 +1,60 @@
+use pest_consume::Parser as _;
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+ident = @{ ASCII_ALPHA+ }
+call = { ident ~ "(" ~ (ident ~ ("," ~ ident)*)? ~ ")" }
+block = { "{" ~ call* ~ "}" }
+program = { SOI ~ block ~ EOI }
+WHITESPACE = _{ " " }
+"#]
+struct ProgramParser;
+
+type Node<'i> = pest_consume::Node<'i, Rule>;
+
+impl pest_consume::Parser for ProgramParser {
+    type Rule = Rule;
+}
+
+fn block_node(input: &str) -> Node<'_> {
+    let inputs = ProgramParser::parse(Rule::program, input).unwrap();
+    inputs.single().unwrap().into_children().next_node().unwrap()
+}
+
+#[test]
+fn with_no_overrides_reconstruct_reproduces_the_original_slice_exactly() {
+    let source = "{ foo(a, b)  bar(c) }";
+    let block = block_node(source);
+    assert_eq!(block.reconstruct(|_| None), block.as_str());
+    assert_eq!(block.reconstruct(|_| None), source);
+}
+
+#[test]
+fn reconstruct_can_rename_one_identifier_while_leaving_everything_else_untouched() {
+    let source = "{ foo(a, b)  bar(a) }";
+    let block = block_node(source);
+    let renamed = block.reconstruct(|node| {
+        if node.as_rule() == Rule::ident && node.as_str() == "a" {
+            Some("renamed".to_owned())
+        } else {
+            None
+        }
+    });
+    assert_eq!(renamed, "{ foo(renamed, b)  bar(renamed) }");
+}
+
+#[test]
+fn an_override_on_a_node_skips_reconstructing_its_children() {
+    let source = "{ foo(a, b) }";
+    let block = block_node(source);
+    let replaced = block.reconstruct(|node| {
+        if node.as_rule() == Rule::call {
+            Some("REDACTED".to_owned())
+        } else if node.as_rule() == Rule::ident {
+            panic!("should never be asked about a child of a node already overridden");
+        } else {
+            None
+        }
+    });
+    assert_eq!(replaced, "{ REDACTED }");
+}
@@ -0,0 +1,56 @@
+use pest_consume::Parser as _;
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+ident = @{ ASCII_ALPHA+ }
+line = { "\t"* ~ ident ~ "\n"? }
+file = { SOI ~ line+ ~ EOI }
+"#]
+struct FileParser;
+
+impl pest_consume::Parser for FileParser {
+    type Rule = Rule;
+}
+
+fn ident_node<'i>(input: &'i str, n: usize) -> pest_consume::Node<'i, Rule> {
+    let inputs = FileParser::parse(Rule::file, input).unwrap();
+    inputs
+        .single()
+        .unwrap()
+        .into_children()
+        .filter_rule(Rule::line)
+        .nth(n)
+        .unwrap()
+        .into_children()
+        .next_node()
+        .unwrap()
+}
+
+#[test]
+fn renders_a_single_line_excerpt_with_a_caret_under_the_span() {
+    let node = ident_node("foo\nbar\nbaz\n", 1);
+    assert_eq!(node.as_str(), "bar");
+    let rendered = node.render_context(0, 0);
+    assert_eq!(rendered, "2 | bar\n  | ^^^");
+}
+
+#[test]
+fn includes_the_requested_number_of_context_lines() {
+    let node = ident_node("foo\nbar\nbaz\n", 1);
+    let rendered = node.render_context(1, 1);
+    assert_eq!(rendered, "1 | foo\n2 | bar\n  | ^^^\n3 | baz");
+}
+
+#[test]
+fn clamps_context_lines_at_the_edges_of_the_source() {
+    let node = ident_node("foo\nbar\nbaz\n", 0);
+    let rendered = node.render_context(5, 0);
+    assert_eq!(rendered, "1 | foo\n  | ^^^");
+}
+
+#[test]
+fn expands_tabs_in_both_the_source_line_and_the_caret() {
+    let node = ident_node("\tfoo\n", 0);
+    let rendered = node.render_context(0, 0);
+    assert_eq!(rendered, "1 |     foo\n  |     ^^^");
+}
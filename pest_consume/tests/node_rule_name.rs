@@ -0,0 +1,23 @@
+use pest_consume::Parser as _;
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+ident = @{ ASCII_ALPHA+ }
+program = { SOI ~ ident ~ EOI }
+"#]
+struct ProgramParser;
+
+impl pest_consume::Parser for ProgramParser {
+    type Rule = Rule;
+}
+
+#[test]
+fn rule_name_matches_the_grammar_rule_identifier() {
+    let inputs = ProgramParser::parse(Rule::program, "foo").unwrap();
+    let program = inputs.single().unwrap();
+    assert_eq!(program.as_rule(), Rule::program);
+    assert_eq!(program.rule_name(), "program");
+
+    let ident = program.into_children().next_node().unwrap();
+    assert_eq!(ident.rule_name(), "ident");
+}
@@ -0,0 +1,72 @@
+use pest_consume::{Error, Parser as _};
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+item = @{ ASCII_ALPHA+ }
+list = { SOI ~ item ~ ("," ~ item)* ~ EOI }
+"#]
+struct ListParser;
+
+type Node<'i> = pest_consume::Node<'i, Rule>;
+type PestResult<T> = Result<T, Error<Rule>>;
+
+impl pest_consume::Parser for ListParser {
+    type Rule = Rule;
+}
+
+impl ListParser {
+    fn item(input: Node) -> PestResult<(usize, String)> {
+        Ok((input.sibling_index().unwrap(), input.as_str().to_owned()))
+    }
+
+    fn list(input: Node) -> PestResult<Vec<(usize, String)>> {
+        input
+            .children_ref()
+            .filter(|child| child.as_rule() == Rule::item)
+            .map(ListParser::item)
+            .collect()
+    }
+}
+
+fn eval(input: &str) -> Vec<(usize, String)> {
+    let inputs = ListParser::parse(Rule::list, input).unwrap();
+    let input = inputs.single().unwrap();
+    ListParser::list(input).unwrap()
+}
+
+#[test]
+fn sibling_index_counts_up_from_zero_among_direct_children() {
+    assert_eq!(
+        eval("a,b,c"),
+        vec![(0, "a".to_owned()), (1, "b".to_owned()), (2, "c".to_owned())]
+    );
+}
+
+#[test]
+fn sibling_index_lets_a_consumer_special_case_the_first_and_last_element() {
+    let items = eval("a,b,c");
+    let first = items.first().unwrap();
+    let last = items.last().unwrap();
+    assert_eq!(first.0, 0);
+    assert_eq!(last.0, items.iter().map(|(i, _)| *i).max().unwrap());
+}
+
+#[test]
+fn sibling_index_resets_to_zero_for_each_fresh_child_sequence() {
+    let inputs = ListParser::parse(Rule::list, "a,b").unwrap();
+    let list = inputs.single().unwrap();
+    let first_child = list.children_ref().next_node().unwrap();
+    assert_eq!(first_child.sibling_index(), Some(0));
+
+    let grandchild_view = first_child.children_ref();
+    assert!(grandchild_view.is_empty());
+}
+
+#[test]
+fn sibling_index_is_none_for_a_node_built_directly_from_a_pair() {
+    let inputs = ListParser::parse(Rule::list, "a").unwrap();
+    let list = inputs.single().unwrap();
+    let pair = list.into_pair();
+    let reentered = pest_consume::Node::new(pair, ());
+    assert_eq!(reentered.sibling_index(), None);
+}
@@ -0,0 +1,75 @@
+use pest_consume::Parser as _;
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+name = @{ ASCII_ALPHA+ }
+arg = @{ ASCII_DIGIT+ }
+call = { name ~ "(" ~ arg ~ ("," ~ arg)* ~ ")" }
+line = { SOI ~ call ~ EOI }
+"#]
+struct CallParser;
+
+impl pest_consume::Parser for CallParser {
+    type Rule = Rule;
+}
+
+fn call_node(input: &str) -> pest_consume::Node<'_, Rule> {
+    CallParser::parse(Rule::line, input)
+        .unwrap()
+        .single()
+        .unwrap()
+        .into_children()
+        .next_node()
+        .unwrap()
+}
+
+fn args<'i>(call: &pest_consume::Node<'i, Rule>) -> Vec<pest_consume::Node<'i, Rule>> {
+    call.find_all(Rule::arg).collect()
+}
+
+#[test]
+fn span_to_covers_from_this_nodes_start_to_others_end() {
+    let call = call_node("add(1,2,3)");
+    let args = args(&call);
+    let span = args[0].span_to(&args[2]).unwrap();
+    assert_eq!(span.as_str(), "1,2,3");
+}
+
+#[test]
+fn span_to_errs_if_other_ends_before_self_starts() {
+    let call = call_node("add(1,2,3)");
+    let args = args(&call);
+    assert!(args[2].span_to(&args[0]).is_err());
+}
+
+#[test]
+fn span_to_errs_across_different_inputs() {
+    let a = call_node("add(1,2,3)");
+    let b = call_node("add(4,5,6)");
+    let a_args = args(&a);
+    let b_args = args(&b);
+    assert!(a_args[0].span_to(&b_args[2]).is_err());
+}
+
+#[test]
+fn span_merge_covers_every_node_in_the_slice() {
+    let call = call_node("add(1,2,3)");
+    let args = args(&call);
+    let span = pest_consume::Node::span_merge(&args).unwrap();
+    assert_eq!(span.as_str(), "1,2,3");
+}
+
+#[test]
+fn span_merge_of_an_empty_slice_is_none() {
+    let args: Vec<pest_consume::Node<'_, Rule>> = Vec::new();
+    assert!(pest_consume::Node::span_merge(&args).is_none());
+}
+
+#[test]
+fn span_merge_across_different_inputs_is_none() {
+    let a = call_node("add(1,2,3)");
+    let b = call_node("add(4,5,6)");
+    let mut mixed = args(&a);
+    mixed.extend(args(&b));
+    assert!(pest_consume::Node::span_merge(&mixed).is_none());
+}
@@ -0,0 +1,57 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::Hasher;
+
+use pest_consume::Parser as _;
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+number = @{ ASCII_DIGIT+ }
+pair = { "(" ~ number ~ "," ~ number ~ ")" }
+expr = { SOI ~ pair ~ EOI }
+"#]
+struct ExprParser;
+
+impl pest_consume::Parser for ExprParser {
+    type Rule = Rule;
+}
+
+fn pair_node(input: &str) -> pest_consume::Node<'_, Rule> {
+    let inputs = ExprParser::parse(Rule::expr, input).unwrap();
+    inputs.single().unwrap().into_children().next_node().unwrap()
+}
+
+fn hash_of(node: &pest_consume::Node<'_, Rule>) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    node.structural_hash(&mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn identical_text_parsed_from_different_inputs_is_structurally_equal() {
+    let a = pair_node("(1,2)");
+    let b = pair_node("(1,2)");
+    assert!(a.structural_eq(&b));
+    assert_eq!(hash_of(&a), hash_of(&b));
+}
+
+#[test]
+fn identical_text_embedded_at_a_different_offset_is_still_structurally_equal() {
+    let a = pair_node("(1,2)");
+    let b = pair_node("(1,2)"); // same text, but compared regardless of absolute span offsets
+    assert!(a.structural_eq(&b));
+}
+
+#[test]
+fn different_text_is_not_structurally_equal() {
+    let a = pair_node("(1,2)");
+    let b = pair_node("(1,3)");
+    assert!(!a.structural_eq(&b));
+    assert_ne!(hash_of(&a), hash_of(&b));
+}
+
+#[test]
+fn a_node_with_fewer_children_is_not_structurally_equal_to_one_with_more() {
+    let a = pair_node("(1,2)").into_children().next_node().unwrap();
+    let b = pair_node("(1,2)");
+    assert!(!a.structural_eq(&b));
+}
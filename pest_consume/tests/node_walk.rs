@@ -0,0 +1,101 @@
+use pest_consume::{Parser as _, Visitor, WalkControl};
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+item = @{ ASCII_ALPHA+ }
+block = { "{" ~ (item | block)* ~ "}" }
+WHITESPACE = _{ " " }
+"#]
+struct BlockParser;
+
+type Node<'i> = pest_consume::Node<'i, Rule>;
+
+impl pest_consume::Parser for BlockParser {
+    type Rule = Rule;
+}
+
+fn root(input: &str) -> Node<'_> {
+    BlockParser::parse(Rule::block, input).unwrap().single().unwrap()
+}
+
+#[derive(Default)]
+struct EnterLeaveLog {
+    events: Vec<String>,
+}
+
+impl<'i> Visitor<'i, Rule> for EnterLeaveLog {
+    fn enter(&mut self, node: &Node<'i>) -> WalkControl {
+        self.events.push(format!("enter {:?}", node.as_rule()));
+        WalkControl::Continue
+    }
+
+    fn leave(&mut self, node: &Node<'i>) {
+        self.events.push(format!("leave {:?}", node.as_rule()));
+    }
+}
+
+#[test]
+fn walk_visits_every_node_depth_first_pre_order() {
+    let mut log = EnterLeaveLog::default();
+    root("{ a { b } }").walk(&mut log);
+    assert_eq!(
+        log.events,
+        vec![
+            "enter block",
+            "enter item",
+            "leave item",
+            "enter block",
+            "enter item",
+            "leave item",
+            "leave block",
+            "leave block",
+        ]
+    );
+}
+
+struct SkipNestedBlocks {
+    entered: Vec<String>,
+}
+
+impl<'i> Visitor<'i, Rule> for SkipNestedBlocks {
+    fn enter(&mut self, node: &Node<'i>) -> WalkControl {
+        self.entered.push(format!("{:?}", node.as_rule()));
+        if node.as_rule() == Rule::block && node.depth() > 0 {
+            WalkControl::SkipChildren
+        } else {
+            WalkControl::Continue
+        }
+    }
+}
+
+#[test]
+fn skip_children_prunes_that_nodes_subtree_but_not_its_siblings() {
+    let mut visitor = SkipNestedBlocks { entered: Vec::new() };
+    root("{ a { b } c }").walk(&mut visitor);
+    assert_eq!(visitor.entered, vec!["block", "item", "block", "item"]);
+}
+
+#[test]
+fn leave_still_runs_for_a_node_whose_children_were_skipped() {
+    struct SkipAllBlockChildren {
+        left: Vec<String>,
+    }
+
+    impl<'i> Visitor<'i, Rule> for SkipAllBlockChildren {
+        fn enter(&mut self, node: &Node<'i>) -> WalkControl {
+            if node.as_rule() == Rule::block {
+                WalkControl::SkipChildren
+            } else {
+                WalkControl::Continue
+            }
+        }
+
+        fn leave(&mut self, node: &Node<'i>) {
+            self.left.push(format!("{:?}", node.as_rule()));
+        }
+    }
+
+    let mut visitor = SkipAllBlockChildren { left: Vec::new() };
+    root("{ a { b } }").walk(&mut visitor);
+    assert_eq!(visitor.left, vec!["block"]);
+}
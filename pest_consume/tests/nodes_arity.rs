@@ -0,0 +1,85 @@
+use pest_consume::Parser as _;
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+item = @{ ASCII_ALPHA+ }
+items = { item* }
+list = { SOI ~ items ~ EOI }
+WHITESPACE = _{ " " }
+"#]
+struct ListParser;
+
+type Nodes<'i> = pest_consume::Nodes<'i, Rule>;
+
+impl pest_consume::Parser for ListParser {
+    type Rule = Rule;
+}
+
+fn items(input: &str) -> Nodes<'_> {
+    ListParser::parse(Rule::list, input)
+        .unwrap()
+        .single()
+        .unwrap()
+        .into_children()
+        .next_node()
+        .unwrap()
+        .into_children()
+}
+
+#[test]
+fn first_returns_the_first_node_without_requiring_the_rest_be_consumed() {
+    let mut nodes = items("foo bar baz");
+    assert_eq!(nodes.first().unwrap().as_str(), "foo");
+    assert_eq!(nodes.map_to_vec(Ok).unwrap().len(), 2);
+}
+
+#[test]
+fn first_errors_on_an_empty_sequence() {
+    assert!(items("").first().is_err());
+}
+
+#[test]
+fn single_or_none_accepts_zero_or_one_nodes() {
+    assert!(items("").single_or_none().unwrap().is_none());
+    assert_eq!(
+        items("foo").single_or_none().unwrap().unwrap().as_str(),
+        "foo"
+    );
+}
+
+#[test]
+fn single_or_none_errors_on_more_than_one_node() {
+    assert!(items("foo bar").single_or_none().is_err());
+}
+
+#[test]
+fn exactly_returns_a_fixed_size_array_in_order() {
+    let [a, b] = items("foo bar").exactly::<2>().unwrap();
+    assert_eq!((a.as_str(), b.as_str()), ("foo", "bar"));
+}
+
+#[test]
+fn exactly_reports_the_actual_count_on_mismatch() {
+    let error = match items("foo bar baz").exactly::<2>() {
+        Ok(_) => panic!("expected an error"),
+        Err(error) => error,
+    };
+    assert!(error.to_string().contains("expected exactly 2 node(s), found 3"));
+}
+
+#[test]
+fn two_is_a_tuple_shorthand_for_exactly_2() {
+    let (a, b) = items("foo bar").two().unwrap();
+    assert_eq!((a.as_str(), b.as_str()), ("foo", "bar"));
+}
+
+#[test]
+fn single_reports_the_actual_count_and_rules_on_mismatch() {
+    let error = match items("foo bar baz").single() {
+        Ok(_) => panic!("expected an error"),
+        Err(error) => error,
+    };
+    let message = error.to_string();
+    assert!(message.contains("expected exactly 1 node, found 3"));
+    assert!(message.contains("[item, item, item]"));
+}
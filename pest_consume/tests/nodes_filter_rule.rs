@@ -0,0 +1,79 @@
+use pest_consume::{match_nodes, Error, Parser as _};
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r##"
+comment = @{ "#" ~ (!"\n" ~ ANY)* }
+word = @{ ASCII_ALPHA+ }
+word_list = { (word | comment) ~ ((" " | "\n") ~ (word | comment))* }
+words = { SOI ~ word_list ~ EOI }
+"##]
+struct WordsParser;
+
+type Node<'i> = pest_consume::Node<'i, Rule>;
+type PestResult<T> = Result<T, Error<Rule>>;
+
+impl pest_consume::Parser for WordsParser {
+    type Rule = Rule;
+}
+
+impl WordsParser {
+    #[allow(non_snake_case)]
+    fn EOI(_input: Node) -> PestResult<()> {
+        Ok(())
+    }
+
+    fn word(input: Node) -> PestResult<String> {
+        Ok(input.as_str().to_owned())
+    }
+
+    fn word_list(input: Node) -> PestResult<Vec<String>> {
+        match_nodes!(input.into_children().exclude_rule(Rule::comment);
+            [word(w)..] => Ok(w),
+        )
+    }
+
+    fn words(input: Node) -> PestResult<Vec<String>> {
+        match_nodes!(input.into_children();
+            [word_list(w), EOI(_)] => Ok(w),
+        )
+    }
+}
+
+fn eval(input: &str) -> PestResult<Vec<String>> {
+    let inputs = WordsParser::parse(Rule::words, input)?;
+    let input = inputs.single()?;
+    WordsParser::words(input)
+}
+
+#[test]
+fn exclude_rule_drops_interleaved_comments_before_matching() {
+    assert_eq!(
+        eval("the #skip me\nquick #also skip\nfox").unwrap(),
+        vec!["the".to_owned(), "quick".to_owned(), "fox".to_owned()]
+    );
+}
+
+#[test]
+fn filter_rule_keeps_only_the_given_rule() {
+    let inputs = WordsParser::parse(Rule::words, "the #note\nquick").unwrap();
+    let word_list = inputs
+        .single()
+        .unwrap()
+        .into_children()
+        .next_node()
+        .unwrap();
+    let comments: Vec<String> = word_list
+        .into_children()
+        .filter_rule(Rule::comment)
+        .map_to_vec(|n| Ok(n.as_str().to_owned()))
+        .unwrap();
+    assert_eq!(comments, vec!["#note".to_owned()]);
+}
+
+#[test]
+fn exclude_rule_preserves_order_of_the_remaining_nodes() {
+    assert_eq!(
+        eval("#first\nthe\n#second\nquick").unwrap(),
+        vec!["the".to_owned(), "quick".to_owned()]
+    );
+}
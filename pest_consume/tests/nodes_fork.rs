@@ -0,0 +1,73 @@
+use pest_consume::Parser as _;
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+ident = @{ ASCII_ALPHA+ }
+number = @{ ASCII_DIGIT+ }
+item = { SOI ~ (ident | number) ~ EOI }
+"#]
+struct ItemParser;
+
+type Node<'i> = pest_consume::Node<'i, Rule>;
+
+impl pest_consume::Parser for ItemParser {
+    type Rule = Rule;
+}
+
+impl ItemParser {
+    fn ident(input: Node) -> String {
+        input.as_str().to_owned()
+    }
+
+    fn number(input: Node) -> u64 {
+        input.as_str().parse().unwrap()
+    }
+}
+
+#[test]
+fn forking_lets_a_failed_speculative_lookahead_back_off_to_the_original_position() {
+    let inputs = ItemParser::parse(Rule::item, "42").unwrap();
+    let mut nodes = inputs.single().unwrap().into_children();
+
+    let mut speculative = nodes.fork();
+    let first = speculative.next_node().unwrap();
+    // Speculatively try `ident` first; it doesn't match, so the fork is simply dropped.
+    assert_ne!(first.as_rule(), Rule::ident);
+
+    // `nodes` itself was never advanced, so it can still be consumed from the start.
+    let first = nodes.next_node().unwrap();
+    assert_eq!(first.as_rule(), Rule::number);
+    assert_eq!(ItemParser::number(first), 42);
+}
+
+#[test]
+fn a_successful_fork_can_replace_the_original_sequence() {
+    let inputs = ItemParser::parse(Rule::item, "foo").unwrap();
+    let mut nodes = inputs.single().unwrap().into_children();
+
+    let mut speculative = nodes.fork();
+    let first = speculative.next_node().unwrap();
+    assert_eq!(first.as_rule(), Rule::ident);
+    assert_eq!(ItemParser::ident(first), "foo");
+
+    // The lookahead panned out, so commit to it instead of `nodes`.
+    nodes = speculative;
+    assert_eq!(nodes.next_node().unwrap().as_rule(), Rule::EOI);
+}
+
+#[test]
+fn clone_reset_sees_every_node_again_even_after_partial_consumption() {
+    let inputs = ItemParser::parse(Rule::item, "foo").unwrap();
+    let mut nodes = inputs.single().unwrap().into_children();
+
+    // Consume partway through, unlike `fork`, which is always taken before any consumption.
+    let first = nodes.next_node().unwrap();
+    assert_eq!(first.as_rule(), Rule::ident);
+
+    let mut restarted = nodes.clone_reset();
+    assert_eq!(restarted.next_node().unwrap().as_rule(), Rule::ident);
+    assert_eq!(restarted.next_node().unwrap().as_rule(), Rule::EOI);
+
+    // `nodes` itself is untouched by the reset copy; it resumes from where it left off.
+    assert_eq!(nodes.next_node().unwrap().as_rule(), Rule::EOI);
+}
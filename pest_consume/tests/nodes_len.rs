@@ -0,0 +1,51 @@
+use pest_consume::Parser as _;
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+word = @{ ASCII_ALPHA+ }
+word_list = { word ~ (" " ~ word)* }
+words = { SOI ~ word_list ~ EOI }
+"#]
+struct WordsParser;
+
+type Node<'i> = pest_consume::Node<'i, Rule>;
+
+impl pest_consume::Parser for WordsParser {
+    type Rule = Rule;
+}
+
+fn word_list(input: &str) -> Node<'_> {
+    let inputs = WordsParser::parse(Rule::words, input).unwrap();
+    inputs
+        .single()
+        .unwrap()
+        .into_children()
+        .next_node()
+        .unwrap()
+}
+
+#[test]
+fn len_reports_the_number_of_remaining_children() {
+    let children = word_list("the quick fox").into_children();
+    assert_eq!(children.len(), 3);
+}
+
+#[test]
+fn len_shrinks_as_nodes_are_pulled_via_next_node() {
+    let mut children = word_list("the quick fox").into_children();
+    assert_eq!(children.len(), 3);
+    children.next_node().unwrap();
+    assert_eq!(children.len(), 2);
+    children.next_node().unwrap();
+    children.next_node().unwrap();
+    assert_eq!(children.len(), 0);
+    assert!(children.is_empty());
+}
+
+#[test]
+fn nodes_is_an_exact_size_iterator() {
+    let children = word_list("the quick fox").into_children();
+    assert_eq!(children.len(), 3);
+    let words: Vec<String> = children.map(|n| n.as_str().to_owned()).collect();
+    assert_eq!(words, vec!["the", "quick", "fox"]);
+}
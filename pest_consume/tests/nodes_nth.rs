@@ -0,0 +1,54 @@
+use pest_consume::Parser as _;
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+statement = @{ ASCII_ALPHA+ }
+program = { SOI ~ statement ~ (";" ~ statement)* ~ EOI }
+"#]
+struct ProgramParser;
+
+impl pest_consume::Parser for ProgramParser {
+    type Rule = Rule;
+}
+
+fn statements(input: &str) -> pest_consume::Nodes<'_, Rule> {
+    ProgramParser::parse(Rule::program, input)
+        .unwrap()
+        .single()
+        .unwrap()
+        .into_children()
+        .filter_rule(Rule::statement)
+}
+
+#[test]
+fn nth_inspects_a_later_node_without_consuming_the_ones_before_it() {
+    let mut nodes = statements("one;two;three;four");
+
+    let third = nodes.nth(2).unwrap();
+    assert_eq!(third.as_str(), "three");
+
+    // `nth` didn't consume anything - iteration still starts from the first node.
+    assert_eq!(nodes.next_node().unwrap().as_str(), "one");
+    assert_eq!(nodes.next_node().unwrap().as_str(), "two");
+}
+
+#[test]
+fn nth_past_the_end_is_none() {
+    let nodes = statements("one;two");
+    assert!(nodes.nth(2).is_none());
+}
+
+#[test]
+fn peek_last_returns_the_final_node_without_consuming_anything() {
+    let mut nodes = statements("one;two;three");
+
+    assert_eq!(nodes.peek_last().unwrap().as_str(), "three");
+    assert_eq!(nodes.next_node().unwrap().as_str(), "one");
+}
+
+#[test]
+fn peek_last_on_an_empty_sequence_is_none() {
+    let mut nodes = statements("one");
+    let _ = nodes.next_node();
+    assert!(nodes.peek_last().is_none());
+}
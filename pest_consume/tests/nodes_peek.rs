@@ -0,0 +1,110 @@
+use pest_consume::{match_nodes, Error, Parser as _};
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+label = @{ ASCII_ALPHA+ }
+num = @{ ASCII_DIGIT+ }
+tagged_value = { label ~ ":" ~ num }
+item = { tagged_value | num }
+item_list = { item ~ (" " ~ item)* }
+items = { SOI ~ item_list ~ EOI }
+"#]
+struct ItemsParser;
+
+type Node<'i> = pest_consume::Node<'i, Rule>;
+type PestResult<T> = Result<T, Error<Rule>>;
+
+impl pest_consume::Parser for ItemsParser {
+    type Rule = Rule;
+}
+
+#[derive(Debug, PartialEq)]
+enum Item {
+    Bare(i64),
+    Labeled(String, i64),
+}
+
+impl ItemsParser {
+    #[allow(non_snake_case)]
+    fn EOI(_input: Node) -> PestResult<()> {
+        Ok(())
+    }
+
+    fn label(input: Node) -> PestResult<String> {
+        Ok(input.as_str().to_owned())
+    }
+
+    fn num(input: Node) -> PestResult<i64> {
+        input.as_str().parse().map_err(|_| input.error("not a number"))
+    }
+
+    fn tagged_value(input: Node) -> PestResult<Item> {
+        let mut children = input.into_children();
+        let label = Self::label(children.next_node().unwrap())?;
+        let num = Self::num(children.next_node().unwrap())?;
+        Ok(Item::Labeled(label, num))
+    }
+
+    // A hand-rolled state machine driven by lookahead, rather than `match_nodes!`: `item`'s
+    // grammar already picks the right alternative, but this exercises `peek_rule` the way a
+    // context-sensitive construct that `match_nodes!` can't express would need to.
+    fn item(input: Node) -> PestResult<Item> {
+        let mut children = input.into_children();
+        match children.peek_rule() {
+            Some(Rule::tagged_value) => Self::tagged_value(children.next_node().unwrap()),
+            _ => Ok(Item::Bare(Self::num(children.next_node().unwrap())?)),
+        }
+    }
+
+    fn item_list(input: Node) -> PestResult<Vec<Item>> {
+        match_nodes!(input.into_children();
+            [item(items)..] => Ok(items),
+        )
+    }
+
+    fn items(input: Node) -> PestResult<Vec<Item>> {
+        match_nodes!(input.into_children();
+            [item_list(items), EOI(_)] => Ok(items),
+        )
+    }
+}
+
+fn eval(input: &str) -> PestResult<Vec<Item>> {
+    let inputs = ItemsParser::parse(Rule::items, input)?;
+    let input = inputs.single()?;
+    ItemsParser::items(input)
+}
+
+#[test]
+fn peek_rule_drives_the_choice_between_bare_and_labeled_items() {
+    let items = eval("1 x:2 y:3 4").unwrap();
+    assert_eq!(
+        items,
+        vec![
+            Item::Bare(1),
+            Item::Labeled("x".to_owned(), 2),
+            Item::Labeled("y".to_owned(), 3),
+            Item::Bare(4),
+        ]
+    );
+}
+
+#[test]
+fn peek_does_not_consume_the_node_it_returns() {
+    let inputs = ItemsParser::parse(Rule::items, "x:2").unwrap();
+    let item_list = inputs.single().unwrap().into_children().next_node().unwrap();
+    let item = item_list.into_children().next_node().unwrap();
+    let mut children = item.into_children();
+
+    let peeked = children.peek().unwrap();
+    assert_eq!(peeked.as_rule(), Rule::tagged_value);
+    assert_eq!(peeked.as_str(), "x:2");
+
+    // Peeking twice in a row returns the same node, since nothing was consumed.
+    let peeked_again = children.peek().unwrap();
+    assert_eq!(peeked_again.as_str(), "x:2");
+
+    // The node is still there to be consumed afterwards.
+    let consumed = children.next_node().unwrap();
+    assert_eq!(consumed.as_str(), "x:2");
+}
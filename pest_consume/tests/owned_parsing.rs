@@ -0,0 +1,76 @@
+#![cfg(feature = "owned_parsing")]
+
+use pest_consume::{Error, Parser as _};
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+word = @{ ASCII_ALPHA+ }
+word_list = { word ~ (" " ~ word)* }
+words = { SOI ~ word_list ~ EOI }
+"#]
+struct WordsParser;
+
+type Node<'i> = pest_consume::Node<'i, Rule>;
+type PestResult<T> = Result<T, Error<Rule>>;
+
+impl pest_consume::Parser for WordsParser {
+    type Rule = Rule;
+}
+
+impl WordsParser {
+    #[allow(non_snake_case)]
+    fn EOI(_input: Node) -> PestResult<()> {
+        Ok(())
+    }
+
+    fn word(input: Node) -> PestResult<String> {
+        Ok(input.as_str().to_owned())
+    }
+
+    fn word_list(input: Node) -> PestResult<Vec<String>> {
+        pest_consume::match_nodes!(input.into_children();
+            [word(words)..] => Ok(words),
+        )
+    }
+
+    fn words(input: Node) -> PestResult<Vec<String>> {
+        pest_consume::match_nodes!(input.into_children();
+            [word_list(w), EOI(_)] => Ok(w),
+        )
+    }
+}
+
+#[test]
+fn parse_owned_keeps_the_input_alive_alongside_the_nodes() {
+    let mut owned = WordsParser::parse_owned(Rule::words, "the quick fox".to_owned()).unwrap();
+    assert_eq!(owned.input(), "the quick fox");
+    let result = owned.consume(|nodes| WordsParser::words(nodes.single().unwrap()));
+    assert_eq!(result.unwrap(), vec!["the", "quick", "fox"]);
+}
+
+#[test]
+fn parse_owned_can_be_returned_from_a_function() {
+    fn parse(input: &str) -> pest_consume::OwnedNodes<Rule> {
+        WordsParser::parse_owned(Rule::words, input.to_owned()).unwrap()
+    }
+    let mut owned = parse("a b");
+    let result = owned.consume(|nodes| WordsParser::words(nodes.single().unwrap()));
+    assert_eq!(result.unwrap(), vec!["a", "b"]);
+}
+
+#[test]
+#[should_panic(expected = "OwnedNodes::consume can only be called once")]
+fn consume_panics_if_called_a_second_time() {
+    let mut owned = WordsParser::parse_owned(Rule::words, "a".to_owned()).unwrap();
+    let _ = owned.consume(|nodes| WordsParser::words(nodes.single().unwrap()));
+    let _ = owned.consume(|nodes| WordsParser::words(nodes.single().unwrap()));
+}
+
+#[test]
+fn parse_owned_reports_a_parse_error_the_same_way_as_parse() {
+    let err = match WordsParser::parse_owned(Rule::words, "123".to_owned()) {
+        Err(err) => err,
+        Ok(_) => panic!("expected a parse failure"),
+    };
+    assert!(err.to_string().contains("expected"));
+}
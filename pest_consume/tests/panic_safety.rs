@@ -0,0 +1,115 @@
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use pest_consume::{match_nodes, Error, Parser as _};
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+nested = { "(" ~ nested ~ ")" | num | ident }
+num = @{ ASCII_DIGIT+ }
+ident = @{ (!("(" | ")") ~ ANY)+ }
+expr = { SOI ~ nested ~ EOI }
+"#]
+struct NestedParser;
+
+type Node<'i> = pest_consume::Node<'i, Rule>;
+type PestResult<T> = Result<T, Error<Rule>>;
+
+impl pest_consume::Parser for NestedParser {
+    type Rule = Rule;
+}
+
+impl NestedParser {
+    #[allow(non_snake_case)]
+    fn EOI(_input: Node) -> PestResult<()> {
+        Ok(())
+    }
+
+    fn num(input: Node) -> PestResult<String> {
+        Ok(input.as_str().to_owned())
+    }
+
+    fn ident(input: Node) -> PestResult<String> {
+        Ok(input.as_str().to_owned())
+    }
+
+    fn nested(input: Node) -> PestResult<String> {
+        match_nodes!(input.into_children();
+            [nested(n)] => Ok(format!("({n})")),
+            [num(n)] => Ok(n),
+            [ident(i)] => Ok(i),
+        )
+    }
+
+    fn expr(input: Node) -> PestResult<String> {
+        match_nodes!(input.into_children();
+            [nested(n), EOI(_)] => Ok(n),
+        )
+    }
+}
+
+fn eval(input: &str) -> PestResult<String> {
+    let inputs = NestedParser::parse(Rule::expr, input)?;
+    let input = inputs.single()?;
+    NestedParser::expr(input)
+}
+
+/// Runs `eval` against `input`, asserting it returns cleanly (`Ok` or `Err`) rather than
+/// unwinding. Returns the result for callers that also want to assert on its value.
+fn assert_no_panic(input: &str) -> PestResult<String> {
+    match catch_unwind(AssertUnwindSafe(|| eval(input))) {
+        Ok(result) => result,
+        Err(_) => panic!("eval({input:?}) panicked instead of returning a Result"),
+    }
+}
+
+#[test]
+fn empty_input_never_panics() {
+    assert!(assert_no_panic("").is_err());
+}
+
+#[test]
+fn only_whitespace_never_panics() {
+    // No `WHITESPACE` rule is declared, so this grammar has no implicit skipping - the whole
+    // string matches `ident` as ordinary text rather than being rejected.
+    assert_eq!(assert_no_panic("   \n\t  ").unwrap(), "   \n\t  ");
+}
+
+#[test]
+fn unmatched_opening_delimiters_never_panic() {
+    assert!(assert_no_panic("((((((((((").is_err());
+}
+
+#[test]
+fn unmatched_closing_delimiters_never_panic() {
+    assert!(assert_no_panic("))))))))))").is_err());
+}
+
+// A depth beneath the point where pest's own generated recursive-descent parser (not
+// pest_consume's code, which never runs until a `Pairs` tree already exists) would overflow the
+// stack - see the caveat on this in `advanced_features::panic_safety`. This only exercises
+// pest_consume's own panic-freedom, not unbounded recursion in the underlying grammar itself.
+#[test]
+fn deeply_nested_valid_input_never_panics() {
+    let depth = 200;
+    let input = format!("{}{}{}", "(".repeat(depth), "1", ")".repeat(depth));
+    assert!(assert_no_panic(&input).is_ok());
+}
+
+#[test]
+fn deeply_nested_then_truncated_input_never_panics() {
+    let depth = 200;
+    let input = format!("{}{}", "(".repeat(depth), "1");
+    assert!(assert_no_panic(&input).is_err());
+}
+
+#[test]
+fn non_ascii_text_never_panics() {
+    assert_eq!(assert_no_panic("héllo wörld 日本語").unwrap(), "héllo wörld 日本語");
+}
+
+#[test]
+fn a_lone_delimiter_among_identifier_characters_never_panics() {
+    for input in ["(", ")", "()", ")(", "(a", "a)", "(a)(b)"] {
+        let _ = assert_no_panic(input);
+    }
+}
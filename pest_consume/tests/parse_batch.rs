@@ -0,0 +1,51 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use pest_consume::Parser as _;
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+num = @{ ASCII_DIGIT+ }
+file = { SOI ~ num ~ EOI }
+"#]
+struct NumParser;
+
+impl pest_consume::Parser for NumParser {
+    type Rule = Rule;
+}
+
+#[test]
+fn every_input_gets_its_own_result_in_order() {
+    let inputs = [("a.num", "1"), ("b.num", "x"), ("c.num", "3")];
+    let results = NumParser::parse_batch(Rule::file, &inputs, ());
+
+    let names: Vec<&str> = results.iter().map(|(name, _)| name.as_str()).collect();
+    assert_eq!(names, vec!["a.num", "b.num", "c.num"]);
+    assert!(results[0].1.is_ok());
+    assert!(results[1].1.is_err());
+    assert!(results[2].1.is_ok());
+}
+
+#[test]
+fn a_parse_error_carries_the_inputs_own_name() {
+    let inputs = [("bad.num", "x")];
+    let results = NumParser::parse_batch(Rule::file, &inputs, ());
+    let (name, result) = &results[0];
+    assert_eq!(name, "bad.num");
+    match result {
+        Err(err) => assert_eq!(err.path(), Some("bad.num")),
+        Ok(_) => panic!("expected a parse error"),
+    }
+}
+
+#[test]
+fn data_is_cloned_and_shared_independently_per_input() {
+    let log = Rc::new(RefCell::new(Vec::new()));
+    let inputs = [("a.num", "1"), ("b.num", "2")];
+    let results = NumParser::parse_batch(Rule::file, &inputs, Rc::clone(&log));
+    for (name, result) in results {
+        let inputs = result.unwrap();
+        inputs.single().unwrap().user_data().borrow_mut().push(name);
+    }
+    assert_eq!(*log.borrow(), vec!["a.num".to_owned(), "b.num".to_owned()]);
+}
@@ -0,0 +1,109 @@
+//! Two grammars, each with its own generated `Rule` type of the same name, living in one file -
+//! [`advanced_features::embedded_grammars`](pest_consume::advanced_features::embedded_grammars)'s
+//! pattern of giving each grammar's consuming methods their own module, so `match_nodes!` resolves
+//! `Rule` to whichever grammar that module belongs to.
+
+mod config {
+    use pest_consume::{match_nodes, Parser as _};
+
+    #[derive(pest_derive::Parser)]
+    #[grammar_inline = r#"
+    string = @{ (!"\"" ~ ANY)* }
+    quoted_string = { "\"" ~ string ~ "\"" }
+    expr_value = { (!"\n" ~ ANY)+ }
+    value = { quoted_string | expr_value }
+    line = { SOI ~ value ~ EOI }
+    "#]
+    pub struct ConfigParser;
+
+    impl pest_consume::Parser for ConfigParser {
+        type Rule = Rule;
+    }
+
+    type Node<'i> = pest_consume::Node<'i, Rule>;
+    type Result<T> = std::result::Result<T, pest_consume::Error<Rule>>;
+
+    impl ConfigParser {
+        pub fn value(input: Node) -> Result<Vec<u64>> {
+            match_nodes!(input.into_children();
+                [quoted_string(s)] => Ok(vec![s.len() as u64]),
+                [expr_value(numbers)] => Ok(numbers),
+            )
+        }
+
+        fn quoted_string(input: Node) -> Result<String> {
+            Ok(input.as_str().trim_matches('"').to_owned())
+        }
+
+        fn expr_value(input: Node) -> Result<Vec<u64>> {
+            let embedded = input
+                .parse_embedded::<super::expr::ExprParser>(super::expr::Rule::expr)
+                .map_err(|e| input.error(e.to_string()))?;
+            super::expr::ExprParser::expr(embedded.single().map_err(|e| input.error(e.to_string()))?)
+                .map_err(|e| input.error(e.to_string()))
+        }
+    }
+
+    pub fn parse(input: &str) -> Vec<u64> {
+        let node = ConfigParser::parse(Rule::line, input)
+            .unwrap()
+            .single()
+            .unwrap()
+            .into_children()
+            .next_node()
+            .unwrap();
+        ConfigParser::value(node).unwrap()
+    }
+}
+
+mod expr {
+    use pest_consume::match_nodes;
+
+    #[derive(pest_derive::Parser)]
+    #[grammar_inline = r#"
+    number = @{ ASCII_DIGIT+ }
+    number_list = { number ~ ("+" ~ number)* }
+    expr = { SOI ~ number_list ~ EOI }
+    "#]
+    pub struct ExprParser;
+
+    impl pest_consume::Parser for ExprParser {
+        type Rule = Rule;
+    }
+
+    type Node<'i> = pest_consume::Node<'i, Rule>;
+    type Result<T> = std::result::Result<T, pest_consume::Error<Rule>>;
+
+    impl ExprParser {
+        pub fn expr(input: Node) -> Result<Vec<u64>> {
+            match_nodes!(input.into_children();
+                [number_list(ns), EOI(_)] => Ok(ns),
+            )
+        }
+
+        fn number_list(input: Node) -> Result<Vec<u64>> {
+            match_nodes!(input.into_children();
+                [number(numbers)..] => Ok(numbers),
+            )
+        }
+
+        fn number(input: Node) -> Result<u64> {
+            input.parse_str()
+        }
+
+        #[allow(non_snake_case)]
+        fn EOI(_input: Node) -> Result<()> {
+            Ok(())
+        }
+    }
+}
+
+#[test]
+fn a_quoted_string_value_is_handled_by_the_config_grammar_alone() {
+    assert_eq!(config::parse("\"hi\""), vec![2]);
+}
+
+#[test]
+fn a_bare_value_is_handed_off_to_the_embedded_expr_grammar() {
+    assert_eq!(config::parse("1+2+3"), vec![1, 2, 3]);
+}
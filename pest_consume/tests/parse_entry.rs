@@ -0,0 +1,50 @@
+use pest_consume::Parser as _;
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+num = @{ ASCII_DIGIT+ }
+record = { SOI ~ num ~ EOI }
+"#]
+struct RecordParser;
+
+type Node<'i> = pest_consume::Node<'i, Rule>;
+type NodeWithScale<'i> = pest_consume::Node<'i, Rule, i64>;
+
+impl pest_consume::Parser for RecordParser {
+    type Rule = Rule;
+}
+
+impl RecordParser {
+    fn record(input: Node) -> Result<i64, pest_consume::Error<Rule>> {
+        input.into_children().filter_rule(Rule::num).single()?.parse_str()
+    }
+
+    fn scaled_record(input: NodeWithScale) -> Result<i64, pest_consume::Error<Rule>> {
+        let scale = *input.user_data();
+        let n: i64 = input.into_children().filter_rule(Rule::num).single()?.parse_str()?;
+        Ok(n * scale)
+    }
+}
+
+#[test]
+fn parse_entry_runs_parse_single_and_dispatch_in_one_call() {
+    let result = RecordParser::parse_entry(Rule::record, "14", RecordParser::record).unwrap();
+    assert_eq!(result, 14);
+}
+
+#[test]
+fn parse_entry_with_userdata_threads_data_to_the_dispatched_node() {
+    let result = RecordParser::parse_entry_with_userdata(
+        Rule::record,
+        "14",
+        3,
+        RecordParser::scaled_record,
+    )
+    .unwrap();
+    assert_eq!(result, 42);
+}
+
+#[test]
+fn parse_entry_propagates_a_parse_failure() {
+    assert!(RecordParser::parse_entry(Rule::record, "not a number", RecordParser::record).is_err());
+}
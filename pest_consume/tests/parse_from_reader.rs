@@ -0,0 +1,68 @@
+#![cfg(feature = "std")]
+
+use pest_consume::{match_nodes, Parser as _};
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+word = @{ ASCII_ALPHA+ }
+word_list = { word ~ (" " ~ word)* }
+words = { SOI ~ word_list ~ EOI }
+"#]
+struct WordsParser;
+
+type Node<'i> = pest_consume::Node<'i, Rule>;
+type PestResult<T> = Result<T, pest_consume::Error<Rule>>;
+
+impl pest_consume::Parser for WordsParser {
+    type Rule = Rule;
+}
+
+impl WordsParser {
+    #[allow(non_snake_case)]
+    fn EOI(_input: Node) -> PestResult<()> {
+        Ok(())
+    }
+
+    fn word(input: Node) -> PestResult<String> {
+        Ok(input.as_str().to_owned())
+    }
+
+    fn word_list(input: Node) -> PestResult<Vec<String>> {
+        match_nodes!(input.into_children();
+            [word(w)..] => Ok(w),
+        )
+    }
+
+    fn words(input: Node) -> PestResult<Vec<String>> {
+        match_nodes!(input.into_children();
+            [word_list(w), EOI(_)] => Ok(w),
+        )
+    }
+}
+
+#[test]
+fn parse_from_reader_buffers_and_parses_the_whole_source() {
+    let mut buf = String::new();
+    let inputs =
+        WordsParser::parse_from_reader(Rule::words, "the quick fox".as_bytes(), &mut buf).unwrap();
+    let input = inputs.single().unwrap();
+    assert_eq!(
+        WordsParser::words(input).unwrap(),
+        vec!["the".to_owned(), "quick".to_owned(), "fox".to_owned()]
+    );
+}
+
+#[test]
+fn parse_from_reader_surfaces_a_parse_error() {
+    let mut buf = String::new();
+    let result = WordsParser::parse_from_reader(Rule::words, "the 123".as_bytes(), &mut buf);
+    assert!(matches!(result, Err(pest_consume::ReadError::Parse(_))));
+}
+
+#[test]
+fn parse_from_reader_clears_leftover_content_from_a_previous_call() {
+    let mut buf = String::from("leftover");
+    let inputs = WordsParser::parse_from_reader(Rule::words, "fox".as_bytes(), &mut buf).unwrap();
+    let input = inputs.single().unwrap();
+    assert_eq!(WordsParser::words(input).unwrap(), vec!["fox".to_owned()]);
+}
@@ -0,0 +1,115 @@
+use pest_consume::{match_nodes, Error, ParseLimits, Parser as _};
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+nested = { "(" ~ nested ~ ")" | num }
+num = @{ ASCII_DIGIT+ }
+expr = { SOI ~ nested ~ EOI }
+nums = { num* }
+list = { SOI ~ nums ~ EOI }
+WHITESPACE = _{ " " }
+"#]
+struct LimitsParser;
+
+type Node<'i> = pest_consume::Node<'i, Rule>;
+type PestResult<T> = Result<T, Error<Rule>>;
+
+impl pest_consume::Parser for LimitsParser {
+    type Rule = Rule;
+}
+
+impl LimitsParser {
+    #[allow(non_snake_case)]
+    fn EOI(_input: Node) -> PestResult<()> {
+        Ok(())
+    }
+
+    fn num(input: Node) -> PestResult<u32> {
+        Ok(input.as_str().parse().unwrap())
+    }
+
+    fn nested(input: Node) -> PestResult<u32> {
+        match_nodes!(input.into_children();
+            [nested(n)] => Ok(n),
+            [num(n)] => Ok(n),
+        )
+    }
+
+    fn expr(input: Node) -> PestResult<u32> {
+        match_nodes!(input.into_children();
+            [nested(n), EOI(_)] => Ok(n),
+        )
+    }
+
+    fn nums(input: Node) -> PestResult<Vec<u32>> {
+        match_nodes!(input.into_children();
+            [num(n)..] => Ok(n),
+        )
+    }
+
+    fn list(input: Node) -> PestResult<Vec<u32>> {
+        match_nodes!(input.into_children();
+            [nums(n), EOI(_)] => Ok(n),
+        )
+    }
+}
+
+fn parens(depth: usize) -> String {
+    format!("{}{}{}", "(".repeat(depth), 1, ")".repeat(depth))
+}
+
+#[test]
+fn an_oversized_input_is_rejected_before_pest_ever_runs() {
+    let limits = ParseLimits::new().max_input_bytes(5);
+    let error = match LimitsParser::parse_with_limits(Rule::expr, "1234567890", limits) {
+        Ok(_) => panic!("expected an error"),
+        Err(error) => error,
+    };
+    assert!(error.to_string().contains("exceeds the 5 byte limit"));
+}
+
+#[test]
+fn an_input_within_the_byte_limit_still_parses() {
+    let limits = ParseLimits::new().max_input_bytes(5);
+    let inputs = LimitsParser::parse_with_limits(Rule::expr, "1", limits).unwrap();
+    assert_eq!(LimitsParser::expr(inputs.single().unwrap()).unwrap(), 1);
+}
+
+#[test]
+fn max_depth_behaves_like_parse_with_depth_limit() {
+    let limits = ParseLimits::new().max_depth(10);
+    let input = parens(20);
+    let inputs = LimitsParser::parse_with_limits(Rule::expr, &input, limits).unwrap();
+    assert!(LimitsParser::expr(inputs.single().unwrap()).is_err());
+}
+
+#[test]
+fn max_nodes_aborts_once_the_walk_visits_too_many_nodes() {
+    let limits = ParseLimits::new().max_nodes(3);
+    let input = parens(10);
+    let inputs = LimitsParser::parse_with_limits(Rule::expr, &input, limits).unwrap();
+    let error = LimitsParser::expr(inputs.single().unwrap()).unwrap_err();
+    assert!(error.to_string().contains("node budget of 3 exceeded"));
+}
+
+#[test]
+fn max_nodes_allows_a_walk_within_budget() {
+    let limits = ParseLimits::new().max_nodes(100);
+    let input = "1 2 3 4 5";
+    let inputs = LimitsParser::parse_with_limits(Rule::list, input, limits).unwrap();
+    assert_eq!(
+        LimitsParser::list(inputs.single().unwrap()).unwrap(),
+        vec![1, 2, 3, 4, 5]
+    );
+}
+
+#[test]
+fn every_limit_can_be_combined_at_once() {
+    let limits = ParseLimits::new()
+        .max_input_bytes(1000)
+        .max_depth(50)
+        .max_nodes(1000);
+    let input = parens(5);
+    let inputs = LimitsParser::parse_with_limits(Rule::expr, &input, limits).unwrap();
+    assert_eq!(LimitsParser::expr(inputs.single().unwrap()).unwrap(), 1);
+}
@@ -0,0 +1,66 @@
+use pest_consume::{Error, Parser as _};
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+item = @{ ASCII_ALPHA+ }
+item_list = { item ~ ("," ~ item)* }
+list = { SOI ~ item_list ~ EOI }
+"#]
+struct ListParser;
+
+type Node<'i> = pest_consume::Node<'i, Rule>;
+type PestResult<T> = Result<T, Error<Rule>>;
+
+impl pest_consume::Parser for ListParser {
+    type Rule = Rule;
+}
+
+impl ListParser {
+    #[allow(non_snake_case)]
+    fn EOI(_input: Node) -> PestResult<()> {
+        Ok(())
+    }
+
+    fn item(input: Node) -> PestResult<String> {
+        Err(input.error("not a valid item"))
+    }
+
+    fn item_list(input: Node) -> PestResult<Vec<String>> {
+        pest_consume::match_nodes!(input.into_children();
+            [item(items)..] => Ok(items),
+        )
+    }
+
+    fn list(input: Node) -> PestResult<Vec<String>> {
+        pest_consume::match_nodes!(input.into_children();
+            [item_list(items), EOI(_)] => Ok(items),
+        )
+    }
+}
+
+#[test]
+fn an_error_built_from_a_descendant_node_carries_the_given_path() {
+    let inputs = ListParser::parse_named(Rule::list, "a,b", "input.list").unwrap();
+    let err = ListParser::list(inputs.single().unwrap()).unwrap_err();
+    assert_eq!(err.path(), Some("input.list"));
+    assert!(err.to_string().contains("input.list"));
+}
+
+#[test]
+fn nodes_error_on_an_empty_sequence_also_carries_the_path() {
+    let inputs = ListParser::parse_named(Rule::list, "a", "input.list").unwrap();
+    let list = inputs.single().unwrap();
+    let mut children = list.into_children();
+    let item_list = children.next_node().unwrap();
+    let mut items = item_list.into_children();
+    let _ = items.next_node();
+    let err = items.error("no more items");
+    assert_eq!(err.path(), Some("input.list"));
+}
+
+#[test]
+fn plain_parse_carries_no_path() {
+    let inputs = ListParser::parse(Rule::list, "a,b").unwrap();
+    let err = ListParser::list(inputs.single().unwrap()).unwrap_err();
+    assert_eq!(err.path(), None);
+}
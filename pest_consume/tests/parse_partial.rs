@@ -0,0 +1,51 @@
+use pest_consume::Parser as _;
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+number = @{ ASCII_DIGIT+ }
+list = { SOI ~ (number ~ ",")* ~ EOI }
+list_plus = { SOI ~ (number ~ ",")+ ~ EOI }
+WHITESPACE = _{ " " }
+"#]
+struct ListParser;
+
+impl pest_consume::Parser for ListParser {
+    type Rule = Rule;
+}
+
+#[test]
+fn parse_partial_returns_the_full_tree_and_no_error_on_a_clean_parse() {
+    let (partial, error) = ListParser::parse_partial(Rule::list, "1,2,3,");
+    assert!(error.is_none());
+    let numbers: Vec<_> = partial
+        .unwrap()
+        .single()
+        .unwrap()
+        .into_children()
+        .filter(|n| n.as_rule() == Rule::number)
+        .map(|n| n.as_str().to_owned())
+        .collect();
+    assert_eq!(numbers, vec!["1", "2", "3"]);
+}
+
+#[test]
+fn parse_partial_recovers_every_item_before_a_trailing_syntax_error() {
+    let (partial, error) = ListParser::parse_partial(Rule::list, "1,2,x,");
+    assert!(error.is_some());
+    let numbers: Vec<_> = partial
+        .unwrap()
+        .single()
+        .unwrap()
+        .into_children()
+        .filter(|n| n.as_rule() == Rule::number)
+        .map(|n| n.as_str().to_owned())
+        .collect();
+    assert_eq!(numbers, vec!["1", "2"]);
+}
+
+#[test]
+fn parse_partial_gives_up_when_the_very_first_item_of_a_one_or_more_rule_is_malformed() {
+    let (partial, error) = ListParser::parse_partial(Rule::list_plus, "x,1,2,");
+    assert!(error.is_some());
+    assert!(partial.is_none());
+}
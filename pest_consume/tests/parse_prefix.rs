@@ -0,0 +1,43 @@
+use pest_consume::Parser as _;
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+statement = @{ ASCII_ALPHA+ ~ ";" }
+maybe_digits = @{ ASCII_DIGIT* }
+"#]
+struct ReplParser;
+
+impl pest_consume::Parser for ReplParser {
+    type Rule = Rule;
+}
+
+#[test]
+fn parse_prefix_matches_one_statement_and_returns_the_remainder() {
+    let (inputs, remainder) = ReplParser::parse_prefix(Rule::statement, "foo;bar;baz;").unwrap();
+    let node = inputs.single().unwrap();
+    assert_eq!(node.as_str(), "foo;");
+    assert_eq!(remainder, "bar;baz;");
+}
+
+#[test]
+fn parse_prefix_can_be_looped_to_read_every_statement_off_the_stream() {
+    let mut remaining = "foo;bar;baz;";
+    let mut statements = Vec::new();
+    while !remaining.is_empty() {
+        let (inputs, rest) = ReplParser::parse_prefix(Rule::statement, remaining).unwrap();
+        statements.push(inputs.single().unwrap().as_str().to_owned());
+        remaining = rest;
+    }
+    assert_eq!(statements, vec!["foo;", "bar;", "baz;"]);
+}
+
+#[test]
+fn parse_prefix_surfaces_a_parse_error_like_parse_does() {
+    assert!(ReplParser::parse_prefix(Rule::statement, "123;").is_err());
+}
+
+#[test]
+fn parse_prefix_errors_on_a_zero_length_match_instead_of_allowing_an_infinite_loop() {
+    let err = ReplParser::parse_prefix(Rule::maybe_digits, "abc").err().unwrap();
+    assert!(err.to_string().contains("matched zero bytes"));
+}
@@ -0,0 +1,77 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use pest_consume::{Error, Parser as _};
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+item = @{ ASCII_ALPHA+ }
+item_list = { item ~ ("," ~ item)* }
+list = { SOI ~ item_list ~ EOI }
+"#]
+struct ListParser;
+
+type Node<'i> = pest_consume::Node<'i, Rule>;
+type PestResult<T> = Result<T, Error<Rule>>;
+
+impl pest_consume::Parser for ListParser {
+    type Rule = Rule;
+}
+
+impl ListParser {
+    #[allow(non_snake_case)]
+    fn EOI(_input: Node) -> PestResult<()> {
+        Ok(())
+    }
+
+    fn item(input: Node) -> PestResult<String> {
+        Ok(input.as_str().to_owned())
+    }
+
+    fn item_list(input: Node) -> PestResult<Vec<String>> {
+        pest_consume::match_nodes!(input.into_children();
+            [item(items)..] => Ok(items),
+        )
+    }
+
+    fn list(input: Node) -> PestResult<Vec<String>> {
+        pest_consume::match_nodes!(input.into_children();
+            [item_list(items), EOI(_)] => Ok(items),
+        )
+    }
+}
+
+#[test]
+fn an_unset_token_lets_the_parse_run_to_completion() {
+    let cancel_token = AtomicBool::new(false);
+    let inputs = ListParser::parse_with_cancel(Rule::list, "a,b,c", &cancel_token).unwrap();
+    let result = ListParser::list(inputs.single().unwrap());
+    assert_eq!(result.unwrap(), vec!["a", "b", "c"]);
+}
+
+#[test]
+fn a_token_set_before_consuming_starts_cancels_immediately() {
+    let cancel_token = AtomicBool::new(true);
+    let inputs = ListParser::parse_with_cancel(Rule::list, "a,b,c", &cancel_token).unwrap();
+    let err = ListParser::list(inputs.single().unwrap()).unwrap_err();
+    assert!(err.to_string().contains("cancelled"));
+}
+
+#[test]
+fn a_token_set_partway_through_cancels_on_the_next_node() {
+    let cancel_token = AtomicBool::new(false);
+    let inputs = ListParser::parse_with_cancel(Rule::list, "a,b,c", &cancel_token).unwrap();
+    let list = inputs.single().unwrap();
+    let item_list = list.into_children().next_node().unwrap();
+    let mut items = item_list.into_children();
+    let first = items.next_node().unwrap();
+    assert_eq!(ListParser::item(first).unwrap(), "a");
+    // Cancel after the first node has already been consumed by hand, bypassing `match_nodes!`.
+    cancel_token.store(true, Ordering::Relaxed);
+    assert!(items.check_cancelled().is_err());
+}
+
+#[test]
+fn check_cancelled_always_passes_outside_of_parse_with_cancel() {
+    let inputs = ListParser::parse(Rule::list, "a,b,c").unwrap();
+    assert!(inputs.single().unwrap().into_children().check_cancelled().is_ok());
+}
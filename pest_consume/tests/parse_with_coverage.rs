@@ -0,0 +1,77 @@
+use std::collections::HashSet;
+
+use pest_consume::{Error, Parser as _};
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+item = @{ ASCII_ALPHA+ }
+item_list = { item ~ ("," ~ item)* }
+list = { SOI ~ ("empty" | item_list) ~ EOI }
+"#]
+struct ListParser;
+
+type Node<'i> = pest_consume::Node<'i, Rule>;
+type PestResult<T> = Result<T, Error<Rule>>;
+
+impl pest_consume::Parser for ListParser {
+    type Rule = Rule;
+}
+
+impl ListParser {
+    #[allow(non_snake_case)]
+    fn EOI(_input: Node) -> PestResult<()> {
+        Ok(())
+    }
+
+    fn item(input: Node) -> PestResult<String> {
+        Ok(input.as_str().to_owned())
+    }
+
+    fn item_list(input: Node) -> PestResult<Vec<String>> {
+        pest_consume::match_nodes!(input.into_children();
+            [item(items)..] => Ok(items),
+        )
+    }
+
+    fn list(input: Node) -> PestResult<Vec<String>> {
+        pest_consume::match_nodes!(input.into_children();
+            [item_list(items), EOI(_)] => Ok(items),
+            [EOI(_)] => Ok(vec![]),
+        )
+    }
+}
+
+#[test]
+fn every_rule_descended_into_is_recorded() {
+    let mut coverage = HashSet::new();
+    let inputs = ListParser::parse_with_coverage(Rule::list, "a,b,c", &mut coverage).unwrap();
+    ListParser::list(inputs.single().unwrap()).unwrap();
+
+    assert!(coverage.contains(&Rule::list));
+    assert!(coverage.contains(&Rule::item_list));
+    assert!(coverage.contains(&Rule::item));
+    assert!(coverage.contains(&Rule::EOI));
+}
+
+#[test]
+fn a_branch_never_taken_is_absent_from_the_set() {
+    let mut coverage = HashSet::new();
+    let inputs = ListParser::parse_with_coverage(Rule::list, "empty", &mut coverage).unwrap();
+    ListParser::list(inputs.single().unwrap()).unwrap();
+
+    assert!(coverage.contains(&Rule::list));
+    assert!(coverage.contains(&Rule::EOI));
+    assert!(!coverage.contains(&Rule::item_list));
+    assert!(!coverage.contains(&Rule::item));
+}
+
+#[test]
+fn manual_dispatch_through_next_node_still_records_coverage() {
+    let mut coverage = HashSet::new();
+    let inputs = ListParser::parse_with_coverage(Rule::list, "a,b,c", &mut coverage).unwrap();
+    let list = inputs.single().unwrap();
+    let mut children = list.into_children();
+    let _item_list = children.next_node().unwrap();
+
+    assert!(coverage.contains(&Rule::item_list));
+}
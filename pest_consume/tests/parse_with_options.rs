@@ -0,0 +1,95 @@
+use pest_consume::{match_nodes, Error, ParseOptions, Parser as _};
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r##"
+comment = @{ "#" ~ (!NEWLINE ~ ANY)* }
+ident = @{ ASCII_ALPHA+ }
+block = { "{" ~ stmt_list ~ "}" }
+stmt = _{ ident | block }
+stmt_list = { (stmt | comment)* }
+program = { SOI ~ stmt_list ~ EOI }
+WHITESPACE = _{ " " | NEWLINE }
+"##]
+struct CodeParser;
+
+type Node<'i> = pest_consume::Node<'i, Rule>;
+type PestResult<T> = Result<T, Error<Rule>>;
+
+#[derive(Debug, PartialEq)]
+enum Stmt {
+    Ident(String),
+    Block(Vec<Stmt>),
+}
+
+impl pest_consume::Parser for CodeParser {
+    type Rule = Rule;
+}
+
+impl CodeParser {
+    #[allow(non_snake_case)]
+    fn EOI(_input: Node) -> PestResult<()> {
+        Ok(())
+    }
+
+    fn ident(input: Node) -> PestResult<String> {
+        Ok(input.as_str().to_owned())
+    }
+
+    fn block(input: Node) -> PestResult<Vec<Stmt>> {
+        match_nodes!(input.into_children();
+            [stmt_list(s)] => Ok(s),
+        )
+    }
+
+    fn stmt(input: Node) -> PestResult<Stmt> {
+        match input.as_rule() {
+            Rule::ident => Ok(Stmt::Ident(Self::ident(input)?)),
+            Rule::block => Ok(Stmt::Block(Self::block(input)?)),
+            rule => Err(input.error(format!("unexpected {rule:?} where a statement was expected"))),
+        }
+    }
+
+    fn stmt_list(input: Node) -> PestResult<Vec<Stmt>> {
+        input.into_children().map(Self::stmt).collect()
+    }
+
+    fn program(input: Node) -> PestResult<Vec<Stmt>> {
+        match_nodes!(input.into_children();
+            [stmt_list(s), EOI(_)] => Ok(s),
+        )
+    }
+}
+
+fn eval_with_skip(input_str: &str) -> PestResult<Vec<Stmt>> {
+    let options = ParseOptions::new().skip_rule(Rule::comment);
+    let inputs = CodeParser::parse_with_options(Rule::program, input_str, options)?;
+    let input = inputs.single()?;
+    CodeParser::program(input)
+}
+
+fn eval_plain(input_str: &str) -> PestResult<Vec<Stmt>> {
+    let inputs = CodeParser::parse(Rule::program, input_str)?;
+    let input = inputs.single()?;
+    CodeParser::program(input)
+}
+
+const SOURCE: &str = "a\n# top-level comment\nb\n{\nc\n# nested comment\nd\n}";
+
+#[test]
+fn skip_rule_drops_comments_at_every_depth_of_nesting() {
+    let stmts = eval_with_skip(SOURCE).unwrap();
+    assert_eq!(
+        stmts,
+        vec![
+            Stmt::Ident("a".to_owned()),
+            Stmt::Ident("b".to_owned()),
+            Stmt::Block(vec![Stmt::Ident("c".to_owned()), Stmt::Ident("d".to_owned())]),
+        ]
+    );
+}
+
+#[test]
+fn without_the_option_an_interleaved_comment_breaks_consumption() {
+    let err = eval_plain(SOURCE).unwrap_err();
+    assert!(err.to_string().contains("comment"));
+}
@@ -0,0 +1,121 @@
+use pest_consume::pest::pratt_parser::{Assoc, Op, PrattParser};
+use pest_consume::{match_nodes, Error, Parser as _};
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+num = @{ ASCII_DIGIT+ }
+add = { "+" }
+sub = { "-" }
+mul = { "*" }
+div = { "/" }
+neg = { "-" }
+fac = { "!" }
+primary = _{ num | "(" ~ expr ~ ")" }
+expr = { neg* ~ primary ~ fac* ~ ((add | sub | mul | div) ~ neg* ~ primary ~ fac*)* }
+calculation = { SOI ~ expr ~ EOI }
+WHITESPACE = _{ " " }
+"#]
+struct CalcParser;
+
+type Node<'i> = pest_consume::Node<'i, Rule>;
+type PestResult<T> = Result<T, Error<Rule>>;
+
+fn pratt() -> PrattParser<Rule> {
+    PrattParser::new()
+        .op(Op::infix(Rule::add, Assoc::Left) | Op::infix(Rule::sub, Assoc::Left))
+        .op(Op::infix(Rule::mul, Assoc::Left) | Op::infix(Rule::div, Assoc::Left))
+        .op(Op::prefix(Rule::neg))
+        .op(Op::postfix(Rule::fac))
+}
+
+impl pest_consume::Parser for CalcParser {
+    type Rule = Rule;
+}
+
+impl CalcParser {
+    #[allow(non_snake_case)]
+    fn EOI(_input: Node) -> PestResult<()> {
+        Ok(())
+    }
+
+    fn num(input: Node) -> PestResult<f64> {
+        input
+            .as_str()
+            .parse()
+            .map_err(|_| input.error("not a number"))
+    }
+
+    fn expr(input: Node) -> PestResult<f64> {
+        input.into_children().pratt_climb(
+            &pratt(),
+            |primary| match primary.as_rule() {
+                Rule::num => Self::num(primary),
+                Rule::expr => Self::expr(primary),
+                _ => unreachable!(),
+            },
+            Some(|op: Node, rhs: PestResult<f64>| match op.as_rule() {
+                Rule::neg => Ok(-rhs?),
+                _ => unreachable!(),
+            }),
+            Some(|lhs: PestResult<f64>, op: Node| match op.as_rule() {
+                Rule::fac => {
+                    let lhs = lhs?;
+                    if lhs < 0.0 || lhs.fract() != 0.0 {
+                        return Err(op.error("factorial requires a non-negative integer"));
+                    }
+                    Ok((1..=(lhs as u64)).product::<u64>() as f64)
+                }
+                _ => unreachable!(),
+            }),
+            Some(|lhs: PestResult<f64>, op: Node, rhs: PestResult<f64>| match op.as_rule() {
+                Rule::add => Ok(lhs? + rhs?),
+                Rule::sub => Ok(lhs? - rhs?),
+                Rule::mul => Ok(lhs? * rhs?),
+                Rule::div => Ok(lhs? / rhs?),
+                _ => unreachable!(),
+            }),
+        )
+    }
+
+    fn calculation(input: Node) -> PestResult<f64> {
+        match_nodes!(input.into_children();
+            [expr(e), EOI(_)] => Ok(e),
+        )
+    }
+}
+
+fn eval(input: &str) -> PestResult<f64> {
+    let inputs = CalcParser::parse(Rule::calculation, input)?;
+    let input = inputs.single()?;
+    CalcParser::calculation(input)
+}
+
+#[test]
+fn infix_operators_still_work_like_prec_climb() {
+    assert_eq!(eval("1 + 2 * 3").unwrap(), 7.0);
+    assert_eq!(eval("(1 + 2) * 3").unwrap(), 9.0);
+}
+
+#[test]
+fn prefix_operator_negates_its_operand() {
+    assert_eq!(eval("-3 + 4").unwrap(), 1.0);
+    assert_eq!(eval("2 * -3").unwrap(), -6.0);
+}
+
+#[test]
+fn postfix_operator_computes_a_factorial() {
+    assert_eq!(eval("3!").unwrap(), 6.0);
+    assert_eq!(eval("2 + 3!").unwrap(), 8.0);
+}
+
+#[test]
+fn prefix_and_postfix_nest_with_postfix_binding_tighter() {
+    // `fac` is registered at a higher precedence than `neg`, so `-3!` is `-(3!)`, not `(-3)!`.
+    assert_eq!(eval("-3!").unwrap(), -6.0);
+}
+
+#[test]
+fn a_postfix_closure_error_short_circuits_the_whole_climb() {
+    let err = eval("(3 - 5)!").unwrap_err();
+    assert!(err.to_string().contains("factorial requires a non-negative integer"));
+}
@@ -0,0 +1,143 @@
+use pest_consume::{match_nodes, Assoc, Error, Parser as _, PrecClimber};
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+num = @{ ASCII_DIGIT+ }
+add = { "+" }
+sub = { "-" }
+mul = { "*" }
+pow = { "^" }
+primary = _{ num | "(" ~ expr ~ ")" }
+expr = { primary ~ ((add | sub | mul | pow) ~ primary)* }
+calculation = { SOI ~ expr ~ EOI }
+// Deliberately allows a malformed (or empty) operator sequence, so `prec_climb`'s own error
+// handling can be exercised without fighting the grammar's own shape.
+ops_seq = { SOI ~ ops_inner ~ EOI }
+ops_inner = { (num | add | sub | mul | pow)* }
+WHITESPACE = _{ " " }
+"#]
+struct CalcParser;
+
+type Node<'i> = pest_consume::Node<'i, Rule>;
+type PestResult<T> = Result<T, Error<Rule>>;
+
+fn climber() -> PrecClimber<Rule> {
+    PrecClimber::new(vec![
+        (Rule::add, 1, Assoc::Left),
+        (Rule::sub, 1, Assoc::Left),
+        (Rule::mul, 2, Assoc::Left),
+        (Rule::pow, 3, Assoc::Right),
+    ])
+}
+
+impl pest_consume::Parser for CalcParser {
+    type Rule = Rule;
+}
+
+impl CalcParser {
+    #[allow(non_snake_case)]
+    fn EOI(_input: Node) -> PestResult<()> {
+        Ok(())
+    }
+
+    fn num(input: Node) -> PestResult<f64> {
+        input
+            .as_str()
+            .parse()
+            .map_err(|_| input.error("not a number"))
+    }
+
+    fn expr(input: Node) -> PestResult<f64> {
+        input.into_children().prec_climb(
+            &climber(),
+            |primary| match primary.as_rule() {
+                Rule::num => Self::num(primary),
+                Rule::expr => Self::expr(primary),
+                _ => unreachable!(),
+            },
+            |lhs, op, rhs| match op.as_rule() {
+                Rule::add => Ok(lhs + rhs),
+                Rule::sub => Ok(lhs - rhs),
+                Rule::mul => Ok(lhs * rhs),
+                Rule::pow => Ok(lhs.powf(rhs)),
+                _ => unreachable!(),
+            },
+        )
+    }
+
+    fn calculation(input: Node) -> PestResult<f64> {
+        match_nodes!(input.into_children();
+            [expr(e), EOI(_)] => Ok(e),
+        )
+    }
+
+    fn ops_inner(input: Node) -> PestResult<f64> {
+        input
+            .into_children()
+            .prec_climb(&climber(), Self::num, |lhs, op, rhs| match op.as_rule() {
+                Rule::add => Ok(lhs + rhs),
+                Rule::sub => Ok(lhs - rhs),
+                Rule::mul => Ok(lhs * rhs),
+                Rule::pow => Ok(lhs.powf(rhs)),
+                _ => unreachable!(),
+            })
+    }
+
+    fn ops_seq(input: Node) -> PestResult<f64> {
+        match_nodes!(input.into_children();
+            [ops_inner(e), EOI(_)] => Ok(e),
+        )
+    }
+}
+
+fn eval(input: &str) -> PestResult<f64> {
+    let inputs = CalcParser::parse(Rule::calculation, input)?;
+    let input = inputs.single()?;
+    CalcParser::calculation(input)
+}
+
+fn eval_ops_seq(input: &str) -> PestResult<f64> {
+    let inputs = CalcParser::parse(Rule::ops_seq, input)?;
+    let input = inputs.single()?;
+    CalcParser::ops_seq(input)
+}
+
+#[test]
+fn left_associative_same_precedence() {
+    assert_eq!(eval("1 + 2 - 3").unwrap(), 0.0);
+}
+
+#[test]
+fn precedence_binds_tighter() {
+    assert_eq!(eval("1 + 2 * 3").unwrap(), 7.0);
+}
+
+#[test]
+fn right_associative() {
+    // 2 ^ (3 ^ 2) = 2 ^ 9 = 512, not (2 ^ 3) ^ 2 = 64
+    assert_eq!(eval("2 ^ 3 ^ 2").unwrap(), 512.0);
+}
+
+#[test]
+fn parenthesized_primary() {
+    assert_eq!(eval("(1 + 2) * 3").unwrap(), 9.0);
+}
+
+#[test]
+fn empty_children_is_an_error() {
+    assert!(eval_ops_seq("").is_err());
+}
+
+#[test]
+fn trailing_operator_is_an_error() {
+    assert!(eval_ops_seq("1 +").is_err());
+}
+
+#[test]
+#[should_panic(expected = "is used with both Assoc::Left and Assoc::Right")]
+fn mixed_associativity_at_the_same_precedence_is_rejected_up_front() {
+    PrecClimber::new(vec![
+        (Rule::add, 1, Assoc::Left),
+        (Rule::sub, 1, Assoc::Right),
+    ]);
+}
@@ -0,0 +1,35 @@
+use pest_consume::Parser as _;
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+word = @{ ASCII_ALPHANUMERIC+ }
+list = { SOI ~ word ~ (" " ~ word)* ~ EOI }
+"#]
+struct ListParser;
+
+impl pest_consume::Parser for ListParser {
+    type Rule = Rule;
+}
+
+fn remaining_after_first_word(input_str: &str) -> Option<String> {
+    let inputs = ListParser::parse(Rule::list, input_str).unwrap();
+    let list = inputs.single().unwrap();
+    let mut children = list.into_children().exclude_rule(Rule::EOI);
+    children.next_node().unwrap();
+    children.remaining_span().map(|span| span.as_str().to_owned())
+}
+
+#[test]
+fn remaining_span_covers_every_unconsumed_node() {
+    assert_eq!(remaining_after_first_word("a b c"), Some("b c".to_owned()));
+}
+
+#[test]
+fn remaining_span_is_none_once_every_node_is_consumed() {
+    assert_eq!(remaining_after_first_word("a"), None);
+}
+
+#[test]
+fn remaining_span_covers_a_single_leftover_node() {
+    assert_eq!(remaining_after_first_word("a b"), Some("b".to_owned()));
+}
@@ -0,0 +1,26 @@
+use pest_consume::Parser as _;
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+item = @{ ASCII_ALPHA+ }
+"#]
+struct ItemParser;
+
+impl pest_consume::Parser for ItemParser {
+    type Rule = Rule;
+}
+
+#[test]
+fn reparse_parses_just_the_given_substring() {
+    let document = "foo bar baz";
+    let edited_item = &document[4..7];
+    let inputs = ItemParser::reparse(Rule::item, edited_item).unwrap();
+    let node = inputs.single().unwrap();
+    assert_eq!(node.as_str(), "bar");
+    assert_eq!(node.as_span().start(), 0);
+}
+
+#[test]
+fn reparse_surfaces_a_parse_error_like_parse_does() {
+    assert!(ItemParser::reparse(Rule::item, "123").is_err());
+}
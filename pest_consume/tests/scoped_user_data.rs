@@ -0,0 +1,43 @@
+use pest_consume::Parser as _;
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+ident = @{ ASCII_ALPHA+ }
+block = { "{" ~ ident ~ block? ~ "}" }
+"#]
+struct BlockParser;
+
+type Node<'i> = pest_consume::Node<'i, Rule, i64>;
+
+impl pest_consume::Parser for BlockParser {
+    type Rule = Rule;
+}
+
+impl BlockParser {
+    fn ident(input: Node) -> Result<(String, i64), pest_consume::Error<Rule>> {
+        Ok((input.as_str().to_owned(), *input.user_data()))
+    }
+
+    fn block(input: Node) -> Result<Vec<(String, i64)>, pest_consume::Error<Rule>> {
+        let nested_depth = input.user_data() + 1;
+        let mut found = Vec::new();
+        let mut children = input.with_user_data(nested_depth).into_children();
+        while let Some(child) = children.next_node() {
+            match child.as_rule() {
+                Rule::ident => found.push(Self::ident(child)?),
+                Rule::block => found.extend(Self::block(child)?),
+            }
+        }
+        Ok(found)
+    }
+}
+
+#[test]
+fn descendants_see_the_swapped_value_while_the_original_node_keeps_its_own() {
+    let inputs = BlockParser::parse_with_userdata(Rule::block, "{a{b}}", 0i64).unwrap();
+    let root = inputs.single().unwrap();
+    assert_eq!(*root.user_data(), 0);
+
+    let found = BlockParser::block(root).unwrap();
+    assert_eq!(found, vec![("a".to_owned(), 1), ("b".to_owned(), 2)]);
+}
@@ -0,0 +1,83 @@
+#![cfg(feature = "serde")]
+
+use pest_consume::Parser as _;
+use serde::Deserialize;
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+name = @{ (!NEWLINE ~ !" " ~ ANY)+ }
+port = @{ ASCII_DIGIT+ }
+timeout = @{ ASCII_DIGIT+ }
+host = @{ (!NEWLINE ~ !" " ~ ANY)+ }
+config = { SOI ~ name ~ " " ~ port ~ (" " ~ timeout)? ~ (" " ~ host)* ~ EOI }
+"#]
+struct ConfigParser;
+
+impl pest_consume::Parser for ConfigParser {
+    type Rule = Rule;
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+struct Config {
+    name: String,
+    port: u16,
+    timeout: Option<u32>,
+    host: Vec<String>,
+}
+
+fn parse(input: &str) -> Config {
+    let inputs = ConfigParser::parse(Rule::config, input).unwrap();
+    let node = inputs.single().unwrap();
+    Config::deserialize(node).unwrap()
+}
+
+#[test]
+fn deserializes_a_struct_from_matching_rule_names() {
+    let config = parse("db 5432");
+    assert_eq!(
+        config,
+        Config { name: "db".to_owned(), port: 5432, timeout: None, host: vec![] }
+    );
+}
+
+#[test]
+fn an_optional_rule_present_becomes_some() {
+    let config = parse("db 5432 30");
+    assert_eq!(config.timeout, Some(30));
+}
+
+#[test]
+fn a_repeated_rule_collects_into_a_vec_in_order() {
+    let config = parse("db 5432 30 a b c");
+    assert_eq!(config.host, vec!["a".to_owned(), "b".to_owned(), "c".to_owned()]);
+}
+
+#[test]
+fn a_scalar_field_that_fails_to_parse_is_a_deserialize_error() {
+    #[derive(Deserialize, Debug)]
+    struct BadPort {
+        #[allow(dead_code)]
+        name: String,
+        #[allow(dead_code)]
+        port: bool,
+    }
+    let inputs = ConfigParser::parse(Rule::config, "db 5432").unwrap();
+    let node = inputs.single().unwrap();
+    assert!(BadPort::deserialize(node).is_err());
+}
+
+#[test]
+fn a_field_with_more_than_one_match_errors_unless_it_is_a_vec() {
+    #[derive(Deserialize, Debug)]
+    struct OneHost {
+        #[allow(dead_code)]
+        name: String,
+        #[allow(dead_code)]
+        port: u16,
+        #[allow(dead_code)]
+        host: String,
+    }
+    let inputs = ConfigParser::parse(Rule::config, "db 5432 30 a b").unwrap();
+    let node = inputs.single().unwrap();
+    assert!(OneHost::deserialize(node).is_err());
+}
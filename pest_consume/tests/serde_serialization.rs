@@ -0,0 +1,58 @@
+#![cfg(feature = "serde")]
+
+use pest_consume::{match_nodes, Error, Parser as _};
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+num = @{ ASCII_DIGIT+ }
+pair = { num ~ "," ~ num }
+"#]
+struct PairParser;
+
+type Node<'i> = pest_consume::Node<'i, Rule>;
+type PestResult<T> = Result<T, Error<Rule>>;
+
+impl pest_consume::Parser for PairParser {
+    type Rule = Rule;
+}
+
+impl PairParser {
+    fn num(input: Node) -> PestResult<i64> {
+        input.as_str().parse().map_err(|_| input.error("not a number"))
+    }
+
+    fn pair(input: Node) -> PestResult<(i64, i64)> {
+        match_nodes!(input.into_children();
+            [num(a), num(b)] => Ok((a, b)),
+        )
+    }
+}
+
+#[test]
+fn serialized_node_exposes_rule_text_span_and_children() {
+    let inputs = PairParser::parse(Rule::pair, "12,345").unwrap();
+    let node = inputs.single().unwrap();
+
+    let json = serde_json::to_value(&node).unwrap();
+    assert_eq!(json["rule"], "pair");
+    assert_eq!(json["str"], "12,345");
+    assert_eq!(json["start"], 0);
+    assert_eq!(json["end"], 6);
+
+    let children = json["children"].as_array().unwrap();
+    assert_eq!(children.len(), 2);
+    assert_eq!(children[0]["rule"], "num");
+    assert_eq!(children[0]["str"], "12");
+    assert_eq!(children[1]["str"], "345");
+}
+
+#[test]
+fn serializing_does_not_consume_the_node() {
+    let inputs = PairParser::parse(Rule::pair, "1,2").unwrap();
+    let node = inputs.single().unwrap();
+
+    let _ = serde_json::to_value(&node).unwrap();
+
+    // The node is still usable afterwards, since serialization only borrows it.
+    assert_eq!(PairParser::pair(node).unwrap(), (1, 2));
+}
@@ -0,0 +1,52 @@
+use pest_consume::{sort_errors_by_position, Error, Parser as _};
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+field = @{ (!"," ~ ANY)* }
+fields = { field ~ ("," ~ field)* }
+record = { SOI ~ fields ~ EOI }
+"#]
+struct RecordParser;
+
+impl pest_consume::Parser for RecordParser {
+    type Rule = Rule;
+}
+
+fn field_error(input: &str, index: usize, message: &str) -> Error<Rule> {
+    let inputs = RecordParser::parse(Rule::record, input).unwrap();
+    let record = inputs.single().unwrap();
+    let fields = record.into_children().next_node().unwrap();
+    let field = fields.into_children().nth(index).unwrap();
+    field.error(message)
+}
+
+#[test]
+fn errors_are_sorted_by_where_they_start_in_the_source() {
+    let input = "aa,bb,cc";
+    let mut errors = vec![
+        field_error(input, 2, "third"),
+        field_error(input, 0, "first"),
+        field_error(input, 1, "second"),
+    ];
+
+    sort_errors_by_position(&mut errors);
+
+    let messages: Vec<_> = errors.iter().map(|e| format!("{e}")).collect();
+    assert!(messages[0].contains("first"));
+    assert!(messages[1].contains("second"));
+    assert!(messages[2].contains("third"));
+}
+
+#[test]
+fn exact_duplicates_at_the_same_position_are_dropped() {
+    let input = "aa,bb";
+    let mut errors = vec![
+        field_error(input, 0, "bad field"),
+        field_error(input, 1, "other"),
+        field_error(input, 0, "bad field"),
+    ];
+
+    sort_errors_by_position(&mut errors);
+
+    assert_eq!(errors.len(), 2);
+}
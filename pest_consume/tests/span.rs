@@ -0,0 +1,112 @@
+use pest_consume::{match_nodes, Error, Parser as _};
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+word = @{ ASCII_ALPHA+ }
+word_list = { word ~ ((" " | "\n") ~ word)* }
+words = { SOI ~ word_list ~ EOI }
+maybe_word = @{ ASCII_ALPHA* }
+empty_check = { SOI ~ maybe_word ~ EOI }
+"#]
+struct WordsParser;
+
+type Node<'i> = pest_consume::Node<'i, Rule>;
+type PestResult<T> = Result<T, Error<Rule>>;
+
+impl pest_consume::Parser for WordsParser {
+    type Rule = Rule;
+}
+
+impl WordsParser {
+    #[allow(non_snake_case)]
+    fn EOI(_input: Node) -> PestResult<()> {
+        Ok(())
+    }
+
+    fn word(input: Node) -> PestResult<(String, usize, usize)> {
+        let span = input.as_span();
+        Ok((input.as_str().to_owned(), span.start(), span.end()))
+    }
+
+    fn word_list(input: Node) -> PestResult<Vec<(String, usize, usize)>> {
+        match_nodes!(input.into_children();
+            [word(w)..] => Ok(w),
+        )
+    }
+
+    fn words(input: Node) -> PestResult<Vec<(String, usize, usize)>> {
+        match_nodes!(input.into_children();
+            [word_list(w), EOI(_)] => Ok(w),
+        )
+    }
+
+    fn maybe_word(input: Node) -> PestResult<((usize, usize), (usize, usize))> {
+        Ok((input.line_col(), input.end_line_col()))
+    }
+
+    fn empty_check(input: Node) -> PestResult<((usize, usize), (usize, usize))> {
+        match_nodes!(input.into_children();
+            [maybe_word(lc), EOI(_)] => Ok(lc),
+        )
+    }
+}
+
+fn eval(input: &str) -> PestResult<Vec<(String, usize, usize)>> {
+    let inputs = WordsParser::parse(Rule::words, input)?;
+    let input = inputs.single()?;
+    WordsParser::words(input)
+}
+
+#[test]
+fn as_span_reports_the_byte_offsets_of_each_word() {
+    let words = eval("the quick fox").unwrap();
+    assert_eq!(
+        words,
+        vec![
+            ("the".to_owned(), 0, 3),
+            ("quick".to_owned(), 4, 9),
+            ("fox".to_owned(), 10, 13),
+        ]
+    );
+}
+
+#[test]
+fn line_col_reports_one_indexed_line_and_column() {
+    let inputs = WordsParser::parse(Rule::words, "the\nquick fox").unwrap();
+    let word_list = inputs.single().unwrap().into_children().next_node().unwrap();
+    let mut words = word_list.into_children();
+    let _the = words.next_node().unwrap();
+    let quick = words.next_node().unwrap();
+    // `quick` starts on line 2, column 1.
+    assert_eq!(quick.line_col(), (2, 1));
+    assert_eq!(quick.end_line_col(), (2, 6));
+}
+
+#[test]
+fn line_col_on_a_zero_width_match_reports_the_start_position_without_panicking() {
+    let inputs = WordsParser::parse(Rule::empty_check, "").unwrap();
+    let input = inputs.single().unwrap();
+    let (start, end) = WordsParser::empty_check(input).unwrap();
+    assert_eq!(start, (1, 1));
+    assert_eq!(end, (1, 1));
+}
+
+#[test]
+fn as_span_borrows_the_same_source_as_as_str() {
+    let inputs = WordsParser::parse(Rule::words, "solo").unwrap();
+    let words = inputs.single().unwrap().into_children();
+    let word = words.map_to_vec(Ok).unwrap().into_iter().next().unwrap();
+    let span = word.as_span();
+    assert_eq!(span.as_str(), word.as_str());
+}
+
+#[test]
+fn input_returns_the_full_original_source_even_from_a_deeply_nested_node() {
+    let source = "the quick fox";
+    let inputs = WordsParser::parse(Rule::words, source).unwrap();
+    let word_list = inputs.single().unwrap().into_children().next_node().unwrap();
+    let word = word_list.into_children().next_node().unwrap();
+
+    assert_eq!(word.as_str(), "the");
+    assert_eq!(word.input(), source);
+}
@@ -0,0 +1,69 @@
+use pest_consume::{match_nodes, Error, Parser as _};
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+word = @{ ASCII_ALPHA+ }
+word_list = { word ~ (" " ~ word)* }
+words = { SOI ~ word_list ~ EOI }
+"#]
+struct WordsParser;
+
+type Node<'i> = pest_consume::Node<'i, Rule>;
+type PestResult<T> = Result<T, Error<Rule>>;
+
+impl pest_consume::Parser for WordsParser {
+    type Rule = Rule;
+}
+
+impl WordsParser {
+    #[allow(non_snake_case)]
+    fn EOI(_input: Node) -> PestResult<()> {
+        Ok(())
+    }
+
+    fn word<'i>(input: Node<'i>) -> PestResult<pest_consume::Spanned<'i, String>> {
+        input.parse_spanned(|input| Ok(input.as_str().to_owned()))
+    }
+
+    fn word_list<'i>(input: Node<'i>) -> PestResult<Vec<pest_consume::Spanned<'i, String>>> {
+        match_nodes!(input.into_children();
+            [word(w)..] => Ok(w),
+        )
+    }
+
+    fn words<'i>(input: Node<'i>) -> PestResult<Vec<pest_consume::Spanned<'i, String>>> {
+        match_nodes!(input.into_children();
+            [word_list(w), EOI(_)] => Ok(w),
+        )
+    }
+}
+
+fn eval<'i>(input: &'i str) -> PestResult<Vec<pest_consume::Spanned<'i, String>>> {
+    let inputs = WordsParser::parse(Rule::words, input)?;
+    let input = inputs.single()?;
+    WordsParser::words(input)
+}
+
+#[test]
+fn spanned_derefs_to_the_wrapped_value() {
+    let words = eval("the quick fox").unwrap();
+    let joined: Vec<&str> = words.iter().map(|w| w.as_str()).collect();
+    assert_eq!(joined, vec!["the", "quick", "fox"]);
+}
+
+#[test]
+fn spanned_span_reports_the_byte_offsets() {
+    let words = eval("the quick fox").unwrap();
+    assert_eq!(words[1].span().start(), 4);
+    assert_eq!(words[1].span().end(), 9);
+}
+
+#[test]
+fn parse_spanned_propagates_the_closures_error() {
+    let inputs = WordsParser::parse(Rule::words, "x").unwrap();
+    let word = inputs.single().unwrap().into_children().next_node().unwrap();
+    let err = word
+        .parse_spanned(|input: Node| -> PestResult<()> { Err(input.error("nope")) })
+        .unwrap_err();
+    assert!(err.to_string().contains("nope"));
+}
@@ -0,0 +1,48 @@
+use pest_consume::Parser as _;
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+item = @{ ASCII_ALPHANUMERIC+ }
+section_break = { "---" }
+file = { SOI ~ (section_break | item) ~ (" " ~ (section_break | item))* ~ EOI }
+"#]
+struct FileParser;
+
+impl pest_consume::Parser for FileParser {
+    type Rule = Rule;
+}
+
+fn items(input: &str) -> Vec<Vec<String>> {
+    let inputs = FileParser::parse(Rule::file, input).unwrap();
+    let file = inputs.single().unwrap();
+    file.into_children()
+        .exclude_rule(Rule::EOI)
+        .split_at_rule(Rule::section_break)
+        .into_iter()
+        .map(|section| section.map(|node| node.as_str().to_owned()).collect())
+        .collect()
+}
+
+#[test]
+fn splits_into_one_group_per_marker_plus_one() {
+    assert_eq!(
+        items("imports a b --- decls c d"),
+        vec![
+            vec!["imports".to_owned(), "a".to_owned(), "b".to_owned()],
+            vec!["decls".to_owned(), "c".to_owned(), "d".to_owned()],
+        ],
+    );
+}
+
+#[test]
+fn a_leading_marker_produces_an_empty_first_group() {
+    assert_eq!(
+        items("--- a b"),
+        vec![Vec::<String>::new(), vec!["a".to_owned(), "b".to_owned()]],
+    );
+}
+
+#[test]
+fn no_marker_produces_a_single_group() {
+    assert_eq!(items("a b c"), vec![vec!["a".to_owned(), "b".to_owned(), "c".to_owned()]]);
+}
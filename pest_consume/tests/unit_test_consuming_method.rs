@@ -0,0 +1,53 @@
+use pest_consume::{match_nodes, Error, Parser as _};
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+field = @{ ASCII_DIGIT+ ~ ("." ~ ASCII_DIGIT+)? }
+field_list = { field ~ ("," ~ field)* }
+record = { SOI ~ field_list ~ EOI }
+"#]
+struct CSVParser;
+
+type Node<'i> = pest_consume::Node<'i, Rule>;
+type PestResult<T> = Result<T, Error<Rule>>;
+
+impl pest_consume::Parser for CSVParser {
+    type Rule = Rule;
+}
+
+impl CSVParser {
+    #[allow(non_snake_case)]
+    fn EOI(_input: Node) -> PestResult<()> {
+        Ok(())
+    }
+
+    fn field(input: Node) -> PestResult<f64> {
+        input.as_str().parse().map_err(|_| input.error("not a number"))
+    }
+
+    fn field_list(input: Node) -> PestResult<Vec<f64>> {
+        match_nodes!(input.into_children();
+            [field(fields)..] => Ok(fields),
+        )
+    }
+
+    fn record(input: Node) -> PestResult<Vec<f64>> {
+        match_nodes!(input.into_children();
+            [field_list(fields), EOI(_)] => Ok(fields),
+        )
+    }
+}
+
+#[test]
+fn field_can_be_tested_without_going_through_record_or_the_grammars_entry_point() {
+    let inputs = CSVParser::parse(Rule::field, "12.5").unwrap();
+    let input = inputs.single().unwrap();
+    assert_eq!(CSVParser::field(input).unwrap(), 12.5);
+}
+
+#[test]
+fn matches_nodes_works_the_same_way_when_reached_through_a_narrower_parse() {
+    let inputs = CSVParser::parse(Rule::record, "1,2,3").unwrap();
+    let input = inputs.single().unwrap();
+    assert_eq!(CSVParser::record(input).unwrap(), vec![1.0, 2.0, 3.0]);
+}
@@ -0,0 +1,73 @@
+use pest_consume::{match_nodes, Error, Parser as _};
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+number = @{ "+"? ~ ASCII_DIGIT+ }
+number_list = { number ~ ("," ~ number)* }
+record = { SOI ~ number_list ~ EOI }
+"#]
+struct CSVParser;
+
+type Node<'i> = pest_consume::Node<'i, Rule>;
+type PestResult<T> = Result<T, Error<Rule>>;
+
+impl pest_consume::Parser for CSVParser {
+    type Rule = Rule;
+}
+
+impl CSVParser {
+    #[allow(non_snake_case)]
+    fn EOI(_input: Node) -> PestResult<()> {
+        Ok(())
+    }
+
+    fn number(input: Node) -> PestResult<f64> {
+        if input.as_str().starts_with('+') {
+            input.warn("a leading '+' on a number is deprecated");
+        }
+        input
+            .as_str()
+            .trim_start_matches('+')
+            .parse()
+            .map_err(|_| input.error("not a number"))
+    }
+
+    fn number_list(input: Node) -> PestResult<Vec<f64>> {
+        match_nodes!(input.into_children();
+            [number(fields)..] => Ok(fields),
+        )
+    }
+
+    fn record(input: Node) -> PestResult<Vec<f64>> {
+        match_nodes!(input.into_children();
+            [number_list(fields), EOI(_)] => Ok(fields),
+        )
+    }
+}
+
+fn parse(input_str: &str) -> PestResult<(Vec<f64>, Vec<Error<Rule>>)> {
+    CSVParser::parse_collecting_warnings(Rule::record, input_str, |inputs| {
+        let input = inputs.single()?;
+        CSVParser::record(input)
+    })
+}
+
+#[test]
+fn a_clean_parse_collects_no_warnings() {
+    let (fields, warnings) = parse("1,2,3").unwrap();
+    assert_eq!(fields, vec![1.0, 2.0, 3.0]);
+    assert!(warnings.is_empty());
+}
+
+#[test]
+fn deprecated_syntax_is_collected_without_failing_the_parse() {
+    let (fields, warnings) = parse("+1,2,+3").unwrap();
+    assert_eq!(fields, vec![1.0, 2.0, 3.0]);
+    assert_eq!(warnings.len(), 2);
+    assert!(warnings[0].to_string().contains("deprecated"));
+}
+
+#[test]
+fn a_fatal_error_still_propagates_as_err() {
+    assert!(parse("1,nope,3").is_err());
+}
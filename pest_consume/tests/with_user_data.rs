@@ -0,0 +1,40 @@
+use pest_consume::Parser as _;
+
+#[derive(pest_derive::Parser)]
+#[grammar_inline = r#"
+num = @{ ASCII_DIGIT+ }
+record = { SOI ~ num ~ EOI }
+"#]
+struct RecordParser;
+
+struct AppSettings {
+    multiplier: i64,
+}
+
+type Node<'i> = pest_consume::Node<'i, Rule>;
+type NodeWithSettings<'i, 'a> = pest_consume::Node<'i, Rule, &'a AppSettings>;
+
+impl pest_consume::Parser for RecordParser {
+    type Rule = Rule;
+}
+
+impl RecordParser {
+    fn num(input: Node) -> Result<i64, pest_consume::Error<Rule>> {
+        input.parse_str()
+    }
+
+    fn record(input: NodeWithSettings) -> Result<i64, pest_consume::Error<Rule>> {
+        let multiplier = input.user_data().multiplier;
+        let num_node = input.into_children().filter_rule(Rule::num).single()?;
+        let n = Self::num(num_node.with_user_data(()))?;
+        Ok(n * multiplier)
+    }
+}
+
+#[test]
+fn a_data_free_method_can_be_called_from_one_that_carries_data() {
+    let settings = AppSettings { multiplier: 3 };
+    let inputs = RecordParser::parse_with_userdata(Rule::record, "14", &settings).unwrap();
+    let input = inputs.single().unwrap();
+    assert_eq!(RecordParser::record(input).unwrap(), 42);
+}